@@ -0,0 +1,60 @@
+//! Compares hashing grapheme clusters with `UnicodeSegmentationExt::graphemes_with_hash` against
+//! iterating with `UnicodeSegmentation::graphemes` and hashing each cluster with the standard
+//! library's default hasher.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use unicode_segmentation::{UnicodeSegmentation, UnicodeSegmentationExt};
+
+const FILES: &[&str] = &[
+    "arabic",
+    "english",
+    "hindi",
+    "japanese",
+    "korean",
+    "mandarin",
+    "russian",
+    "source_code",
+];
+
+#[inline(always)]
+fn with_hash(text: &str) {
+    for c in black_box(text).graphemes_with_hash(true) {
+        black_box(c);
+    }
+}
+
+#[inline(always)]
+fn then_hash(text: &str) {
+    for c in black_box(text).graphemes(true) {
+        let mut hasher = DefaultHasher::new();
+        c.hash(&mut hasher);
+        black_box((c, hasher.finish()));
+    }
+}
+
+fn bench_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("grapheme_hashes");
+
+    for file in FILES {
+        group.bench_with_input(
+            BenchmarkId::new("graphemes_with_hash", file),
+            &fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap(),
+            |b, content| b.iter(|| with_hash(content)),
+        );
+    }
+
+    for file in FILES {
+        group.bench_with_input(
+            BenchmarkId::new("default_hasher", file),
+            &fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap(),
+            |b, content| b.iter(|| then_hash(content)),
+        );
+    }
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Compares collecting owned grapheme clusters with `new_owned_graphemes`
+//! against copying each cluster into a `String`.
+//!
+//! Before the timings, the number of heap allocations each approach makes for every text is
+//! printed, counted by a wrapper around the system allocator.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use unicode_segmentation::{new_owned_graphemes, UnicodeSegmentation};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const FILES: &[&str] = &[
+    "arabic",
+    "english",
+    "hindi",
+    "japanese",
+    "korean",
+    "mandarin",
+    "russian",
+    "source_code",
+];
+
+#[inline(always)]
+fn owned(text: &str) {
+    for c in new_owned_graphemes(black_box(text), true) {
+        black_box(c);
+    }
+}
+
+#[inline(always)]
+fn string(text: &str) {
+    for c in black_box(text).graphemes(true).map(String::from) {
+        black_box(c);
+    }
+}
+
+fn count_allocations(f: impl Fn()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_all(c: &mut Criterion) {
+    let texts = FILES
+        .iter()
+        .map(|file| fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap())
+        .collect::<Vec<_>>();
+
+    for (file, text) in FILES.iter().zip(&texts) {
+        println!(
+            "{}: {} allocations with new_owned_graphemes, {} with map(String::from)",
+            file,
+            count_allocations(|| owned(text)),
+            count_allocations(|| string(text)),
+        );
+    }
+
+    let mut group = c.benchmark_group("owned_graphemes");
+
+    for (file, text) in FILES.iter().zip(&texts) {
+        group.bench_with_input(
+            BenchmarkId::new("small_cluster", file),
+            text,
+            |b, content| b.iter(|| owned(content)),
+        );
+    }
+
+    for (file, text) in FILES.iter().zip(&texts) {
+        group.bench_with_input(BenchmarkId::new("string", file), text, |b, content| {
+            b.iter(|| string(content))
+        });
+    }
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Measures iteration over long runs of regional indicators, such as flag spam, in both
+//! directions. The time taken should grow linearly with the length of the run.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const LENGTHS: &[usize] = &[1_000, 10_000, 100_000];
+
+#[inline(always)]
+fn graphemes_rev(text: &str) {
+    for g in black_box(text).graphemes(true).rev() {
+        black_box(g);
+    }
+}
+
+#[inline(always)]
+fn word_bounds_rev(text: &str) {
+    for w in black_box(text).split_word_bounds().rev() {
+        black_box(w);
+    }
+}
+
+#[inline(always)]
+fn word_bounds(text: &str) {
+    for w in black_box(text).split_word_bounds() {
+        black_box(w);
+    }
+}
+
+fn bench_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("regional_indicators");
+
+    for &len in LENGTHS {
+        let text = "\u{1F1E6}".repeat(len);
+        group.bench_with_input(BenchmarkId::new("graphemes_rev", len), &text, |b, text| {
+            b.iter(|| graphemes_rev(text))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("word_bounds_rev", len),
+            &text,
+            |b, text| b.iter(|| word_bounds_rev(text)),
+        );
+        group.bench_with_input(BenchmarkId::new("word_bounds", len), &text, |b, text| {
+            b.iter(|| word_bounds(text))
+        });
+    }
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
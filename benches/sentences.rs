@@ -0,0 +1,40 @@
+//! Measures the performance of `UnicodeSegmentation::split_sentence_bounds` over texts in a
+//! number of scripts.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use std::fs;
+use unicode_segmentation::UnicodeSegmentation;
+
+const FILES: &[&str] = &[
+    "arabic",
+    "english",
+    "hindi",
+    "japanese",
+    "korean",
+    "mandarin",
+    "russian",
+    "source_code",
+];
+
+#[inline(always)]
+fn sentences(text: &str) {
+    for s in text.split_sentence_bounds() {
+        black_box(s);
+    }
+}
+
+fn bench_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sentences");
+
+    for file in FILES {
+        group.bench_with_input(
+            BenchmarkId::new("split_sentence_bounds", file),
+            &fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap(),
+            |b, content| b.iter(|| sentences(content)),
+        );
+    }
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+//! Measures `UnicodeSegmentation::graphemes` on many short strings that are each a single
+//! grapheme cluster, as in emoji reactions or single-character tokens.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const EMOJI: &[&str] = &[
+    "\u{1F44D}",
+    "\u{1F44D}\u{1F3FD}",
+    "\u{2764}\u{FE0F}",
+    "\u{1F602}",
+    "\u{1F389}",
+    "\u{1F1EF}\u{1F1F5}",
+    "\u{1F1FA}\u{1F1F8}",
+    "1\u{FE0F}\u{20E3}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+    "\u{1F525}",
+];
+
+const LETTERS: &[&str] = &[
+    "a",
+    "e\u{301}",
+    "\u{E9}",
+    "\u{D55C}",
+    "\u{4E2D}",
+    "\u{928}\u{93F}",
+];
+
+#[inline(always)]
+fn graphemes(strings: &[&str]) {
+    for s in strings {
+        for g in black_box(*s).graphemes(true) {
+            black_box(g);
+        }
+    }
+}
+
+fn bench_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_clusters");
+
+    let emoji = EMOJI.iter().cycle().take(1000).copied().collect::<Vec<_>>();
+    group.bench_with_input(
+        BenchmarkId::new("graphemes", "emoji"),
+        &emoji,
+        |b, strings| b.iter(|| graphemes(strings)),
+    );
+
+    let letters = LETTERS
+        .iter()
+        .cycle()
+        .take(1000)
+        .copied()
+        .collect::<Vec<_>>();
+    group.bench_with_input(
+        BenchmarkId::new("graphemes", "letters"),
+        &letters,
+        |b, strings| b.iter(|| graphemes(strings)),
+    );
+}
+
+criterion_group!(benches, bench_all);
+criterion_main!(benches);
@@ -21,6 +21,11 @@ fn grapheme(text: &str) {
     }
 }
 
+#[inline(always)]
+fn count(text: &str) {
+    black_box(text.split_word_bounds().count());
+}
+
 fn bench_all(c: &mut Criterion) {
     let mut group = c.benchmark_group("word_bounds");
 
@@ -30,6 +35,11 @@ fn bench_all(c: &mut Criterion) {
             &fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap(),
             |b, content| b.iter(|| grapheme(content)),
         );
+        group.bench_with_input(
+            BenchmarkId::new("count", file),
+            &fs::read_to_string(format!("benches/texts/{}.txt", file)).unwrap(),
+            |b, content| b.iter(|| count(content)),
+        );
     }
 }
 
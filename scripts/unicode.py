@@ -12,9 +12,12 @@
 
 # This script uses the following Unicode tables:
 # - DerivedCoreProperties.txt
+# - EastAsianWidth.txt
+# - extracted/DerivedBidiClass.txt
 # - auxiliary/GraphemeBreakProperty.txt
 # - auxiliary/WordBreakProperty.txt
 # - ReadMe.txt
+# - Scripts.txt
 # - UnicodeData.txt
 #
 # Since this should not require frequent updates, we just store this
@@ -58,6 +61,15 @@ UNICODE_VERSION = (16, 0, 0)
 
 UNICODE_VERSION_NUMBER = "%s.%s.%s" %UNICODE_VERSION
 
+# The scripts told apart when looking for words that mix scripts. Alphabetic characters of
+# other scripts map to `Other`, and those of Common and Inherited to `Common`.
+mixed_scripts = ["Arabic", "Armenian", "Bengali", "Bopomofo", "Canadian_Aboriginal", "Cherokee",
+                 "Coptic", "Cyrillic", "Devanagari", "Ethiopic", "Georgian", "Greek", "Gujarati",
+                 "Gurmukhi", "Han", "Hangul", "Hebrew", "Hiragana", "Kannada", "Katakana",
+                 "Khmer", "Lao", "Latin", "Malayalam", "Mongolian", "Myanmar", "Nko", "Ogham",
+                 "Oriya", "Runic", "Sinhala", "Syriac", "Tamil", "Telugu", "Thaana", "Thai",
+                 "Tibetan"]
+
 def is_surrogate(n):
     return surrogate_codepoints[0] <= n <= surrogate_codepoints[1]
 
@@ -110,6 +122,26 @@ def load_gencats(f):
     gencats = group_cats(gencats)
     return gencats
 
+def load_combining_classes(f):
+    fetch(f)
+    classes = []
+    for line in fileinput.input(f):
+        data = line.split(';')
+        if len(data) != 15:
+            continue
+        ccc = int(data[3])
+        if ccc != 0:
+            classes.append((int(data[0], 16), ccc))
+
+    # merge runs of adjacent code points with the same class
+    table = []
+    for (cp, ccc) in sorted(classes):
+        if table and table[-1][1] == cp - 1 and table[-1][2] == ccc:
+            table[-1] = (table[-1][0], cp, ccc)
+        else:
+            table.append((cp, cp, ccc))
+    return table
+
 def group_cats(cats):
     cats_out = {}
     for cat in cats:
@@ -196,6 +228,57 @@ def load_properties(f, interestingprops: "list[str | tuple[str, str]] | None" =
 
     return props
 
+def load_missing(f):
+    # The values given by the @missing lines of `f` to the code points it doesn't list, with
+    # later lines taking precedence over earlier ones
+    fetch(f)
+    re_missing = re.compile(r"^#\s*@missing:\s*([0-9A-F]+)\.\.([0-9A-F]+)\s*;\s*(\w+)")
+    missing = {}
+    for line in fileinput.input(os.path.basename(f)):
+        m = re_missing.match(line)
+        if m:
+            for cp in range(int(m.group(1), 16), int(m.group(2), 16) + 1):
+                missing[cp] = m.group(3)
+    return missing
+
+def load_with_defaults(f, values):
+    # The code points of `f` with one of `values`, either listed or by default
+    props = load_properties(f)
+    listed = set()
+    for prop in props:
+        listed.update(ungroup_cat(props[prop]))
+    cps = []
+    for value in values:
+        if value in props:
+            cps.extend(ungroup_cat(props[value]))
+    missing = load_missing(f)
+    cps.extend(cp for cp in missing if missing[cp] in values and cp not in listed)
+    return group_cat(cps)
+
+def script_table(scripts, alphabetic):
+    # The script of each alphabetic character, merging runs that aren't interrupted by an
+    # alphabetic character of another script, so ranges may cover other characters
+    alphabetic = set(ungroup_cat(alphabetic))
+    script_of = {}
+    for name in scripts:
+        if name in ("Common", "Inherited"):
+            value = "Common"
+        elif name in mixed_scripts:
+            value = name.replace("_", "")
+        else:
+            value = "Other"
+        for cp in ungroup_cat(scripts[name]):
+            if cp in alphabetic:
+                script_of[cp] = value
+
+    table = []
+    for cp in sorted(script_of):
+        if table and table[-1][2] == script_of[cp]:
+            table[-1] = (table[-1][0], cp, script_of[cp])
+        else:
+            table.append((cp, cp, script_of[cp]))
+    return [entry for entry in table if entry[2] != "Other"]
+
 def escape_char(c):
     return "'\\u{%x}'" % c
 
@@ -230,6 +313,16 @@ pub mod util {
         }).is_ok()
     }
 
+    #[inline]
+    pub fn bsearch_range_value_table<T: Copy>(c: char, r: &[(char, char, T)]) -> Option<T> {
+        use core::cmp::Ordering::{Equal, Less, Greater};
+        r.binary_search_by(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }).ok().map(|idx| r[idx].2)
+    }
+
     #[inline]
     fn is_alphabetic(c: char) -> bool {
         if super::UNICODE_VERSION_U8 == char::UNICODE_VERSION {
@@ -283,7 +376,45 @@ def emit_property_module(f, mod, tbl, emit: "list[str | tuple[str, str]]"):
         f.write("    }\n\n")
     f.write("}\n\n")
 
-def emit_break_module(f, break_table, break_cats, name):
+def emit_value_module(f, mod, name, table, value_type, default, header=""):
+    f.write("pub mod %s {\n" % mod)
+    f.write(header)
+    emit_table(f, "%s_table" % name, table, "&[(char, char, %s)]" % value_type, is_pub=False,
+        pfun=lambda x: "(%s,%s,%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]))
+    f.write("    #[inline]\n")
+    f.write("    pub fn %s(c: char) -> %s {\n" % (name, value_type))
+    f.write("        super::util::bsearch_range_value_table(c, %s_table).unwrap_or(%s)\n" % (name, default))
+    f.write("    }\n\n")
+    f.write("}\n\n")
+
+def emit_bmp_lookup(f, break_table, name):
+    # A two-level table of the category of every character in the Basic Multilingual Plane. The
+    # index maps each block of 32 code points to one of the distinct blocks of categories.
+    Name = name.capitalize()
+    cats = ["Any"] * 0x10000
+    for (lo, hi, cat) in break_table:
+        for cp in range(lo, min(hi, 0xffff) + 1):
+            cats[cp] = cat
+    blocks = {}
+    index = []
+    for start in range(0, 0x10000, 32):
+        block = tuple(cats[start:start + 32])
+        index.append(blocks.setdefault(block, len(blocks)))
+    assert len(blocks) <= 0x100
+
+    f.write("""    #[inline]
+    pub fn %s_category_bmp(c: char) -> Option<%sCat> {
+        let block = *%s_bmp_index.get(c as usize >> 5)? as usize;
+        Some(%s_bmp_blocks[block << 5 | (c as usize & 0x1f)])
+    }
+
+""" % (name, Name, name, name))
+    emit_table(f, "%s_bmp_index" % name, index, "&[u8]", pfun=lambda x: "%d" % x,
+        is_pub=False)
+    emit_table(f, "%s_bmp_blocks" % name, [cat for block in blocks for cat in block],
+        "&[%sCat]" % Name, pfun=lambda x: "%sC_%s" % (Name[0], x), is_pub=False)
+
+def emit_break_module(f, break_table, break_cats, name, bmp_lookup=False):
     Name = name.capitalize()
     f.write("""pub mod %s {
     use core::result::Result::{Ok, Err};
@@ -383,6 +514,8 @@ def emit_break_module(f, break_table, break_cats, name):
     emit_table(f, "%s_cat_table" % name, break_table, "&[(char, char, %sCat)]" % Name,
         pfun=lambda x: "(%s,%s,%sC_%s)" % (escape_char(x[0]), escape_char(x[1]), Name[0], x[2]),
         is_pub=False, is_const=True)
+    if bmp_lookup:
+        emit_bmp_lookup(f, break_table, name)
     f.write("}\n")
 
 if __name__ == "__main__":
@@ -405,12 +538,39 @@ const UNICODE_VERSION_U8: (u8, u8, u8) = (%s, %s, %s);
         # download and parse all the data
         gencats = load_gencats("UnicodeData.txt")
         derived = load_properties("DerivedCoreProperties.txt", ["Alphabetic", ("InCB", "Consonant"), ("InCB", "Extend"), ("InCB", "Linker")])
+        # Bidi_Class R and AL, including unassigned code points in right-to-left blocks
+        bidi = {"RTL": load_with_defaults("extracted/DerivedBidiClass.txt",
+                                          ["R", "AL", "Right_To_Left", "Arabic_Letter"])}
+        # East_Asian_Width W and F, including unassigned code points in blocks of ideographs
+        east_asian_width = {"Wide": load_with_defaults("EastAsianWidth.txt",
+                                                       ["W", "F", "Wide", "Fullwidth"])}
 
         emit_util_mod(rf)
-        for (name, cat, pfuns) in ("general_category", gencats, ["N"]), \
-                                  ("derived_property", derived, ["Alphabetic", ("InCB", "Extend")]):
+        for (name, cat, pfuns) in ("general_category", gencats, ["N", "P", "Pi", "Ps"]), \
+                                  ("derived_property", derived, ["Alphabetic", ("InCB", "Extend")]), \
+                                  ("bidi_class", bidi, ["RTL"]), \
+                                  ("east_asian_width", east_asian_width, ["Wide"]):
             emit_property_module(rf, name, cat, pfuns)
 
+        # only used to put graphemes in canonical order, which allocates
+        combining_classes = load_combining_classes("UnicodeData.txt")
+        rf.write('#[cfg(feature = "alloc")]\n')
+        emit_value_module(rf, "canonical_combining_class", "combining_class", combining_classes,
+                          "u8", "0")
+
+        scripts = load_properties("Scripts.txt")
+        script_names = sorted([name.replace("_", "") for name in mixed_scripts] + ["Common"])
+        emit_value_module(rf, "script", "script", script_table(scripts, derived["Alphabetic"]),
+                          "Script", "Other", header="""    pub use self::Script::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum Script {
+%s
+        Other,
+    }
+
+""" % "\n".join("        %s," % name for name in script_names))
+
         rf.write("""pub fn is_incb_linker(c: char) -> bool {
     matches!(c,""")
 
@@ -444,7 +604,8 @@ const UNICODE_VERSION_U8: (u8, u8, u8) = (%s, %s, %s);
             if chars[0] <= last:
                 raise "Grapheme tables and Extended_Pictographic values overlap; need to store these separately!"
             last = chars[1]
-        emit_break_module(rf, grapheme_table, list(grapheme_cats.keys()), "grapheme")
+        emit_break_module(rf, grapheme_table, list(grapheme_cats.keys()), "grapheme",
+                          bmp_lookup=True)
         rf.write("\n")
 
         word_cats = load_properties("auxiliary/WordBreakProperty.txt")
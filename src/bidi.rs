@@ -0,0 +1,116 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The base direction of text from its first strong directional character, as in rules P2 and
+// P3 of the Unicode Bidirectional Algorithm (UAX #9), without the rest of the algorithm.
+
+use crate::sentence::USentenceBounds;
+
+/// The base direction of a piece of text, from its first strong directional character.
+///
+/// This is created by the [`sentences_with_direction`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`sentences_with_direction`]: trait.UnicodeSegmentationExt.html#tymethod.sentences_with_direction
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The first strong character is left-to-right, such as a Latin, Cyrillic or Han letter.
+    Ltr,
+    /// The first strong character is right-to-left, such as a Hebrew or Arabic letter.
+    Rtl,
+    /// There is no strong character, for example in text made up only of digits,
+    /// punctuation and whitespace.
+    Neutral,
+}
+
+/// The direction of the first strong character of `s`.
+///
+/// Right-to-left characters are those with Bidi_Class R or AL. Since the crate has no table
+/// of Bidi_Class L, left-to-right characters are taken to be the other alphabetic
+/// characters that are not combining marks, which covers the letters of all left-to-right
+/// scripts.
+pub fn direction(s: &str) -> Direction {
+    use crate::tables::bidi_class::RTL;
+    use crate::tables::derived_property::Alphabetic;
+    use crate::tables::grapheme as gr;
+
+    for c in s.chars() {
+        if RTL(c) {
+            return Direction::Rtl;
+        }
+        if Alphabetic(c) && gr::grapheme_category(c).2 != gr::GC_Extend {
+            return Direction::Ltr;
+        }
+    }
+    Direction::Neutral
+}
+
+/// External iterator for the sentences of a string and their base directions.
+///
+/// This struct is created by the [`sentences_with_direction`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`sentences_with_direction`]: trait.UnicodeSegmentationExt.html#tymethod.sentences_with_direction
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct SentencesWithDirection<'a> {
+    iter: USentenceBounds<'a>,
+}
+
+impl<'a> Iterator for SentencesWithDirection<'a> {
+    type Item = (&'a str, Direction);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, Direction)> {
+        self.iter.next().map(|s| (s, direction(s)))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for SentencesWithDirection<'a> {}
+
+/// Returns an iterator over the sentences of `s` and their text direction. See
+/// [`UnicodeSegmentationExt::sentences_with_direction`].
+///
+/// [`UnicodeSegmentationExt::sentences_with_direction`]: crate::UnicodeSegmentationExt::sentences_with_direction
+#[inline]
+pub fn new_sentences_with_direction(s: &str) -> SentencesWithDirection<'_> {
+    SentencesWithDirection {
+        iter: crate::sentence::new_sentence_bounds(s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{direction, Direction};
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(direction(""), Direction::Neutral);
+        assert_eq!(direction("12, 34!"), Direction::Neutral);
+        assert_eq!(direction("abc"), Direction::Ltr);
+        assert_eq!(direction("\u{4e2d}\u{6587}"), Direction::Ltr);
+        assert_eq!(direction("\u{5e9}\u{5dc}\u{5d5}\u{5dd}"), Direction::Rtl);
+        assert_eq!(
+            direction("\u{627}\u{644}\u{639}\u{631}\u{628}\u{64a}\u{629}"),
+            Direction::Rtl
+        );
+        assert_eq!(direction("\u{710}\u{72a}"), Direction::Rtl);
+        assert_eq!(direction("\u{10900}"), Direction::Rtl);
+        // the first strong character decides
+        assert_eq!(direction("(1) \u{5d0} a"), Direction::Rtl);
+        assert_eq!(direction("\"a\" \u{5d0}"), Direction::Ltr);
+        // Arabic-Indic digits and combining marks are not strong, but the right-to-left mark is
+        assert_eq!(direction("\u{661}\u{662} x"), Direction::Ltr);
+        assert_eq!(direction("\u{301}\u{64b}"), Direction::Neutral);
+        assert_eq!(direction("\u{897}a"), Direction::Ltr);
+        assert_eq!(direction("\u{200f}abc"), Direction::Rtl);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Canonical ordering of the combining marks within grapheme clusters.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::grapheme::{new_graphemes, Graphemes};
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries),
+/// with the combining marks of each cluster in canonical order.
+///
+/// This struct is created by the [`new_canonical_graphemes`] function. See its documentation
+/// for more.
+///
+/// [`new_canonical_graphemes`]: fn.new_canonical_graphemes.html
+#[derive(Clone, Debug)]
+pub struct CanonicalGraphemes<'a> {
+    inner: Graphemes<'a>,
+}
+
+impl<'a> Iterator for CanonicalGraphemes<'a> {
+    type Item = Cow<'a, str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Cow<'a, str>> {
+        self.inner.next().map(canonical_order)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for CanonicalGraphemes<'a> {}
+
+impl<'a> DoubleEndedIterator for CanonicalGraphemes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Cow<'a, str>> {
+        self.inner.next_back().map(canonical_order)
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s`, with the combining marks of each
+/// cluster sorted into canonical order. See [`UnicodeSegmentation::graphemes`] for more
+/// information.
+///
+/// Within each cluster, every run of characters with a nonzero
+/// [Canonical_Combining_Class](http://www.unicode.org/reports/tr44/#Canonical_Combining_Class_Values)
+/// is stably sorted by class, as in the Canonical Ordering Algorithm of
+/// [UAX #15](http://www.unicode.org/reports/tr15/). No characters are composed or decomposed,
+/// so this is not a normalization, but two clusters that only differ in the order of their
+/// marks come out equal. Clusters that are already in order are borrowed.
+///
+/// Requires the `alloc` feature, which is enabled by default.
+///
+/// [`UnicodeSegmentation::graphemes`]: crate::UnicodeSegmentation::graphemes
+///
+/// # Example
+///
+/// ```
+/// # use unicode_segmentation::new_canonical_graphemes;
+/// let gr1 = new_canonical_graphemes("a\u{301}\u{323}b", true).collect::<Vec<_>>();
+///
+/// assert_eq!(gr1, ["a\u{323}\u{301}", "b"]);
+/// ```
+#[inline]
+pub fn new_canonical_graphemes(s: &str, is_extended: bool) -> CanonicalGraphemes<'_> {
+    CanonicalGraphemes {
+        inner: new_graphemes(s, is_extended),
+    }
+}
+
+/// Applies the Canonical Ordering Algorithm to `s`, borrowing it if it is already in order.
+fn canonical_order(s: &str) -> Cow<'_, str> {
+    let mut last = 0;
+    let in_order = s.chars().all(|c| {
+        let class = combining_class(c);
+        let ok = class == 0 || last <= class;
+        last = class;
+        ok
+    });
+    if in_order {
+        return Cow::Borrowed(s);
+    }
+
+    let mut chars = s
+        .chars()
+        .map(|c| (combining_class(c), c))
+        .collect::<Vec<_>>();
+    // sort each run of non-starters by class, keeping marks of the same class in order
+    for run in chars.split_mut(|&(class, _)| class == 0) {
+        run.sort_by_key(|&(class, _)| class);
+    }
+    Cow::Owned(chars.into_iter().map(|(_, c)| c).collect::<String>())
+}
+
+pub fn combining_class(c: char) -> u8 {
+    if c < '\u{300}' {
+        return 0;
+    }
+    crate::tables::canonical_combining_class::combining_class(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_order, combining_class};
+    use alloc::borrow::Cow;
+
+    #[test]
+    fn test_combining_class() {
+        assert_eq!(combining_class('a'), 0);
+        assert_eq!(combining_class('\u{300}'), 230);
+        assert_eq!(combining_class('\u{316}'), 220);
+        assert_eq!(combining_class('\u{345}'), 240);
+        assert_eq!(combining_class('\u{5b0}'), 10);
+        assert_eq!(combining_class('\u{94d}'), 9);
+        assert_eq!(combining_class('\u{1e94a}'), 7);
+        assert_eq!(combining_class('\u{e0100}'), 0);
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        assert!(matches!(
+            canonical_order("a\u{323}\u{301}"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            canonical_order("a\u{301}\u{301}"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(canonical_order("a\u{301}\u{323}"), "a\u{323}\u{301}");
+        // marks of the same class keep their relative order
+        assert_eq!(
+            canonical_order("a\u{301}\u{300}\u{323}"),
+            "a\u{323}\u{301}\u{300}"
+        );
+        // starters are not reordered, and separate the runs that are sorted
+        assert_eq!(
+            canonical_order("\u{1100}\u{301}\u{323}\u{1161}\u{302}\u{316}"),
+            "\u{1100}\u{323}\u{301}\u{1161}\u{316}\u{302}"
+        );
+    }
+}
@@ -9,6 +9,10 @@
 // except according to those terms.
 
 use core::cmp;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Peekable;
+use core::ops::{ControlFlow, Range};
 
 use crate::tables::grapheme::GraphemeCat;
 
@@ -17,6 +21,9 @@ use crate::tables::grapheme::GraphemeCat;
 /// This struct is created by the [`grapheme_indices`] method on the [`UnicodeSegmentation`]
 /// trait. See its documentation for more.
 ///
+/// Two `GraphemeIndices` compare equal if they would yield the same items: their remaining
+/// text and segmentation mode are the same, and the remaining text starts at the same offset.
+///
 /// [`grapheme_indices`]: trait.UnicodeSegmentation.html#tymethod.grapheme_indices
 /// [`UnicodeSegmentation`]: trait.UnicodeSegmentation.html
 #[derive(Debug, Clone)]
@@ -30,6 +37,7 @@ impl<'a> GraphemeIndices<'a> {
     /// View the underlying data (the part yet to be iterated) as a slice of the original string.
     ///
     /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
     /// # use unicode_segmentation::UnicodeSegmentation;
     /// let mut iter = "abc".grapheme_indices(true);
     /// assert_eq!(iter.as_str(), "abc");
@@ -38,10 +46,29 @@ impl<'a> GraphemeIndices<'a> {
     /// iter.next();
     /// iter.next();
     /// assert_eq!(iter.as_str(), "");
+    /// # }
     /// ```
     pub fn as_str(&self) -> &'a str {
         self.iter.as_str()
     }
+
+    #[inline]
+    /// The byte offset of the next grapheme cluster to be yielded from the front, which is the
+    /// start of [`as_str`](GraphemeIndices::as_str) in the original string.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let mut iter = "a\u{301}bc".grapheme_indices(true);
+    /// assert_eq!(iter.offset(), 0);
+    /// iter.next();
+    /// assert_eq!(iter.offset(), 3);
+    /// assert_eq!(iter.next(), Some((3, "b")));
+    /// # }
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.iter.cursor.cur_cursor()
+    }
 }
 
 impl<'a> Iterator for GraphemeIndices<'a> {
@@ -54,168 +81,2446 @@ impl<'a> Iterator for GraphemeIndices<'a> {
             .map(|s| (s.as_ptr() as usize - self.start_offset, s))
     }
 
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeIndices<'a> {}
+
+impl<'a> PartialEq for GraphemeIndices<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter.cursor.cur_cursor() == other.iter.cursor.cur_cursor() && self.iter == other.iter
+    }
+}
+
+impl<'a> Eq for GraphemeIndices<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemeIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.iter
+            .next_back()
+            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    }
+}
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries).
+///
+/// This struct is created by the [`graphemes`] method on the [`UnicodeSegmentation`] trait. See its
+/// documentation for more.
+///
+/// Two `Graphemes` compare equal if their remaining text (see [`as_str`](Graphemes::as_str))
+/// and segmentation mode are the same. How much of the original string has already been
+/// consumed is not taken into account.
+///
+/// ```rust
+/// # #[cfg(feature = "str-impl")] {
+/// # use unicode_segmentation::UnicodeSegmentation;
+/// let mut a = "xa\u{301}b".graphemes(true);
+/// a.next();
+/// assert!(a == "a\u{301}b".graphemes(true));
+/// assert!(a != "a\u{301}b".graphemes(false));
+/// # }
+/// ```
+///
+/// [`graphemes`]: trait.UnicodeSegmentation.html#tymethod.graphemes
+/// [`UnicodeSegmentation`]: trait.UnicodeSegmentation.html
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    string: &'a str,
+    cursor: GraphemeCursor,
+    cursor_back: GraphemeCursor,
+    // the whole string is known to be one cluster and has not been returned yet
+    single: bool,
+}
+
+impl<'a> Graphemes<'a> {
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let mut iter = "abc".graphemes(true);
+    /// assert_eq!(iter.as_str(), "abc");
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "bc");
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "");
+    /// # }
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        &self.string[self.cursor.cur_cursor()..self.cursor_back.cur_cursor()]
+    }
+
+    #[inline]
+    /// The byte offset in the original string of the next cluster `next()` would return, or of
+    /// the end of iteration once all clusters have been returned.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let mut iter = "a\u{301}bc".graphemes(true);
+    /// assert_eq!(iter.offset(), 0);
+    /// iter.next();
+    /// assert_eq!(iter.offset(), 3);
+    /// iter.next_back();
+    /// iter.next();
+    /// assert_eq!(iter.offset(), 4);
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.offset(), 4);
+    /// # }
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.cursor.cur_cursor()
+    }
+
+    #[inline]
+    /// Returns a copy of the cursor used for forward iteration, positioned at the start of the
+    /// next cluster `next()` would return.
+    ///
+    /// The copy can be moved independently, for example to look ahead without consuming the
+    /// iterator. Its offsets are relative to the string the iterator was created from.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let s = "a\u{301}bc";
+    /// let mut iter = s.graphemes(true);
+    /// iter.next();
+    ///
+    /// let mut cursor = iter.cursor();
+    /// assert_eq!(cursor.cur_cursor(), 3);
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(4)));
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(5)));
+    ///
+    /// assert_eq!(iter.next(), Some("b"));
+    /// # }
+    /// ```
+    pub fn cursor(&self) -> GraphemeCursor {
+        self.cursor.clone()
+    }
+
+    #[inline]
+    /// The rest of the original string after the forward cursor.
+    ///
+    /// Unlike [`as_str`](Graphemes::as_str), this includes any clusters already returned by
+    /// `next_back()`. It can be given to the cursor returned by [`cursor`](Graphemes::cursor)
+    /// as a chunk starting at [`cur_cursor()`](GraphemeCursor::cur_cursor); the cursor may
+    /// then ask for pre-context from before the chunk.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let mut iter = "abc".graphemes(true);
+    /// iter.next();
+    /// iter.next_back();
+    /// assert_eq!(iter.as_str(), "b");
+    /// assert_eq!(iter.remaining_string(), "bc");
+    /// # }
+    /// ```
+    pub fn remaining_string(&self) -> &'a str {
+        &self.string[self.cursor.cur_cursor()..]
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.single {
+            return (1, Some(1));
+        }
+        let slen = self.cursor_back.cur_cursor() - self.cursor.cur_cursor();
+        (cmp::min(slen, 1), Some(slen))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.single {
+            self.single = false;
+            self.cursor.set_cursor(self.string.len());
+            return Some(self.string);
+        }
+        let start = self.cursor.cur_cursor();
+        if start == self.cursor_back.cur_cursor() {
+            return None;
+        }
+        let next = self.cursor.next_boundary(self.string, 0).unwrap().unwrap();
+        Some(&self.string[start..next])
+    }
+
+    #[inline]
+    fn count(mut self) -> usize {
+        if self.single {
+            return 1;
+        }
+        // Only the boundaries are needed, not the clusters between them
+        let end = self.cursor_back.cur_cursor();
+        let mut count = 0;
+        while self.cursor.cur_cursor() < end {
+            self.cursor.next_boundary(self.string, 0).unwrap();
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Graphemes<'a> {}
+
+impl<'a> PartialEq for Graphemes<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cursor.options() == other.cursor.options() && self.as_str() == other.as_str()
+    }
+}
+
+impl<'a> Eq for Graphemes<'a> {}
+
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.single {
+            self.single = false;
+            self.cursor_back.set_cursor(0);
+            return Some(self.string);
+        }
+        let end = self.cursor_back.cur_cursor();
+        if end == self.cursor.cur_cursor() {
+            return None;
+        }
+        let prev = self
+            .cursor_back
+            .prev_boundary(self.string, 0)
+            .unwrap()
+            .unwrap();
+        Some(&self.string[prev..end])
+    }
+}
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) and the
+/// number of combining marks in each.
+///
+/// This struct is created by the [`graphemes_with_mark_counts`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`graphemes_with_mark_counts`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_with_mark_counts
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeMarkCounts<'a> {
+    iter: Graphemes<'a>,
+}
+
+// Count the codepoints after the first whose Grapheme_Cluster_Break is Extend or SpacingMark.
+fn mark_count(cluster: &str) -> usize {
+    use crate::tables::grapheme as gr;
+    cluster
+        .chars()
+        .skip(1)
+        .filter(|&ch| {
+            ch > '\u{7e}'
+                && matches!(
+                    gr::grapheme_category(ch).2,
+                    gr::GC_Extend | gr::GC_SpacingMark
+                )
+        })
+        .count()
+}
+
+impl<'a> Iterator for GraphemeMarkCounts<'a> {
+    type Item = (&'a str, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, usize)> {
+        self.iter.next().map(|g| (g, mark_count(g)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeMarkCounts<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemeMarkCounts<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, usize)> {
+        self.iter.next_back().map(|g| (g, mark_count(g)))
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` and the number of combining marks in
+/// each. See [`UnicodeSegmentationExt::graphemes_with_mark_counts`].
+///
+/// [`UnicodeSegmentationExt::graphemes_with_mark_counts`]: crate::UnicodeSegmentationExt::graphemes_with_mark_counts
+#[inline]
+pub fn new_grapheme_mark_counts(s: &str, is_extended: bool) -> GraphemeMarkCounts<'_> {
+    GraphemeMarkCounts {
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+/// External iterator for the codepoints of a string, each as a substring.
+///
+/// This struct is created by the [`char_segments`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`char_segments`]: trait.UnicodeSegmentationExt.html#tymethod.char_segments
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct CharSegments<'a> {
+    string: &'a str,
+}
+
+impl<'a> Iterator for CharSegments<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let c = self.string.chars().next()?;
+        let (segment, rest) = self.string.split_at(c.len_utf8());
+        self.string = rest;
+        Some(segment)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.string.len();
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for CharSegments<'a> {}
+
+impl<'a> DoubleEndedIterator for CharSegments<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        let c = self.string.chars().next_back()?;
+        let (rest, segment) = self.string.split_at(self.string.len() - c.len_utf8());
+        self.string = rest;
+        Some(segment)
+    }
+}
+
+/// Returns an iterator over the `char`s of `s`, as string slices. See
+/// [`UnicodeSegmentationExt::char_segments`].
+///
+/// [`UnicodeSegmentationExt::char_segments`]: crate::UnicodeSegmentationExt::char_segments
+#[inline]
+pub fn new_char_segments(s: &str) -> CharSegments<'_> {
+    CharSegments { string: s }
+}
+
+/// External iterator for runs of adjacent grapheme clusters merged by a predicate.
+///
+/// This struct is created by the [`graphemes_coalesce`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`graphemes_coalesce`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_coalesce
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone)]
+pub struct GraphemesCoalesce<'a, F> {
+    iter: Graphemes<'a>,
+    should_merge: F,
+}
+
+impl<'a, F> fmt::Debug for GraphemesCoalesce<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphemesCoalesce")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, F: Fn(&str, &str) -> bool> Iterator for GraphemesCoalesce<'a, F> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let start = self.iter.cursor.cur_cursor();
+        let mut prev = self.iter.next()?;
+        loop {
+            let mut peek = self.iter.clone();
+            match peek.next() {
+                Some(next) if (self.should_merge)(prev, next) => {
+                    prev = next;
+                    self.iter = peek;
+                }
+                _ => break,
+            }
+        }
+        Some(&self.iter.string[start..self.iter.cursor.cur_cursor()])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (cmp::min(lower, 1), upper)
+    }
+}
+
+impl<'a, F: Fn(&str, &str) -> bool> core::iter::FusedIterator for GraphemesCoalesce<'a, F> {}
+
+impl<'a, F: Fn(&str, &str) -> bool> DoubleEndedIterator for GraphemesCoalesce<'a, F> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        let end = self.iter.cursor_back.cur_cursor();
+        let mut next = self.iter.next_back()?;
+        loop {
+            let mut peek = self.iter.clone();
+            match peek.next_back() {
+                Some(prev) if (self.should_merge)(prev, next) => {
+                    next = prev;
+                    self.iter = peek;
+                }
+                _ => break,
+            }
+        }
+        Some(&self.iter.string[self.iter.cursor_back.cur_cursor()..end])
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s`, merging neighbours for which
+/// `should_merge` returns `true`. See [`UnicodeSegmentationExt::graphemes_coalesce`].
+///
+/// [`UnicodeSegmentationExt::graphemes_coalesce`]: crate::UnicodeSegmentationExt::graphemes_coalesce
+#[inline]
+pub fn new_graphemes_coalesce<F>(
+    s: &str,
+    is_extended: bool,
+    should_merge: F,
+) -> GraphemesCoalesce<'_, F> {
+    GraphemesCoalesce {
+        iter: new_graphemes(s, is_extended),
+        should_merge,
+    }
+}
+
+/// External iterator for grapheme clusters and their byte ranges.
+///
+/// This struct is created by the [`grapheme_ranges`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`grapheme_ranges`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_ranges
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeRanges<'a> {
+    iter: Graphemes<'a>,
+}
+
+impl<'a> Iterator for GraphemeRanges<'a> {
+    type Item = (Range<usize>, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(Range<usize>, &'a str)> {
+        let start = self.iter.cursor.cur_cursor();
+        let cluster = self.iter.next()?;
+        Some((start..self.iter.cursor.cur_cursor(), cluster))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeRanges<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemeRanges<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(Range<usize>, &'a str)> {
+        let end = self.iter.cursor_back.cur_cursor();
+        let cluster = self.iter.next_back()?;
+        Some((self.iter.cursor_back.cur_cursor()..end, cluster))
+    }
+}
+
+/// Returns an iterator over the byte ranges of the grapheme clusters of `s`, together with the
+/// clusters. See [`UnicodeSegmentationExt::grapheme_ranges`].
+///
+/// [`UnicodeSegmentationExt::grapheme_ranges`]: crate::UnicodeSegmentationExt::grapheme_ranges
+#[inline]
+pub fn new_grapheme_ranges(s: &str, is_extended: bool) -> GraphemeRanges<'_> {
+    GraphemeRanges {
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+/// External iterator for grapheme clusters and their first `char`.
+///
+/// This struct is created by the [`graphemes_with_first_char`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`graphemes_with_first_char`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_with_first_char
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemesWithFirstChar<'a> {
+    iter: Graphemes<'a>,
+}
+
+impl<'a> Iterator for GraphemesWithFirstChar<'a> {
+    type Item = (&'a str, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, char)> {
+        let cluster = self.iter.next()?;
+        // clusters are never empty
+        Some((cluster, cluster.chars().next().unwrap()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemesWithFirstChar<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemesWithFirstChar<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, char)> {
+        let cluster = self.iter.next_back()?;
+        Some((cluster, cluster.chars().next().unwrap()))
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s`, each paired with its first `char`.
+/// See [`UnicodeSegmentationExt::graphemes_with_first_char`].
+///
+/// [`UnicodeSegmentationExt::graphemes_with_first_char`]: crate::UnicodeSegmentationExt::graphemes_with_first_char
+#[inline]
+pub fn new_graphemes_with_first_char(s: &str, is_extended: bool) -> GraphemesWithFirstChar<'_> {
+    GraphemesWithFirstChar {
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+/// A single [grapheme cluster](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// of a string.
+///
+/// This is returned by the [`graphemes_typed`] and [`grapheme_indices_typed`] methods on the
+/// [`UnicodeSegmentationExt`] trait, so that a function taking a `Grapheme` can only be given a
+/// whole cluster. It dereferences to `str` and compares equal to the string it wraps.
+///
+/// [`graphemes_typed`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_typed
+/// [`grapheme_indices_typed`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_indices_typed
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grapheme<'a>(&'a str);
+
+impl<'a> Grapheme<'a> {
+    /// View the cluster as a slice of the string it was found in.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// The length of the cluster in bytes.
+    #[inline]
+    pub fn len_bytes(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the `char`s of the cluster.
+    #[inline]
+    pub fn chars(&self) -> core::str::Chars<'a> {
+        self.0.chars()
+    }
+}
+
+impl<'a> core::ops::Deref for Grapheme<'a> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> AsRef<str> for Grapheme<'a> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> From<Grapheme<'a>> for &'a str {
+    #[inline]
+    fn from(g: Grapheme<'a>) -> &'a str {
+        g.0
+    }
+}
+
+impl<'a> PartialEq<str> for Grapheme<'a> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for Grapheme<'a> {
+    #[inline]
+    fn eq(&self, other: &&'b str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> fmt::Display for Grapheme<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) as
+/// [`Grapheme`]s.
+///
+/// This struct is created by the [`graphemes_typed`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`graphemes_typed`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_typed
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemesTyped<'a> {
+    iter: Graphemes<'a>,
+}
+
+impl<'a> Iterator for GraphemesTyped<'a> {
+    type Item = Grapheme<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Grapheme<'a>> {
+        self.iter.next().map(Grapheme)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemesTyped<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemesTyped<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Grapheme<'a>> {
+        self.iter.next_back().map(Grapheme)
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` as [`Grapheme`]s. See
+/// [`UnicodeSegmentationExt::graphemes_typed`].
+///
+/// [`UnicodeSegmentationExt::graphemes_typed`]: crate::UnicodeSegmentationExt::graphemes_typed
+#[inline]
+pub fn new_graphemes_typed(s: &str, is_extended: bool) -> GraphemesTyped<'_> {
+    GraphemesTyped {
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+/// External iterator for [`Grapheme`]s and their byte offsets.
+///
+/// This struct is created by the [`grapheme_indices_typed`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`grapheme_indices_typed`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_indices_typed
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeIndicesTyped<'a> {
+    iter: GraphemeIndices<'a>,
+}
+
+impl<'a> Iterator for GraphemeIndicesTyped<'a> {
+    type Item = (usize, Grapheme<'a>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, Grapheme<'a>)> {
+        self.iter.next().map(|(i, g)| (i, Grapheme(g)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeIndicesTyped<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemeIndicesTyped<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, Grapheme<'a>)> {
+        self.iter.next_back().map(|(i, g)| (i, Grapheme(g)))
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` as [`Grapheme`]s and their byte
+/// offsets. See [`UnicodeSegmentationExt::grapheme_indices_typed`].
+///
+/// [`UnicodeSegmentationExt::grapheme_indices_typed`]: crate::UnicodeSegmentationExt::grapheme_indices_typed
+#[inline]
+pub fn new_grapheme_indices_typed(s: &str, is_extended: bool) -> GraphemeIndicesTyped<'_> {
+    GraphemeIndicesTyped {
+        iter: new_grapheme_indices(s, is_extended),
+    }
+}
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) as
+/// mutable slices.
+///
+/// This struct is created by the [`new_graphemes_mut`] function. See its documentation for
+/// more.
+///
+/// [`new_graphemes_mut`]: fn.new_graphemes_mut.html
+#[derive(Debug)]
+pub struct GraphemesMut<'a> {
+    // The part not yet iterated, which is split like `str::split_at_mut` as clusters are taken
+    rest: &'a mut str,
+    is_extended: bool,
+}
+
+impl<'a> Iterator for GraphemesMut<'a> {
+    type Item = &'a mut str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut str> {
+        // Clusters start at a boundary, so the rules never need the text already taken
+        let len = new_graphemes(self.rest, self.is_extended).next()?.len();
+        let (cluster, rest) = core::mem::take(&mut self.rest).split_at_mut(len);
+        self.rest = rest;
+        Some(cluster)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let slen = self.rest.len();
+        (cmp::min(slen, 1), Some(slen))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemesMut<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemesMut<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut str> {
+        let len = new_graphemes(self.rest, self.is_extended)
+            .next_back()?
+            .len();
+        let mid = self.rest.len() - len;
+        let (rest, cluster) = core::mem::take(&mut self.rest).split_at_mut(mid);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` as mutable slices, so that clusters
+/// can be changed in place. See [`UnicodeSegmentation::graphemes`] for more information.
+///
+/// The slices don't overlap, and are taken from the front or the back as with
+/// `str::split_at_mut`.
+///
+/// [`UnicodeSegmentation::graphemes`]: crate::UnicodeSegmentation::graphemes
+///
+/// # Examples
+///
+/// ```
+/// # use unicode_segmentation::new_graphemes_mut;
+/// let mut s = String::from("a\u{310}e\u{301}o\u{308}\u{332}");
+/// for cluster in new_graphemes_mut(&mut s, true).step_by(2) {
+///     cluster.make_ascii_uppercase();
+/// }
+///
+/// assert_eq!(s, "A\u{310}e\u{301}O\u{308}\u{332}");
+/// ```
+#[inline]
+pub fn new_graphemes_mut(s: &mut str, is_extended: bool) -> GraphemesMut<'_> {
+    GraphemesMut {
+        rest: s,
+        is_extended,
+    }
+}
+
+/// External iterator for runs of a fixed number of grapheme clusters.
+///
+/// This struct is created by the [`grapheme_chunks`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`grapheme_chunks`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_chunks
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeChunks<'a> {
+    iter: Graphemes<'a>,
+    clusters_per_chunk: usize,
+}
+
+impl<'a> Iterator for GraphemeChunks<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.iter.as_str();
+        let len = self
+            .iter
+            .by_ref()
+            .take(self.clusters_per_chunk)
+            .map(str::len)
+            .sum();
+        if len == 0 {
+            None
+        } else {
+            Some(&rest[..len])
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let chunks = |clusters: usize| clusters.div_ceil(self.clusters_per_chunk);
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeChunks<'a> {}
+
+/// Returns an iterator over slices of `s` holding `clusters_per_chunk` grapheme clusters each.
+/// See [`UnicodeSegmentationExt::grapheme_chunks`].
+///
+/// [`UnicodeSegmentationExt::grapheme_chunks`]: crate::UnicodeSegmentationExt::grapheme_chunks
+#[inline]
+pub fn new_grapheme_chunks(
+    s: &str,
+    clusters_per_chunk: usize,
+    is_extended: bool,
+) -> GraphemeChunks<'_> {
+    assert!(
+        clusters_per_chunk != 0,
+        "clusters per chunk must be non-zero"
+    );
+    GraphemeChunks {
+        iter: new_graphemes(s, is_extended),
+        clusters_per_chunk,
+    }
+}
+
+/// External iterator for grapheme clusters and a hash of each.
+///
+/// This struct is created by the [`graphemes_with_hash`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`graphemes_with_hash`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_with_hash
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeHashes<'a> {
+    iter: Graphemes<'a>,
+}
+
+impl<'a> Iterator for GraphemeHashes<'a> {
+    type Item = (&'a str, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, u64)> {
+        let cluster = self.iter.next()?;
+        Some((cluster, fx_hash(cluster.as_bytes())))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeHashes<'a> {}
+
+impl<'a> DoubleEndedIterator for GraphemeHashes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, u64)> {
+        let cluster = self.iter.next_back()?;
+        Some((cluster, fx_hash(cluster.as_bytes())))
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` and their hashes. See
+/// [`UnicodeSegmentationExt::graphemes_with_hash`].
+///
+/// [`UnicodeSegmentationExt::graphemes_with_hash`]: crate::UnicodeSegmentationExt::graphemes_with_hash
+#[inline]
+pub fn new_grapheme_hashes(s: &str, is_extended: bool) -> GraphemeHashes<'_> {
+    GraphemeHashes {
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+// The multiplicative hash used by Firefox and rustc (FxHash), taking eight bytes at a time.
+// Clusters are short, so this is much cheaper than a general purpose hash.
+#[inline]
+fn fx_hash(bytes: &[u8]) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+    let add = |hash: u64, word: u64| (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+
+    let mut hash = add(0, bytes.len() as u64);
+    let mut words = bytes.chunks_exact(8);
+    for word in &mut words {
+        let mut le = [0; 8];
+        le.copy_from_slice(word);
+        hash = add(hash, u64::from_le_bytes(le));
+    }
+    for &byte in words.remainder() {
+        hash = add(hash, u64::from(byte));
+    }
+    hash
+}
+
+/// External iterator for the byte offsets of all the
+/// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// of a string, including its start and end.
+///
+/// This struct is created by the [`grapheme_boundary_indices`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`grapheme_boundary_indices`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_boundary_indices
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeBoundaryIndices<'a> {
+    string: &'a str,
+    cursor: GraphemeCursor,
+    done: bool,
+}
+
+impl<'a> Iterator for GraphemeBoundaryIndices<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let boundary = self.cursor.cur_cursor();
+        if self.cursor.next_boundary(self.string, 0).unwrap().is_none() {
+            self.done = true;
+        }
+        Some(boundary)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let slen = self.string.len() - self.cursor.cur_cursor();
+        (cmp::min(slen, 1) + 1, Some(slen + 1))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeBoundaryIndices<'a> {}
+
+/// Returns an iterator over the grapheme cluster boundaries of `s`, including both ends. See
+/// [`UnicodeSegmentationExt::grapheme_boundary_indices`].
+///
+/// [`UnicodeSegmentationExt::grapheme_boundary_indices`]: crate::UnicodeSegmentationExt::grapheme_boundary_indices
+#[inline]
+pub fn new_grapheme_boundary_indices(s: &str, is_extended: bool) -> GraphemeBoundaryIndices<'_> {
+    GraphemeBoundaryIndices {
+        string: s,
+        cursor: GraphemeCursor::new(0, s.len(), is_extended),
+        done: false,
+    }
+}
+
+/// External iterator for the byte offsets at which the extended and legacy
+/// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// of a string differ.
+///
+/// This struct is created by the [`grapheme_mode_diff`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`grapheme_mode_diff`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_mode_diff
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeModeDiff<'a> {
+    extended: Peekable<GraphemeBoundaryIndices<'a>>,
+    legacy: Peekable<GraphemeBoundaryIndices<'a>>,
+}
+
+impl<'a> Iterator for GraphemeModeDiff<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            match (self.extended.peek(), self.legacy.peek()) {
+                (Some(ext), Some(leg)) if ext == leg => {
+                    self.extended.next();
+                    self.legacy.next();
+                }
+                (Some(ext), Some(leg)) if ext < leg => return self.extended.next(),
+                (_, Some(_)) => return self.legacy.next(),
+                (Some(_), None) => return self.extended.next(),
+                (None, None) => return None,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, ext) = self.extended.size_hint();
+        let (_, leg) = self.legacy.size_hint();
+        (0, ext.and_then(|ext| leg.map(|leg| ext + leg)))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeModeDiff<'a> {}
+
+/// Returns an iterator over the places where the extended and legacy grapheme clusters of `s`
+/// differ. See [`UnicodeSegmentationExt::grapheme_mode_diff`].
+///
+/// [`UnicodeSegmentationExt::grapheme_mode_diff`]: crate::UnicodeSegmentationExt::grapheme_mode_diff
+#[inline]
+pub fn new_grapheme_mode_diff(s: &str) -> GraphemeModeDiff<'_> {
+    GraphemeModeDiff {
+        extended: new_grapheme_boundary_indices(s, true).peekable(),
+        legacy: new_grapheme_boundary_indices(s, false).peekable(),
+    }
+}
+
+/// External iterator for the
+/// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// within a byte range of a string.
+///
+/// This struct is created by the [`grapheme_boundaries_in`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`grapheme_boundaries_in`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_boundaries_in
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeBoundariesIn<'a> {
+    string: &'a str,
+    cursor: GraphemeCursor,
+    end: usize,
+}
+
+impl<'a> Iterator for GraphemeBoundariesIn<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.cursor.cur_cursor() >= self.end {
+            return None;
+        }
+        match self.cursor.next_boundary(self.string, 0).unwrap() {
+            Some(boundary) if boundary < self.end => Some(boundary),
+            _ => {
+                self.cursor.set_cursor(self.end);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let slen = self.end.saturating_sub(self.cursor.cur_cursor());
+        (0, Some(slen.saturating_sub(1)))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeBoundariesIn<'a> {}
+
+/// Returns an iterator over the grapheme cluster boundaries of `s` within `range`. See
+/// [`UnicodeSegmentationExt::grapheme_boundaries_in`].
+///
+/// [`UnicodeSegmentationExt::grapheme_boundaries_in`]: crate::UnicodeSegmentationExt::grapheme_boundaries_in
+#[inline]
+pub fn new_grapheme_boundaries_in(
+    s: &str,
+    range: Range<usize>,
+    is_extended: bool,
+) -> GraphemeBoundariesIn<'_> {
+    assert!(
+        range.start <= range.end
+            && s.is_char_boundary(range.start)
+            && s.is_char_boundary(range.end),
+        "range {:?} is not a valid range of char boundaries of the string",
+        range
+    );
+    GraphemeBoundariesIn {
+        string: s,
+        cursor: GraphemeCursor::new(range.start, s.len(), is_extended),
+        end: range.end,
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s`. See
+/// [`UnicodeSegmentation::graphemes`].
+///
+/// [`UnicodeSegmentation::graphemes`]: crate::UnicodeSegmentation::graphemes
+#[inline]
+pub fn new_graphemes(s: &str, is_extended: bool) -> Graphemes<'_> {
+    let len = s.len();
+    Graphemes {
+        string: s,
+        cursor: GraphemeCursor::new(0, len, is_extended),
+        cursor_back: GraphemeCursor::new(len, len, is_extended),
+        single: is_trivially_single(s, is_extended),
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s`, as tailored by `options`. See
+/// [`UnicodeSegmentationExt::graphemes_with`].
+///
+/// [`UnicodeSegmentationExt::graphemes_with`]: crate::UnicodeSegmentationExt::graphemes_with
+#[inline]
+pub fn new_graphemes_with(s: &str, options: GraphemeOptions) -> Graphemes<'_> {
+    let len = s.len();
+    Graphemes {
+        string: s,
+        cursor: GraphemeCursor::with_options(0, len, options),
+        cursor_back: GraphemeCursor::with_options(len, len, options),
+        single: false,
+    }
+}
+
+// A cheap check for the common short strings that are a single cluster without needing a
+// cursor: one codepoint, a pair of regional indicators, or a base followed only by codepoints
+// that always join onto it (GB9, GB9a and GB11). Returns false if unsure.
+fn is_trivially_single(s: &str, is_extended: bool) -> bool {
+    use crate::tables::grapheme::grapheme_category;
+    use crate::tables::grapheme::GraphemeCat::*;
+
+    let mut chars = s.chars();
+    let first = match chars.next() {
+        Some(c) => grapheme_category(c).2,
+        None => return false,
+    };
+    if chars.as_str().is_empty() {
+        return true;
+    }
+    match first {
+        GC_Control | GC_CR | GC_LF => return false,
+        GC_Regional_Indicator => {
+            return chars.as_str().len() == 4
+                && chars.next().map(|c| grapheme_category(c).2) == Some(GC_Regional_Indicator)
+        }
+        _ => {}
+    }
+    // whether the codepoints so far are an emoji sequence that GB11 can continue
+    let mut emoji = first == GC_Extended_Pictographic;
+    let mut prev = first;
+    for c in chars {
+        let cat = grapheme_category(c).2;
+        match cat {
+            GC_Extend | GC_ZWJ => emoji &= prev != GC_ZWJ,
+            GC_SpacingMark if is_extended => emoji = false,
+            GC_Extended_Pictographic if emoji && prev == GC_ZWJ => {}
+            _ => return false,
+        }
+        prev = cat;
+    }
+    true
+}
+
+/// Whether `s` is exactly one grapheme cluster. See
+/// [`UnicodeSegmentationExt::is_single_grapheme`].
+///
+/// [`UnicodeSegmentationExt::is_single_grapheme`]: crate::UnicodeSegmentationExt::is_single_grapheme
+#[inline]
+pub fn is_single_grapheme(s: &str, is_extended: bool) -> bool {
+    if is_trivially_single(s, is_extended) {
+        return true;
+    }
+    if s.is_empty() {
+        return false;
+    }
+    let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+    cursor.next_boundary(s, 0) == Ok(Some(s.len()))
+}
+
+/// The length in bytes of the grapheme cluster of `s` starting at `offset`, if one does. See
+/// [`UnicodeSegmentationExt::grapheme_len_at`].
+///
+/// [`UnicodeSegmentationExt::grapheme_len_at`]: crate::UnicodeSegmentationExt::grapheme_len_at
+#[inline]
+pub fn grapheme_len_at(s: &str, offset: usize, is_extended: bool) -> Option<usize> {
+    if offset >= s.len() || !s.is_char_boundary(offset) {
+        return None;
+    }
+    let mut cursor = GraphemeCursor::new(offset, s.len(), is_extended);
+    if !cursor.is_boundary(s, 0).unwrap() {
+        return None;
+    }
+    let end = cursor.next_boundary(s, 0).unwrap()?;
+    Some(end - offset)
+}
+
+// The byte range of the cluster containing `offset`, which must be less than `s.len()` but
+// need not be on a character boundary.
+fn cluster_range_at(s: &str, offset: usize, is_extended: bool) -> Range<usize> {
+    let mut floor = offset;
+    while !s.is_char_boundary(floor) {
+        floor -= 1;
+    }
+    let mut cursor = GraphemeCursor::new(floor, s.len(), is_extended);
+    let start = if cursor.is_boundary(s, 0).unwrap() {
+        floor
+    } else {
+        cursor.clone().prev_boundary(s, 0).unwrap().unwrap_or(0)
+    };
+    let end = cursor.next_boundary(s, 0).unwrap().unwrap_or(s.len());
+    start..end
+}
+
+/// The grapheme cluster boundary of `s` nearest to `offset`. See
+/// [`UnicodeSegmentationExt::snap_to_grapheme_boundary`].
+///
+/// [`UnicodeSegmentationExt::snap_to_grapheme_boundary`]: crate::UnicodeSegmentationExt::snap_to_grapheme_boundary
+#[inline]
+pub fn snap_to_grapheme_boundary(s: &str, offset: usize, is_extended: bool) -> usize {
+    if offset >= s.len() {
+        return s.len();
+    }
+    let Range { start, end } = cluster_range_at(s, offset, is_extended);
+    if end - offset < offset - start {
+        end
+    } else {
+        start
+    }
+}
+
+/// Whether the byte at `offset` in `s` is part of an emoji sequence. See
+/// [`UnicodeSegmentationExt::in_emoji_sequence`].
+///
+/// [`UnicodeSegmentationExt::in_emoji_sequence`]: crate::UnicodeSegmentationExt::in_emoji_sequence
+#[inline]
+pub fn in_emoji_sequence(s: &str, offset: usize) -> bool {
+    use crate::tables::grapheme as gr;
+
+    if offset >= s.len() {
+        return false;
+    }
+    let cluster = &s[cluster_range_at(s, offset, true)];
+    let mut chars = cluster.chars();
+    let first = chars.next().unwrap();
+    if chars.as_str().is_empty() {
+        return false;
+    }
+    match gr::grapheme_category(first).2 {
+        gr::GC_Extended_Pictographic | gr::GC_Regional_Indicator => true,
+        // a keycap sequence
+        _ => matches!(first, '0'..='9' | '#' | '*') && chars.any(|c| c == '\u{20e3}'),
+    }
+}
+
+/// The offset of the start of the last grapheme cluster of `s`, or 0. See
+/// [`UnicodeSegmentationExt::last_grapheme_boundary`].
+///
+/// [`UnicodeSegmentationExt::last_grapheme_boundary`]: crate::UnicodeSegmentationExt::last_grapheme_boundary
+#[inline]
+pub fn last_grapheme_boundary(s: &str, is_extended: bool) -> usize {
+    let mut cursor = GraphemeCursor::new(s.len(), s.len(), is_extended);
+    cursor.prev_boundary(s, 0).unwrap().unwrap_or(0)
+}
+
+/// The number of grapheme clusters of `s` that end at or before `offset`. See
+/// [`UnicodeSegmentationExt::grapheme_count_to`].
+///
+/// [`UnicodeSegmentationExt::grapheme_count_to`]: crate::UnicodeSegmentationExt::grapheme_count_to
+#[inline]
+pub fn grapheme_count_to(s: &str, offset: usize, is_extended: bool) -> usize {
+    assert!(
+        offset <= s.len(),
+        "offset {} is out of bounds of the string",
+        offset
+    );
+    let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+    let mut count = 0;
+    while let Some(boundary) = cursor.next_boundary(s, 0).unwrap() {
+        if boundary > offset {
+            break;
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Returns the number of
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) in
+/// `bytes`, which are expected to be UTF-8.
+///
+/// For valid UTF-8 this is the same as `str::from_utf8(bytes).unwrap().graphemes(is_extended)
+/// .count()`, without the caller needing a `&str`. The bytes aren't validated as a whole first:
+/// each character is decoded as the cursor reaches it. Passing bytes that aren't UTF-8 is not
+/// undefined behavior: every byte that isn't part of valid UTF-8 counts as a cluster by itself,
+/// and no cluster continues past it, as in [`word_bounds_bytes`](crate::word_bounds_bytes).
+///
+/// ```rust
+/// # use unicode_segmentation::grapheme_count_bytes;
+/// assert_eq!(grapheme_count_bytes("a\u{301}\r\n\u{1f1f7}\u{1f1fa}".as_bytes(), true), 3);
+/// assert_eq!(grapheme_count_bytes(b"ab\xff\xfecd", true), 6);
+/// ```
+pub fn grapheme_count_bytes(bytes: &[u8], is_extended: bool) -> usize {
+    let mut count = 0;
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let first = match decode_utf8(rest) {
+            Some((c, _)) => c,
+            None => {
+                count += 1;
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        // Give the run of valid UTF-8 at the start of `rest` to a cursor one character at a
+        // time, each encoded again to make it a `&str`.
+        let mut cursor = GraphemeCursor::new(0, rest.len(), is_extended);
+        let mut buf = [0; 4];
+        let mut chunk: &str = first.encode_utf8(&mut buf);
+        let mut chunk_start = 0;
+        let run_len = loop {
+            match cursor.next_boundary(chunk, chunk_start) {
+                Ok(Some(_)) => count += 1,
+                Ok(None) => break rest.len(),
+                Err(GraphemeIncomplete::NextChunk) => {
+                    chunk_start += chunk.len();
+                    match decode_utf8(&rest[chunk_start..]) {
+                        Some((c, _)) => chunk = c.encode_utf8(&mut buf),
+                        None => {
+                            // the last cluster of the run ends before the invalid byte
+                            count += 1;
+                            break chunk_start;
+                        }
+                    }
+                }
+                Err(GraphemeIncomplete::PreContext(end)) => {
+                    let start = (0..end).rev().find(|&i| rest[i] & 0xc0 != 0x80).unwrap();
+                    let (c, _) = decode_utf8(&rest[start..end]).unwrap();
+                    cursor.provide_context(c.encode_utf8(&mut [0; 4]), start);
+                }
+                Err(e) => unreachable!("{:?} with every chunk given", e),
+            }
+        };
+        rest = &rest[run_len..];
+    }
+    count
+}
+
+// Decode the character at the start of `bytes` and return it with its length, or `None` if
+// `bytes` doesn't start with valid UTF-8.
+fn decode_utf8(bytes: &[u8]) -> Option<(char, usize)> {
+    let first = *bytes.first()?;
+    let (len, min) = match first {
+        0x00..=0x7f => return Some((first as char, 1)),
+        0xc2..=0xdf => (2, 0x80),
+        0xe0..=0xef => (3, 0x800),
+        0xf0..=0xf4 => (4, 0x10000),
+        _ => return None,
+    };
+    let mut c = (first & 0x7f >> len) as u32;
+    for i in 1..len {
+        let b = *bytes.get(i)?;
+        if b & 0xc0 != 0x80 {
+            return None;
+        }
+        c = c << 6 | (b & 0x3f) as u32;
+    }
+    if c < min {
+        return None;
+    }
+    // rejects surrogates and values past U+10FFFF
+    core::char::from_u32(c).map(|c| (c, len))
+}
+
+/// Returns a [grapheme cluster
+/// boundary](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) of `s` at or
+/// before the byte offset `approx`, found without looking at the text before it.
+///
+/// This is useful for splitting a long text into pieces that can be segmented independently,
+/// for example on several threads: the clusters of `&s[..i]` and `&s[i..]`, where `i` is the
+/// offset returned, are exactly the clusters of `s`. The returned offset is a boundary in
+/// both legacy and extended mode.
+///
+/// Only the 64 characters before `approx` are examined, however long the string, looking for
+/// a pair of characters that no preceding text can join. This skips past runs of combining
+/// marks and joiners, and past runs of regional indicators, whose pairing into flags depends
+/// on how many come before. If no such pair is found within that distance, 0 is returned.
+/// An `approx` at or past the end of `s` returns `s.len()`, and one inside a character is
+/// treated as the start of that character.
+///
+/// ```rust
+/// # use unicode_segmentation::find_safe_grapheme_boundary;
+/// let s = "ab\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}e\u{301}";
+/// // between the two flags, but that depends on how many indicators come first
+/// assert_eq!(find_safe_grapheme_boundary(s, 10), 2);
+/// // inside the last character
+/// assert_eq!(find_safe_grapheme_boundary(s, 20), 18);
+/// // before the combining mark
+/// assert_eq!(find_safe_grapheme_boundary(s, 19), 18);
+/// assert_eq!(find_safe_grapheme_boundary(s, 100), s.len());
+/// ```
+pub fn find_safe_grapheme_boundary(s: &str, approx: usize) -> usize {
+    use crate::tables::grapheme as gr;
+    const MAX_LOOKBACK: usize = 64;
+
+    if approx >= s.len() {
+        return s.len();
+    }
+    let mut offset = approx;
+    while !s.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let after = s[offset..].chars().next().unwrap();
+    let mut after_cat = gr::grapheme_category(after).2;
+    for before in s[..offset].chars().rev().take(MAX_LOOKBACK) {
+        let before_cat = gr::grapheme_category(before).2;
+        // anything other than a plain break may depend on what comes before `before`
+        if check_pair(before_cat, after_cat) == PairResult::Break {
+            return offset;
+        }
+        offset -= before.len_utf8();
+        after_cat = before_cat;
+    }
+    0
+}
+
+/// Calls `f` with each grapheme cluster boundary of `s`, until it returns `ControlFlow::Break`.
+/// See [`UnicodeSegmentationExt::for_each_grapheme_boundary`].
+///
+/// [`UnicodeSegmentationExt::for_each_grapheme_boundary`]: crate::UnicodeSegmentationExt::for_each_grapheme_boundary
+#[inline]
+pub fn for_each_grapheme_boundary(
+    s: &str,
+    is_extended: bool,
+    mut f: impl FnMut(usize) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    if s.is_empty() {
+        return ControlFlow::Continue(());
+    }
+    f(0)?;
+    let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+    while let Some(offset) = cursor.next_boundary(s, 0).unwrap() {
+        f(offset)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Returns an iterator over the grapheme clusters of `s` and their byte offsets. See
+/// [`UnicodeSegmentation::grapheme_indices`].
+///
+/// [`UnicodeSegmentation::grapheme_indices`]: crate::UnicodeSegmentation::grapheme_indices
+#[inline]
+pub fn new_grapheme_indices(s: &str, is_extended: bool) -> GraphemeIndices<'_> {
+    GraphemeIndices {
+        start_offset: s.as_ptr() as usize,
+        iter: new_graphemes(s, is_extended),
+    }
+}
+
+/// A saved position for resuming grapheme cluster iteration over a string.
+///
+/// Unlike [`Graphemes`], a token does not borrow the string it was created from, so it can be
+/// kept across yields and turned back into an iterator later. Tokens are created by the
+/// [`grapheme_state_at`] method on the [`UnicodeSegmentationExt`] trait and consumed by
+/// [`resume_graphemes`].
+///
+/// The token carries the cursor's cached context around its offset. To persist it elsewhere,
+/// encode it with [`to_bytes`](GraphemeResumeToken::to_bytes) and decode it with
+/// [`from_bytes`](GraphemeResumeToken::from_bytes).
+///
+/// [`grapheme_state_at`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_state_at
+/// [`resume_graphemes`]: trait.UnicodeSegmentationExt.html#tymethod.resume_graphemes
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeResumeToken {
+    cursor: GraphemeCursor,
+}
+
+impl GraphemeResumeToken {
+    #[inline]
+    /// The byte offset at which iteration resumes. This is always a grapheme cluster boundary.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "a\u{301}bc";
+    /// assert_eq!(s.grapheme_state_at(1, true).offset(), 0);
+    /// assert_eq!(s.grapheme_state_at(3, true).offset(), 3);
+    /// # }
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.cursor.cur_cursor()
+    }
+
+    #[inline]
+    /// Whether the token resumes iteration over extended or legacy grapheme clusters.
+    pub fn is_extended(&self) -> bool {
+        self.cursor.option(IS_EXTENDED)
+    }
+
+    /// The length in bytes of a token encoded by `to_bytes`.
+    pub const ENCODED_LEN: usize = 17;
+
+    /// Encode the token as bytes, which `from_bytes` turns back into a token resuming at the
+    /// same position, on any target.
+    ///
+    /// The encoding is the offset and the length of the string, as little-endian `u64`s,
+    /// followed by 1 for extended grapheme clusters or 0 for legacy ones. The cached context
+    /// isn't part of it, and is found again in the string when iteration resumes.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{GraphemeResumeToken, UnicodeSegmentationExt};
+    /// let s = "a\u{310}e\u{301}o\u{308}\u{332}";
+    /// let bytes = s.grapheme_state_at(3, true).to_bytes();
+    ///
+    /// let token = GraphemeResumeToken::from_bytes(bytes).unwrap();
+    /// let rest = s.resume_graphemes(token).collect::<Vec<&str>>();
+    /// assert_eq!(rest, ["e\u{301}", "o\u{308}\u{332}"]);
+    /// # }
+    /// ```
+    pub fn to_bytes(&self) -> [u8; GraphemeResumeToken::ENCODED_LEN] {
+        let mut bytes = [0; GraphemeResumeToken::ENCODED_LEN];
+        bytes[..8].copy_from_slice(&(self.offset() as u64).to_le_bytes());
+        bytes[8..16].copy_from_slice(&(self.cursor.len as u64).to_le_bytes());
+        bytes[16] = self.is_extended() as u8;
+        bytes
+    }
+
+    /// Decode a token encoded by `to_bytes`. Returns `None` if `bytes` isn't such an encoding,
+    /// or has an offset or length too large for this target.
+    ///
+    /// The token must still be used with the string it was created for.
+    pub fn from_bytes(
+        bytes: [u8; GraphemeResumeToken::ENCODED_LEN],
+    ) -> Option<GraphemeResumeToken> {
+        use core::convert::TryFrom;
+
+        let mut offset = [0; 8];
+        offset.copy_from_slice(&bytes[..8]);
+        let offset = usize::try_from(u64::from_le_bytes(offset)).ok()?;
+        let mut len = [0; 8];
+        len.copy_from_slice(&bytes[8..16]);
+        let len = usize::try_from(u64::from_le_bytes(len)).ok()?;
+        let is_extended = match bytes[16] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        if offset > len {
+            return None;
+        }
+        // the offset of a token is always a boundary
+        let mut cursor = GraphemeCursor::new(offset, len, is_extended);
+        cursor.set_state(GraphemeState::Break);
+        Some(GraphemeResumeToken { cursor })
+    }
+}
+
+/// Returns a token recording the state of grapheme segmentation of `s` at `offset`. See
+/// [`UnicodeSegmentationExt::grapheme_state_at`].
+///
+/// [`UnicodeSegmentationExt::grapheme_state_at`]: crate::UnicodeSegmentationExt::grapheme_state_at
+#[inline]
+pub fn new_grapheme_resume_token(s: &str, offset: usize, is_extended: bool) -> GraphemeResumeToken {
+    assert!(
+        s.is_char_boundary(offset),
+        "offset {} is not a char boundary of the string",
+        offset
+    );
+    let mut cursor = GraphemeCursor::new(offset, s.len(), is_extended);
+    if !cursor.is_boundary(s, 0).unwrap() {
+        cursor.prev_boundary(s, 0).unwrap();
+    }
+    GraphemeResumeToken { cursor }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` from the position saved in `token`.
+/// See [`UnicodeSegmentationExt::resume_graphemes`].
+///
+/// [`UnicodeSegmentationExt::resume_graphemes`]: crate::UnicodeSegmentationExt::resume_graphemes
+#[inline]
+pub fn resume_graphemes(s: &str, token: GraphemeResumeToken) -> Graphemes<'_> {
+    let len = s.len();
+    assert_eq!(
+        token.cursor.len, len,
+        "token was created for a string of a different length"
+    );
+    Graphemes {
+        string: s,
+        cursor_back: GraphemeCursor::with_options(len, len, token.cursor.options()),
+        cursor: token.cursor,
+        single: false,
+    }
+}
+
+/// A string stored as a sequence of chunks, such as a rope.
+///
+/// Implementing this trait allows grapheme clusters to be found with [`graphemes_over`]
+/// without first copying the text into a contiguous `String`.
+pub trait TextSource {
+    /// Returns the chunk containing the byte at offset `byte`, together with the offset of
+    /// the start of that chunk. `byte` is always less than the total length of the text.
+    fn chunk_at(&self, byte: usize) -> (&str, usize);
+}
+
+impl TextSource for str {
+    #[inline]
+    fn chunk_at(&self, _byte: usize) -> (&str, usize) {
+        (self, 0)
+    }
+}
+
+/// External iterator for the byte ranges of the grapheme clusters of a [`TextSource`].
+///
+/// This struct is created by the [`graphemes_over`] function. See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct GraphemesOver<'a, S: ?Sized> {
+    source: &'a S,
+    cursor: GraphemeCursor,
+}
+
+impl<'a, S: TextSource + ?Sized> Iterator for GraphemesOver<'a, S> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let slen = self.cursor.len - self.cursor.cur_cursor();
+        (cmp::min(slen, 1), Some(slen))
+    }
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let start = self.cursor.cur_cursor();
+        if start == self.cursor.len {
+            return None;
+        }
+        let (mut chunk, mut chunk_start) = self.source.chunk_at(start);
+        loop {
+            match self.cursor.next_boundary(chunk, chunk_start) {
+                Ok(Some(end)) => return Some(start..end),
+                Ok(None) => return None,
+                Err(GraphemeIncomplete::NextChunk) => {
+                    let (next, next_start) = self.source.chunk_at(chunk_start + chunk.len());
+                    chunk = next;
+                    chunk_start = next_start;
+                }
+                Err(GraphemeIncomplete::PreContext(n)) => {
+                    let (ctx, ctx_start) = self.source.chunk_at(n - 1);
+                    self.cursor
+                        .provide_context(&ctx[..n - ctx_start], ctx_start);
+                }
+                Err(e) => panic!("chunk_at returned an inconsistent chunk: {:?}", e),
+            }
+        }
+    }
+}
+
+impl<'a, S: TextSource + ?Sized> core::iter::FusedIterator for GraphemesOver<'a, S> {}
+
+/// Returns an iterator over the byte ranges of the grapheme clusters of a chunked text
+/// of `total_len` bytes. See [`UnicodeSegmentation::graphemes`] for the meaning of
+/// `is_extended`.
+///
+/// Chunks are requested from `source` only as the underlying [`GraphemeCursor`] needs them,
+/// so clusters spanning several chunks are handled without copying.
+///
+/// [`UnicodeSegmentation::graphemes`]: trait.UnicodeSegmentation.html#tymethod.graphemes
+///
+/// ```rust
+/// # use unicode_segmentation::{graphemes_over, TextSource};
+/// struct Rope(Vec<&'static str>);
+///
+/// impl TextSource for Rope {
+///     fn chunk_at(&self, byte: usize) -> (&str, usize) {
+///         let mut start = 0;
+///         for chunk in &self.0 {
+///             if byte < start + chunk.len() {
+///                 return (chunk, start);
+///             }
+///             start += chunk.len();
+///         }
+///         unreachable!()
+///     }
+/// }
+///
+/// let rope = Rope(vec!["a\r", "\n\u{1F1F7}\u{1F1F8}", "e", "\u{301}"]);
+/// let ranges = graphemes_over(&rope, 14, true).collect::<Vec<_>>();
+/// assert_eq!(ranges, [0..1, 1..3, 3..11, 11..14]);
+/// ```
+#[inline]
+pub fn graphemes_over<S: TextSource + ?Sized>(
+    source: &S,
+    total_len: usize,
+    is_extended: bool,
+) -> GraphemesOver<'_, S> {
+    GraphemesOver {
+        source,
+        cursor: GraphemeCursor::new(0, total_len, is_extended),
+    }
+}
+
+/// maybe unify with PairResult?
+/// An enum describing information about a potential boundary.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(u8)]
+enum GraphemeState {
+    /// No information is known.
+    Unknown,
+    /// It is known to not be a boundary.
+    NotBreak,
+    /// It is known to be a boundary.
+    Break,
+    /// The codepoint after it has Indic_Conjunct_Break=Consonant,
+    /// so there is a break before so a boundary if it is preceded by another
+    /// InCB=Consonant follwoed by a sequence consisting of one or more InCB=Linker
+    /// and zero or more InCB = Extend (in any order).
+    InCbConsonant,
+    /// The codepoint after is a Regional Indicator Symbol, so a boundary iff
+    /// it is preceded by an even number of RIS codepoints. (GB12, GB13)
+    Regional,
+    /// The codepoint after is Extended_Pictographic,
+    /// so whether it's a boundary depends on pre-context according to GB11.
+    Emoji,
+}
+
+impl GraphemeState {
+    #[inline]
+    fn from_u8(value: u8) -> GraphemeState {
+        match value {
+            1 => GraphemeState::NotBreak,
+            2 => GraphemeState::Break,
+            3 => GraphemeState::InCbConsonant,
+            4 => GraphemeState::Regional,
+            5 => GraphemeState::Emoji,
+            _ => GraphemeState::Unknown,
+        }
+    }
+}
+
+/// Which kind of [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// to select: a readable stand-in for the `is_extended` flag taken by most functions.
+///
+/// `GraphemeMode::Extended` is the same as `is_extended == true`, and `GraphemeMode::Legacy`
+/// the same as `false`. A mode can be passed wherever [`GraphemeOptions`] are expected.
+///
+/// ```rust
+/// # #[cfg(feature = "str-impl")] {
+/// # use unicode_segmentation::{GraphemeMode, UnicodeSegmentation, UnicodeSegmentationExt};
+/// let s = "\u{915}\u{93e}";
+/// assert!(s.graphemes_with(GraphemeMode::Extended).eq(s.graphemes(true)));
+/// assert!(s.graphemes_with(GraphemeMode::Legacy).eq(s.graphemes(false)));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GraphemeMode {
+    /// Extended grapheme clusters, which UAX#29 recommends for general processing.
+    #[default]
+    Extended,
+    /// Legacy grapheme clusters, which leave out the `SpacingMark` and `Prepend` rules (GB9a,
+    /// GB9b).
+    Legacy,
+}
+
+impl GraphemeMode {
+    /// Whether this is `GraphemeMode::Extended`, as the `is_extended` flag.
+    #[inline]
+    pub fn is_extended(self) -> bool {
+        self == GraphemeMode::Extended
+    }
+}
+
+impl From<bool> for GraphemeMode {
+    #[inline]
+    fn from(is_extended: bool) -> GraphemeMode {
+        if is_extended {
+            GraphemeMode::Extended
+        } else {
+            GraphemeMode::Legacy
+        }
+    }
+}
+
+impl From<GraphemeMode> for bool {
+    #[inline]
+    fn from(mode: GraphemeMode) -> bool {
+        mode.is_extended()
+    }
+}
+
+/// Options tailoring how strings are split into grapheme clusters.
+///
+/// The default options select extended grapheme clusters as described in
+/// [UAX#29](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries). Options are passed
+/// to the [`graphemes_with`] method on the [`UnicodeSegmentationExt`] trait, or to
+/// [`GraphemeCursor::with_options`].
+///
+/// [`graphemes_with`]: trait.UnicodeSegmentationExt.html#tymethod.graphemes_with
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GraphemeOptions {
+    is_extended: bool,
+    spacing_mark_joins: bool,
+    prepend_joins: bool,
+    split_tag_sequences: bool,
+    extended_pictographic: Option<CharPredicate>,
+}
+
+impl GraphemeOptions {
+    /// Create options selecting extended grapheme clusters with no tailoring.
+    pub const fn new() -> GraphemeOptions {
+        GraphemeOptions {
+            is_extended: true,
+            spacing_mark_joins: true,
+            prepend_joins: true,
+            split_tag_sequences: false,
+            extended_pictographic: None,
+        }
+    }
+
+    /// Select extended (`true`) or legacy (`false`) grapheme clusters.
+    ///
+    /// This also sets [`spacing_mark_joins`](GraphemeOptions::spacing_mark_joins) and
+    /// [`prepend_joins`](GraphemeOptions::prepend_joins) to `is_extended`, so call those
+    /// afterwards to tailor the individual rules.
+    pub fn extended(mut self, is_extended: bool) -> GraphemeOptions {
+        self.is_extended = is_extended;
+        self.spacing_mark_joins = is_extended;
+        self.prepend_joins = is_extended;
+        self
+    }
+
+    /// Select the clusters of `mode`, as [`extended`](GraphemeOptions::extended) does.
+    pub fn mode(self, mode: GraphemeMode) -> GraphemeOptions {
+        self.extended(mode.is_extended())
+    }
+
+    /// Whether a `SpacingMark` joins the preceding character (rule GB9a). On by default for
+    /// extended grapheme clusters and off for legacy ones.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{GraphemeOptions, UnicodeSegmentation, UnicodeSegmentationExt};
+    /// // DEVANAGARI LETTER KA followed by the spacing vowel sign AA.
+    /// let s = "\u{915}\u{93e}";
+    /// assert_eq!(s.graphemes(true).count(), 1);
+    ///
+    /// let options = GraphemeOptions::new().spacing_mark_joins(false);
+    /// assert_eq!(s.graphemes_with(options).count(), 2);
+    /// # }
+    /// ```
+    pub fn spacing_mark_joins(mut self, joins: bool) -> GraphemeOptions {
+        self.spacing_mark_joins = joins;
+        self
+    }
+
+    /// Whether a `Prepend` character joins the following character (rule GB9b). On by default
+    /// for extended grapheme clusters and off for legacy ones.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{GraphemeOptions, UnicodeSegmentation, UnicodeSegmentationExt};
+    /// // ARABIC NUMBER SIGN followed by a digit.
+    /// let s = "\u{600}1";
+    /// assert_eq!(s.graphemes(true).count(), 1);
+    ///
+    /// let options = GraphemeOptions::new().prepend_joins(false);
+    /// assert_eq!(s.graphemes_with(options).count(), 2);
+    /// # }
+    /// ```
+    pub fn prepend_joins(mut self, joins: bool) -> GraphemeOptions {
+        self.prepend_joins = joins;
+        self
+    }
+
+    /// Break before every tag character (U+E0020 to U+E007F), so that emoji tag sequences such
+    /// as subdivision flags are split into their base emoji and individual tags.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{GraphemeOptions, UnicodeSegmentation, UnicodeSegmentationExt};
+    /// let wales = "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}";
+    /// assert_eq!(wales.graphemes(true).count(), 1);
+    ///
+    /// let options = GraphemeOptions::new().split_tag_sequences(true);
+    /// let parts = wales.graphemes_with(options).collect::<Vec<_>>();
+    /// assert_eq!(parts[0], "\u{1f3f4}");
+    /// assert_eq!(parts.len(), 7);
+    /// # }
+    /// ```
+    pub fn split_tag_sequences(mut self, split: bool) -> GraphemeOptions {
+        self.split_tag_sequences = split;
+        self
+    }
+
+    /// Treat characters for which `f` returns `true` as `Extended_Pictographic`, in addition to
+    /// those with the property in the Unicode version the crate was built with (see
+    /// [`UNICODE_VERSION`](crate::UNICODE_VERSION)).
+    ///
+    /// This only affects characters that otherwise have no special grapheme cluster break
+    /// property, and lets emoji assigned in a newer Unicode version join ZWJ sequences (rule
+    /// GB11) without waiting for a crate update.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{GraphemeOptions, UnicodeSegmentation, UnicodeSegmentationExt};
+    /// // A man followed by a ZWJ and a private use character standing in for a new emoji.
+    /// let s = "\u{1f468}\u{200d}\u{e000}";
+    /// assert_eq!(s.graphemes(true).count(), 2);
+    ///
+    /// let options = GraphemeOptions::new().extended_pictographic(|c| c == '\u{e000}');
+    /// assert_eq!(s.graphemes_with(options).count(), 1);
+    /// # }
+    /// ```
+    pub fn extended_pictographic(mut self, f: fn(char) -> bool) -> GraphemeOptions {
+        self.extended_pictographic = Some(CharPredicate(f));
+        self
+    }
+
+    /// Whether these options select extended grapheme clusters, with both the
+    /// `SpacingMark` and `Prepend` rules on.
+    pub fn is_extended(&self) -> bool {
+        self.is_extended && self.spacing_mark_joins && self.prepend_joins
+    }
+
+    // Whether rules GB9a and GB9b keep `before` and `after` together.
+    #[inline]
+    fn joins_extended(&self, before: GraphemeCat, after: GraphemeCat) -> bool {
+        (self.spacing_mark_joins && after == GraphemeCat::GC_SpacingMark)
+            || (self.prepend_joins && before == GraphemeCat::GC_Prepend)
+    }
+}
+
+impl Default for GraphemeOptions {
+    fn default() -> GraphemeOptions {
+        GraphemeOptions::new()
+    }
+}
+
+impl From<GraphemeMode> for GraphemeOptions {
+    #[inline]
+    fn from(mode: GraphemeMode) -> GraphemeOptions {
+        GraphemeOptions::new().mode(mode)
+    }
+}
+
+#[inline]
+fn is_ascii_printable(b: u8) -> bool {
+    (b' '..=b'~').contains(&b)
+}
+
+// A user-supplied character property. Compared and hashed by address, like the function
+// pointer it wraps.
+#[derive(Clone, Copy)]
+struct CharPredicate(fn(char) -> bool);
+
+impl PartialEq for CharPredicate {
+    fn eq(&self, other: &CharPredicate) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+impl Eq for CharPredicate {}
+
+impl Hash for CharPredicate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 as usize).hash(state)
+    }
+}
+
+impl fmt::Debug for CharPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:p}", self.0)
+    }
+}
+
+fn is_tag(ch: char) -> bool {
+    ('\u{e0020}'..='\u{e007f}').contains(&ch)
+}
+
+/// Returns `true` if `cluster` is an emoji tag sequence, such as the flag of Scotland: a
+/// U+1F3F4 WAVING BLACK FLAG followed by one or more tag characters and a terminating
+/// U+E007F CANCEL TAG.
+///
+/// ```rust
+/// # use unicode_segmentation::is_tag_sequence_cluster;
+/// let scotland = "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}";
+/// assert!(is_tag_sequence_cluster(scotland));
+/// assert!(!is_tag_sequence_cluster("\u{1f3f4}"));
+/// assert!(!is_tag_sequence_cluster("\u{1f1ec}\u{1f1e7}"));
+/// ```
+pub fn is_tag_sequence_cluster(cluster: &str) -> bool {
+    let mut chars = cluster.chars();
+    if chars.next() != Some('\u{1f3f4}') || chars.next_back() != Some('\u{e007f}') {
+        return false;
+    }
+    let mut tags = 0;
+    for ch in chars {
+        if !is_tag(ch) || ch == '\u{e007f}' {
+            return false;
+        }
+        tags += 1;
+    }
+    tags > 0
+}
+
+/// Returns `true` if there is a
+/// [grapheme cluster boundary](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+/// between `before` and `after` when they are the only two characters of a string.
+///
+/// This is a quick check that needs no cursor, but it only sees the two characters. Rules
+/// that depend on earlier text are decided as if there was none: two regional indicators
+/// always form a flag (GB12, GB13), a zero width joiner only joins an emoji that follows it
+/// if an emoji comes before it (GB11), and a linker is only joined to a following consonant
+/// after another consonant (GB9c). Use a `GraphemeCursor` to take the preceding text into
+/// account.
+///
+/// ```rust
+/// # use unicode_segmentation::decide_pair;
+/// assert!(decide_pair('a', 'b', true));
+/// assert!(!decide_pair('e', '\u{301}', true));
+/// assert!(!decide_pair('\r', '\n', true));
+/// // spacing marks only extend the cluster in extended mode
+/// assert!(!decide_pair('\u{915}', '\u{93f}', true));
+/// assert!(decide_pair('\u{915}', '\u{93f}', false));
+/// // an emoji before the joiner would be needed to join these
+/// assert!(decide_pair('\u{200d}', '\u{1f466}', true));
+/// ```
+pub fn decide_pair(before: char, after: char, is_extended: bool) -> bool {
+    use crate::tables::grapheme as gr;
+
+    match check_pair(
+        gr::grapheme_category(before).2,
+        gr::grapheme_category(after).2,
+    ) {
+        PairResult::NotBreak | PairResult::Regional => false,
+        PairResult::Break | PairResult::InCbConsonant | PairResult::Emoji => true,
+        PairResult::Extended => !is_extended,
     }
 }
 
-impl<'a> DoubleEndedIterator for GraphemeIndices<'a> {
-    #[inline]
-    fn next_back(&mut self) -> Option<(usize, &'a str)> {
-        self.iter
-            .next_back()
-            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+/// Returns `true` if inserting `insert` into `s` at byte `offset` would change the grapheme
+/// clusters around the insertion point.
+///
+/// This is the case if `offset` is inside a cluster of `s`, or if the start or the end of the
+/// inserted text would not be a cluster boundary of the result, for example when inserting a
+/// combining mark right after a letter, or a regional indicator next to another. If this
+/// returns `false`, the clusters of `s` are unchanged by the insertion and `insert` forms
+/// whole clusters of its own.
+///
+/// ```rust
+/// # use unicode_segmentation::insertion_splits_cluster;
+/// assert!(!insertion_splits_cluster("ab", 1, "xy", true));
+/// // inside the cluster "e\u{301}"
+/// assert!(insertion_splits_cluster("e\u{301}", 1, "x", true));
+/// // the mark joins the "a" before it
+/// assert!(insertion_splits_cluster("ab", 1, "\u{301}", true));
+/// // the zero width joiner joins the emoji on either side
+/// assert!(insertion_splits_cluster("\u{1f468}\u{1f469}", 4, "\u{200d}", true));
+/// assert!(!insertion_splits_cluster("\u{1f468}\u{1f469}", 4, "\u{1f466}", true));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `offset` is not on a `char` boundary of `s`, or is past its end.
+pub fn insertion_splits_cluster(s: &str, offset: usize, insert: &str, is_extended: bool) -> bool {
+    let (before, after) = s.split_at(offset);
+    if !is_boundary_of_pieces(&[before, after], offset, is_extended) {
+        return true;
     }
+    let pieces = [before, insert, after];
+    !is_boundary_of_pieces(&pieces, offset, is_extended)
+        || !is_boundary_of_pieces(&pieces, offset + insert.len(), is_extended)
 }
 
-/// External iterator for a string's
-/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries).
+/// Returns `true` if the end of `prev_tail` and the start of `next_head` would be part of
+/// the same grapheme cluster when the two are joined, so that there is no cluster boundary
+/// between them.
 ///
-/// This struct is created by the [`graphemes`] method on the [`UnicodeSegmentation`] trait. See its
-/// documentation for more.
+/// `prev_tail` should hold enough of the preceding text for the rules that look back past the
+/// last character: at least its last cluster, or all of the text since the last line break to
+/// be safe. Returns `false` if either string is empty.
 ///
-/// [`graphemes`]: trait.UnicodeSegmentation.html#tymethod.graphemes
-/// [`UnicodeSegmentation`]: trait.UnicodeSegmentation.html
-#[derive(Clone, Debug)]
-pub struct Graphemes<'a> {
-    string: &'a str,
-    cursor: GraphemeCursor,
-    cursor_back: GraphemeCursor,
+/// ```rust
+/// # use unicode_segmentation::would_join_across;
+/// // a combining mark split from its base
+/// assert!(would_join_across("cafe", "\u{301} au lait", true));
+/// assert!(!would_join_across("cafe", " au lait", true));
+/// // the second half of a flag
+/// assert!(would_join_across("\u{1f1e8}", "\u{1f1e6}", true));
+/// assert!(!would_join_across("\u{1f1e8}\u{1f1e6}", "\u{1f1e6}", true));
+/// // spacing marks only join in extended mode
+/// assert!(would_join_across("\u{915}", "\u{93f}", true));
+/// assert!(!would_join_across("\u{915}", "\u{93f}", false));
+/// ```
+pub fn would_join_across(prev_tail: &str, next_head: &str, is_extended: bool) -> bool {
+    !prev_tail.is_empty()
+        && !next_head.is_empty()
+        && !is_boundary_of_pieces(&[prev_tail, next_head], prev_tail.len(), is_extended)
 }
 
-impl<'a> Graphemes<'a> {
-    #[inline]
-    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
-    ///
-    /// ```rust
-    /// # use unicode_segmentation::UnicodeSegmentation;
-    /// let mut iter = "abc".graphemes(true);
-    /// assert_eq!(iter.as_str(), "abc");
-    /// iter.next();
-    /// assert_eq!(iter.as_str(), "bc");
-    /// iter.next();
-    /// iter.next();
-    /// assert_eq!(iter.as_str(), "");
-    /// ```
-    pub fn as_str(&self) -> &'a str {
-        &self.string[self.cursor.cur_cursor()..self.cursor_back.cur_cursor()]
+// Whether `offset` is a grapheme cluster boundary of the concatenation of `pieces`, found by
+// giving the pieces to a cursor as chunks.
+fn is_boundary_of_pieces(pieces: &[&str], offset: usize, is_extended: bool) -> bool {
+    let len = pieces.iter().map(|piece| piece.len()).sum();
+    // the non-empty piece ending at `end`, or starting at `offset`, with its start
+    let piece_at = |end: Option<usize>| {
+        let mut start = 0;
+        for piece in pieces {
+            let found = match end {
+                Some(end) => start + piece.len() == end,
+                None => start <= offset && offset < start + piece.len(),
+            };
+            if found && !piece.is_empty() {
+                return (*piece, start);
+            }
+            start += piece.len();
+        }
+        unreachable!("no piece at {:?}", end);
+    };
+    if offset == 0 || offset == len {
+        return true;
+    }
+    let (chunk, chunk_start) = piece_at(None);
+    let mut cursor = GraphemeCursor::new(offset, len, is_extended);
+    loop {
+        match cursor.is_boundary(chunk, chunk_start) {
+            Ok(is_boundary) => return is_boundary,
+            Err(GraphemeIncomplete::PreContext(end)) => {
+                let (context, context_start) = piece_at(Some(end));
+                cursor.provide_context(context, context_start);
+            }
+            Err(e) => unreachable!("{:?}", e),
+        }
     }
 }
 
-impl<'a> Iterator for Graphemes<'a> {
-    type Item = &'a str;
+/// Returns the byte length of the last grapheme cluster of `chunk` if more text appended to
+/// `chunk` could still extend it, or 0 if `chunk` ends on a cluster boundary that is final.
+///
+/// `chunk` should be all of the text received so far, or start at a cluster boundary. A
+/// streaming renderer can display `chunk` up to the returned length from the end and hold the
+/// rest back until more input arrives. The clusters before that point never change.
+///
+/// Almost any cluster can still grow, since combining marks extend whatever comes before them
+/// (GB9). Only a line feed or a control character other than a carriage return ends a cluster
+/// for good (GB4); a carriage return may still be followed by a line feed (GB3).
+///
+/// ```rust
+/// # use unicode_segmentation::trailing_incomplete_cluster_len;
+/// // "e" may yet take an accent
+/// assert_eq!(trailing_incomplete_cluster_len("cafe", true), 1);
+/// // an emoji and a trailing zero width joiner may join a following emoji
+/// assert_eq!(trailing_incomplete_cluster_len("ok \u{1f468}\u{200d}", true), 7);
+/// // a lone regional indicator may pair with the next one
+/// assert_eq!(trailing_incomplete_cluster_len("\u{1f1fa}\u{1f1f8}\u{1f1e6}", true), 4);
+/// assert_eq!(trailing_incomplete_cluster_len("line\r", true), 1);
+/// assert_eq!(trailing_incomplete_cluster_len("line\r\n", true), 0);
+/// assert_eq!(trailing_incomplete_cluster_len("", true), 0);
+/// ```
+pub fn trailing_incomplete_cluster_len(chunk: &str, is_extended: bool) -> usize {
+    use crate::tables::grapheme as gr;
 
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let slen = self.cursor_back.cur_cursor() - self.cursor.cur_cursor();
-        (cmp::min(slen, 1), Some(slen))
+    match chunk.chars().next_back() {
+        None => 0,
+        Some(last) => match gr::grapheme_category(last).2 {
+            gr::GC_Control | gr::GC_LF => 0,
+            _ => new_graphemes(chunk, is_extended)
+                .next_back()
+                .map_or(0, str::len),
+        },
     }
+}
 
-    #[inline]
-    fn next(&mut self) -> Option<&'a str> {
-        let start = self.cursor.cur_cursor();
-        if start == self.cursor_back.cur_cursor() {
-            return None;
-        }
-        let next = self.cursor.next_boundary(self.string, 0).unwrap().unwrap();
-        Some(&self.string[start..next])
-    }
+/// The coarse kind of a grapheme cluster, as determined by its first character.
+///
+/// Each kind has a fixed `u8` discriminant, for passing kinds across FFI boundaries. These
+/// values are stable: they will never change or be reused, and kinds added in later versions
+/// will get new values.
+///
+/// ```rust
+/// # #[cfg(feature = "str-impl")] {
+/// # use unicode_segmentation::{ClusterKind, UnicodeSegmentation};
+/// let kinds = "a1 \r\n\u{1f1fa}\u{1f1f8}!"
+///     .graphemes(true)
+///     .map(|g| ClusterKind::of(g) as u8)
+///     .collect::<Vec<u8>>();
+/// assert_eq!(kinds, [4, 5, 3, 1, 7, 0]);
+/// assert_eq!(ClusterKind::from_u8(6), Some(ClusterKind::Emoji));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ClusterKind {
+    /// Anything not covered by another kind, such as punctuation, symbols and unassigned
+    /// code points.
+    Other = 0,
+    /// A line feed, a carriage return, or both together.
+    Newline = 1,
+    /// Another control or format character.
+    Control = 2,
+    /// A character with the
+    /// [White_Space](http://unicode.org/reports/tr44/#White_Space) property.
+    Whitespace = 3,
+    /// A letter, that is, a character with the
+    /// [Alphabetic](http://unicode.org/reports/tr44/#Alphabetic) property. This includes
+    /// ideographs and Hangul syllables.
+    Letter = 4,
+    /// A numeric character.
+    Number = 5,
+    /// An emoji or other pictographic character.
+    Emoji = 6,
+    /// A regional indicator, which pairs up into a flag.
+    Flag = 7,
 }
 
-impl<'a> DoubleEndedIterator for Graphemes<'a> {
-    #[inline]
-    fn next_back(&mut self) -> Option<&'a str> {
-        let end = self.cursor_back.cur_cursor();
-        if end == self.cursor.cur_cursor() {
-            return None;
+impl ClusterKind {
+    /// Classify a grapheme cluster by its first character. An empty string is `Other`.
+    pub fn of(cluster: &str) -> ClusterKind {
+        use crate::tables::grapheme as gr;
+        use crate::tables::{derived_property, general_category};
+
+        let ch = match cluster.chars().next() {
+            Some(ch) => ch,
+            None => return ClusterKind::Other,
+        };
+        match gr::grapheme_category(ch).2 {
+            gr::GC_CR | gr::GC_LF => ClusterKind::Newline,
+            gr::GC_Regional_Indicator => ClusterKind::Flag,
+            gr::GC_Extended_Pictographic => ClusterKind::Emoji,
+            _ if ch.is_whitespace() => ClusterKind::Whitespace,
+            gr::GC_Control => ClusterKind::Control,
+            _ if derived_property::Alphabetic(ch) => ClusterKind::Letter,
+            _ if general_category::N(ch) => ClusterKind::Number,
+            _ => ClusterKind::Other,
         }
-        let prev = self
-            .cursor_back
-            .prev_boundary(self.string, 0)
-            .unwrap()
-            .unwrap();
-        Some(&self.string[prev..end])
     }
-}
 
-#[inline]
-pub fn new_graphemes(s: &str, is_extended: bool) -> Graphemes<'_> {
-    let len = s.len();
-    Graphemes {
-        string: s,
-        cursor: GraphemeCursor::new(0, len, is_extended),
-        cursor_back: GraphemeCursor::new(len, len, is_extended),
+    /// The kind with the discriminant `value`, if there is one.
+    pub fn from_u8(value: u8) -> Option<ClusterKind> {
+        use self::ClusterKind::*;
+        match value {
+            0 => Some(Other),
+            1 => Some(Newline),
+            2 => Some(Control),
+            3 => Some(Whitespace),
+            4 => Some(Letter),
+            5 => Some(Number),
+            6 => Some(Emoji),
+            7 => Some(Flag),
+            _ => None,
+        }
     }
 }
 
-#[inline]
-pub fn new_grapheme_indices(s: &str, is_extended: bool) -> GraphemeIndices<'_> {
-    GraphemeIndices {
-        start_offset: s.as_ptr() as usize,
-        iter: new_graphemes(s, is_extended),
+impl From<ClusterKind> for u8 {
+    #[inline]
+    fn from(kind: ClusterKind) -> u8 {
+        kind as u8
     }
 }
 
-/// maybe unify with PairResult?
-/// An enum describing information about a potential boundary.
-#[derive(PartialEq, Eq, Clone, Debug)]
-enum GraphemeState {
-    /// No information is known.
-    Unknown,
-    /// It is known to not be a boundary.
-    NotBreak,
-    /// It is known to be a boundary.
-    Break,
-    /// The codepoint after it has Indic_Conjunct_Break=Consonant,
-    /// so there is a break before so a boundary if it is preceded by another
-    /// InCB=Consonant follwoed by a sequence consisting of one or more InCB=Linker
-    /// and zero or more InCB = Extend (in any order).
-    InCbConsonant,
-    /// The codepoint after is a Regional Indicator Symbol, so a boundary iff
-    /// it is preceded by an even number of RIS codepoints. (GB12, GB13)
-    Regional,
-    /// The codepoint after is Extended_Pictographic,
-    /// so whether it's a boundary depends on pre-context according to GB11.
-    Emoji,
-}
-
 /// Cursor-based segmenter for grapheme clusters.
 ///
 /// This allows working with ropes and other datastructures where the string is not contiguous or
 /// fully known at initialization time.
-#[derive(Clone, Debug)]
+///
+/// A cursor is kept small, 64 bytes on 64-bit targets, so that many of them can be stored.
+#[derive(Clone)]
 pub struct GraphemeCursor {
     /// Current cursor position.
     offset: usize,
     /// Total length of the string.
     len: usize,
-    /// A config flag indicating whether this cursor computes legacy or extended
-    /// grapheme cluster boundaries (enables GB9a and GB9b if set).
-    is_extended: bool,
-    /// Information about the potential boundary at `offset`
-    state: GraphemeState,
+    /// The boolean options, `RESUMING`, and the `GraphemeState` of the potential boundary
+    /// at `offset` in the bits from `STATE_SHIFT` up.
+    flags: u8,
+    /// The `extended_pictographic` tailoring from the options.
+    extended_pictographic: Option<CharPredicate>,
     /// Category of codepoint immediately preceding cursor, if known.
     cat_before: Option<GraphemeCat>,
     /// Category of codepoint immediately after cursor, if known.
     cat_after: Option<GraphemeCat>,
     /// If set, at least one more codepoint immediately preceding this offset
     /// is needed to resolve whether there's a boundary at `offset`.
-    pre_context_offset: Option<usize>,
+    pre_context_offset: PackedOption,
     /// The number of `InCB=Linker` codepoints preceding `offset`
     /// (potentially intermingled with `InCB=Extend`).
-    incb_linker_count: Option<usize>,
+    incb_linker_count: PackedOption,
     /// The number of RIS codepoints preceding `offset`. If `pre_context_offset`
     /// is set, then counts the number of RIS between that and `offset`, otherwise
     /// is an accurate count relative to the string.
-    ris_count: Option<usize>,
-    /// Set if a call to `prev_boundary` or `next_boundary` was suspended due
-    /// to needing more input.
-    resuming: bool,
+    ris_count: PackedOption,
     /// Cached grapheme category and associated scalar value range.
     grapheme_cat_cache: (u32, u32, GraphemeCat),
 }
 
+// Bits of `GraphemeCursor::flags`.
+const IS_EXTENDED: u8 = 1;
+const SPACING_MARK_JOINS: u8 = 1 << 1;
+const PREPEND_JOINS: u8 = 1 << 2;
+const SPLIT_TAG_SEQUENCES: u8 = 1 << 3;
+/// Set if a call to `prev_boundary` or `next_boundary` was suspended due
+/// to needing more input.
+const RESUMING: u8 = 1 << 4;
+const STATE_SHIFT: u8 = 5;
+
+// An `Option<usize>` in the space of a `usize`. No offset into a string, or count of the
+// characters in it, can reach `usize::MAX`, so that value stands for `None`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct PackedOption(usize);
+
+impl PackedOption {
+    const NONE: PackedOption = PackedOption(usize::MAX);
+
+    #[inline]
+    fn new(value: Option<usize>) -> PackedOption {
+        PackedOption(value.unwrap_or(usize::MAX))
+    }
+
+    #[inline]
+    fn get(self) -> Option<usize> {
+        if self == PackedOption::NONE {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+}
+
+impl fmt::Debug for GraphemeCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphemeCursor")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .field("options", &self.options())
+            .field("state", &self.state())
+            .field("cat_before", &self.cat_before)
+            .field("cat_after", &self.cat_after)
+            .field("pre_context_offset", &self.pre_context_offset.get())
+            .field("incb_linker_count", &self.incb_linker_count.get())
+            .field("ris_count", &self.ris_count.get())
+            .field("resuming", &self.resuming())
+            .finish()
+    }
+}
+
+/// The category of a character used to find grapheme cluster boundaries, as given by the
+/// `cur_cat_before` and `cur_cat_after` methods of [`GraphemeCursor`].
+///
+/// This is the
+/// [Grapheme_Cluster_Break](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values)
+/// property, with the characters that rules GB9c and GB11 look for given their own categories.
+///
+/// [`GraphemeCursor`]: struct.GraphemeCursor.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphemeBreakProperty {
+    /// Any other character.
+    Other,
+    /// U+000D CARRIAGE RETURN.
+    CR,
+    /// A control or format character, other than CR and LF, that breaks clusters.
+    Control,
+    /// A combining mark or other character that extends the preceding cluster.
+    Extend,
+    /// An emoji or other pictographic character, which a `ZWJ` can join to the one before.
+    ExtendedPictographic,
+    /// A consonant of a script whose conjuncts form one cluster, with Indic_Conjunct_Break=Consonant.
+    InCBConsonant,
+    /// A Hangul leading consonant (choseong) jamo.
+    L,
+    /// U+000A LINE FEED.
+    LF,
+    /// A precomposed Hangul syllable with no trailing consonant.
+    LV,
+    /// A precomposed Hangul syllable with a trailing consonant.
+    LVT,
+    /// A character that joins the following cluster, such as U+0600 ARABIC NUMBER SIGN.
+    Prepend,
+    /// A regional indicator symbol, used in pairs for flags.
+    RegionalIndicator,
+    /// A spacing combining mark, which joins extended clusters only.
+    SpacingMark,
+    /// A Hangul trailing consonant (jongseong) jamo.
+    T,
+    /// A Hangul vowel (jungseong) jamo.
+    V,
+    /// U+200D ZERO WIDTH JOINER.
+    ZWJ,
+}
+
+impl From<GraphemeCat> for GraphemeBreakProperty {
+    fn from(cat: GraphemeCat) -> GraphemeBreakProperty {
+        match cat {
+            GraphemeCat::GC_Any => GraphemeBreakProperty::Other,
+            GraphemeCat::GC_CR => GraphemeBreakProperty::CR,
+            GraphemeCat::GC_Control => GraphemeBreakProperty::Control,
+            GraphemeCat::GC_Extend => GraphemeBreakProperty::Extend,
+            GraphemeCat::GC_Extended_Pictographic => GraphemeBreakProperty::ExtendedPictographic,
+            GraphemeCat::GC_InCB_Consonant => GraphemeBreakProperty::InCBConsonant,
+            GraphemeCat::GC_L => GraphemeBreakProperty::L,
+            GraphemeCat::GC_LF => GraphemeBreakProperty::LF,
+            GraphemeCat::GC_LV => GraphemeBreakProperty::LV,
+            GraphemeCat::GC_LVT => GraphemeBreakProperty::LVT,
+            GraphemeCat::GC_Prepend => GraphemeBreakProperty::Prepend,
+            GraphemeCat::GC_Regional_Indicator => GraphemeBreakProperty::RegionalIndicator,
+            GraphemeCat::GC_SpacingMark => GraphemeBreakProperty::SpacingMark,
+            GraphemeCat::GC_T => GraphemeBreakProperty::T,
+            GraphemeCat::GC_V => GraphemeBreakProperty::V,
+            GraphemeCat::GC_ZWJ => GraphemeBreakProperty::ZWJ,
+        }
+    }
+}
+
+impl From<GraphemeBreakProperty> for GraphemeCat {
+    fn from(property: GraphemeBreakProperty) -> GraphemeCat {
+        match property {
+            GraphemeBreakProperty::Other => GraphemeCat::GC_Any,
+            GraphemeBreakProperty::CR => GraphemeCat::GC_CR,
+            GraphemeBreakProperty::Control => GraphemeCat::GC_Control,
+            GraphemeBreakProperty::Extend => GraphemeCat::GC_Extend,
+            GraphemeBreakProperty::ExtendedPictographic => GraphemeCat::GC_Extended_Pictographic,
+            GraphemeBreakProperty::InCBConsonant => GraphemeCat::GC_InCB_Consonant,
+            GraphemeBreakProperty::L => GraphemeCat::GC_L,
+            GraphemeBreakProperty::LF => GraphemeCat::GC_LF,
+            GraphemeBreakProperty::LV => GraphemeCat::GC_LV,
+            GraphemeBreakProperty::LVT => GraphemeCat::GC_LVT,
+            GraphemeBreakProperty::Prepend => GraphemeCat::GC_Prepend,
+            GraphemeBreakProperty::RegionalIndicator => GraphemeCat::GC_Regional_Indicator,
+            GraphemeBreakProperty::SpacingMark => GraphemeCat::GC_SpacingMark,
+            GraphemeBreakProperty::T => GraphemeCat::GC_T,
+            GraphemeBreakProperty::V => GraphemeCat::GC_V,
+            GraphemeBreakProperty::ZWJ => GraphemeCat::GC_ZWJ,
+        }
+    }
+}
+
+/// The state of a [`GraphemeCursor`] as plain data, for saving it and restoring it later.
+///
+/// This is created by [`GraphemeCursor::to_raw`] and turned back into a cursor by
+/// [`GraphemeCursor::from_raw`]. It records the offset, length and options of the cursor along
+/// with what the cursor has learned about the text around its offset.
+///
+/// [`GraphemeCursor`]: struct.GraphemeCursor.html
+/// [`GraphemeCursor::to_raw`]: struct.GraphemeCursor.html#method.to_raw
+/// [`GraphemeCursor::from_raw`]: struct.GraphemeCursor.html#method.from_raw
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GraphemeCursorState {
+    offset: usize,
+    len: usize,
+    flags: u8,
+    extended_pictographic: Option<CharPredicate>,
+    cat_before: Option<GraphemeBreakProperty>,
+    cat_after: Option<GraphemeBreakProperty>,
+    pre_context_offset: Option<usize>,
+    incb_linker_count: Option<usize>,
+    ris_count: Option<usize>,
+}
+
 /// An error return indicating that not enough content was available in the
 /// provided chunk to satisfy the query, and that more content must be provided.
 #[derive(PartialEq, Eq, Debug)]
@@ -236,7 +2541,8 @@ pub enum GraphemeIncomplete {
     /// creation of the cursor.
     NextChunk, // requesting chunk following the one given
 
-    /// An error returned when the chunk given does not contain the cursor position.
+    /// An error returned when the chunk given does not contain the cursor position, or the
+    /// cursor position falls inside a character of the chunk.
     InvalidOffset,
 }
 
@@ -286,7 +2592,8 @@ impl GraphemeCursor {
     /// time, but the contents of the string are not. The `is_extended` parameter
     /// controls whether extended grapheme clusters are selected.
     ///
-    /// The `offset` parameter must be on a codepoint boundary.
+    /// The `offset` parameter must be on a codepoint boundary. Otherwise, queries with a chunk
+    /// containing it return `GraphemeIncomplete::InvalidOffset`.
     ///
     /// ```rust
     /// # use unicode_segmentation::GraphemeCursor;
@@ -297,31 +2604,121 @@ impl GraphemeCursor {
     /// assert_eq!(extended.next_boundary(s, 0), Ok(Some("हि".len())));
     /// ```
     pub fn new(offset: usize, len: usize, is_extended: bool) -> GraphemeCursor {
+        GraphemeCursor::with_options(offset, len, GraphemeOptions::new().extended(is_extended))
+    }
+
+    /// Create a new cursor selecting the grapheme clusters of `mode`. This is the same as
+    /// `new()` with `mode.is_extended()`.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeCursor, GraphemeMode};
+    /// let s = "हिन्दी";
+    /// let mut legacy = GraphemeCursor::with_mode(0, s.len(), GraphemeMode::Legacy);
+    /// assert_eq!(legacy.next_boundary(s, 0), Ok(Some("ह".len())));
+    /// ```
+    #[inline]
+    pub fn with_mode(offset: usize, len: usize, mode: GraphemeMode) -> GraphemeCursor {
+        GraphemeCursor::new(offset, len, mode.is_extended())
+    }
+
+    /// Create a new cursor selecting grapheme clusters as tailored by `options`. See `new()`
+    /// for the other parameters.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeCursor, GraphemeOptions};
+    /// let s = "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}";
+    /// let options = GraphemeOptions::new().split_tag_sequences(true);
+    /// let mut cursor = GraphemeCursor::with_options(0, s.len(), options);
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some("\u{1f3f4}".len())));
+    /// ```
+    pub fn with_options(offset: usize, len: usize, options: GraphemeOptions) -> GraphemeCursor {
         let state = if offset == 0 || offset == len {
             GraphemeState::Break
         } else {
             GraphemeState::Unknown
         };
+        let mut flags = 0;
+        for &(on, flag) in &[
+            (options.is_extended, IS_EXTENDED),
+            (options.spacing_mark_joins, SPACING_MARK_JOINS),
+            (options.prepend_joins, PREPEND_JOINS),
+            (options.split_tag_sequences, SPLIT_TAG_SEQUENCES),
+        ] {
+            if on {
+                flags |= flag;
+            }
+        }
         GraphemeCursor {
             offset,
             len,
-            state,
-            is_extended,
+            flags: flags | (state as u8) << STATE_SHIFT,
+            extended_pictographic: options.extended_pictographic,
             cat_before: None,
             cat_after: None,
-            pre_context_offset: None,
-            incb_linker_count: None,
-            ris_count: None,
-            resuming: false,
+            pre_context_offset: PackedOption::NONE,
+            incb_linker_count: PackedOption::NONE,
+            ris_count: PackedOption::NONE,
             grapheme_cat_cache: (0, 0, GraphemeCat::GC_Control),
         }
     }
 
+    // The options this cursor was created with.
+    fn options(&self) -> GraphemeOptions {
+        GraphemeOptions {
+            is_extended: self.option(IS_EXTENDED),
+            spacing_mark_joins: self.option(SPACING_MARK_JOINS),
+            prepend_joins: self.option(PREPEND_JOINS),
+            split_tag_sequences: self.option(SPLIT_TAG_SEQUENCES),
+            extended_pictographic: self.extended_pictographic,
+        }
+    }
+
+    #[inline]
+    fn option(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    #[inline]
+    fn state(&self) -> GraphemeState {
+        GraphemeState::from_u8(self.flags >> STATE_SHIFT)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: GraphemeState) {
+        self.flags = (self.flags & !(!0 << STATE_SHIFT)) | (state as u8) << STATE_SHIFT;
+    }
+
+    #[inline]
+    fn resuming(&self) -> bool {
+        self.flags & RESUMING != 0
+    }
+
+    #[inline]
+    fn set_resuming(&mut self, resuming: bool) {
+        if resuming {
+            self.flags |= RESUMING;
+        } else {
+            self.flags &= !RESUMING;
+        }
+    }
+
     fn grapheme_category(&mut self, ch: char) -> GraphemeCat {
+        let cat = self.table_category(ch);
+        match self.extended_pictographic {
+            Some(CharPredicate(f)) if cat == GraphemeCat::GC_Any && f(ch) => {
+                GraphemeCat::GC_Extended_Pictographic
+            }
+            _ => cat,
+        }
+    }
+
+    fn table_category(&mut self, ch: char) -> GraphemeCat {
         use crate::tables::grapheme as gr;
         use crate::tables::grapheme::GraphemeCat::*;
 
-        if ch <= '\u{7e}' {
+        if self.option(SPLIT_TAG_SEQUENCES) && is_tag(ch) {
+            GC_Any
+        } else if ch <= '\u{7e}' {
             // Special-case optimization for ascii, except U+007F.  This
             // improves performance even for many primarily non-ascii texts,
             // due to use of punctuation and white space characters from the
@@ -335,6 +2732,11 @@ impl GraphemeCursor {
             } else {
                 GC_Control
             }
+        } else if let Some(cat) = gr::grapheme_category_bmp(ch) {
+            // Characters of the Basic Multilingual Plane are looked up directly rather than
+            // searched for, since in scripts where consonants and combining marks alternate
+            // a cached range would keep changing.
+            cat
         } else {
             // If this char isn't within the cached range, update the cache to the
             // range that includes it.
@@ -360,16 +2762,80 @@ impl GraphemeCursor {
     pub fn set_cursor(&mut self, offset: usize) {
         if offset != self.offset {
             self.offset = offset;
-            self.state = if offset == 0 || offset == self.len {
+            self.set_state(if offset == 0 || offset == self.len {
                 GraphemeState::Break
             } else {
                 GraphemeState::Unknown
-            };
+            });
             // reset state derived from text around cursor
             self.cat_before = None;
             self.cat_after = None;
-            self.incb_linker_count = None;
-            self.ris_count = None;
+            self.incb_linker_count = PackedOption::NONE;
+            self.ris_count = PackedOption::NONE;
+        }
+    }
+
+    /// Reinitialize the cursor for a new string, exactly as `new()` would create it.
+    ///
+    /// This is meant for reusing one cursor over many strings. Unlike `set_cursor`, it also
+    /// clears any pending request for context and the grapheme mode, and it keeps the cached
+    /// category lookup, which is still valid for the new string.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::GraphemeCursor;
+    /// let mut cursor = GraphemeCursor::new(0, 4, false);
+    /// assert_eq!(cursor.next_boundary("abcd", 0), Ok(Some(1)));
+    /// let s = "e\u{301}f";
+    /// cursor.reset(0, s.len(), true);
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(3)));
+    /// ```
+    pub fn reset(&mut self, offset: usize, len: usize, is_extended: bool) {
+        let grapheme_cat_cache = self.grapheme_cat_cache;
+        *self = GraphemeCursor::new(offset, len, is_extended);
+        self.grapheme_cat_cache = grapheme_cat_cache;
+    }
+
+    /// Save the state of the cursor as plain data, which `from_raw` turns back into the same
+    /// cursor. This can be done at any point, including while waiting for another chunk.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+    /// let s = "ab\u{301}\u{301}c";
+    /// let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    /// assert_eq!(cursor.next_boundary(&s[..4], 0), Ok(Some(1)));
+    /// assert_eq!(cursor.next_boundary(&s[..4], 0), Err(GraphemeIncomplete::NextChunk));
+    /// let saved = cursor.to_raw();
+    ///
+    /// let mut restored = GraphemeCursor::from_raw(saved);
+    /// assert_eq!(restored.next_boundary(&s[4..], 4), Ok(Some(6)));
+    /// ```
+    pub fn to_raw(&self) -> GraphemeCursorState {
+        GraphemeCursorState {
+            offset: self.offset,
+            len: self.len,
+            flags: self.flags,
+            extended_pictographic: self.extended_pictographic,
+            cat_before: self.cat_before.map(Into::into),
+            cat_after: self.cat_after.map(Into::into),
+            pre_context_offset: self.pre_context_offset.get(),
+            incb_linker_count: self.incb_linker_count.get(),
+            ris_count: self.ris_count.get(),
+        }
+    }
+
+    /// Restore a cursor saved by `to_raw`.
+    pub fn from_raw(raw: GraphemeCursorState) -> GraphemeCursor {
+        GraphemeCursor {
+            offset: raw.offset,
+            len: raw.len,
+            flags: raw.flags,
+            extended_pictographic: raw.extended_pictographic,
+            cat_before: raw.cat_before.map(Into::into),
+            cat_after: raw.cat_after.map(Into::into),
+            pre_context_offset: PackedOption::new(raw.pre_context_offset),
+            incb_linker_count: PackedOption::new(raw.incb_linker_count),
+            ris_count: PackedOption::new(raw.ris_count),
+            grapheme_cat_cache: (0, 0, GraphemeCat::GC_Control),
         }
     }
 
@@ -379,16 +2845,105 @@ impl GraphemeCursor {
     /// `prev_boundary()`.
     ///
     /// ```rust
-    /// # use unicode_segmentation::GraphemeCursor;
+    /// # use unicode_segmentation::GraphemeCursor;
+    /// // Two flags (🇷🇸🇮🇴), each flag is two RIS codepoints, each RIS is 4 bytes.
+    /// let flags = "\u{1F1F7}\u{1F1F8}\u{1F1EE}\u{1F1F4}";
+    /// let mut cursor = GraphemeCursor::new(4, flags.len(), false);
+    /// assert_eq!(cursor.cur_cursor(), 4);
+    /// assert_eq!(cursor.next_boundary(flags, 0), Ok(Some(8)));
+    /// assert_eq!(cursor.cur_cursor(), 8);
+    /// ```
+    pub fn cur_cursor(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    /// The category of the character before the cursor, if the cursor has looked it up.
+    ///
+    /// After a call to `is_boundary` that returned a result, this is the category its decision
+    /// was based on, along with `cur_cat_after`. Nothing is looked up by this call, so it is
+    /// `None` when the cursor hasn't needed it, such as at the start of the text.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeBreakProperty, GraphemeCursor};
+    /// let s = "a\u{301}";
+    /// let mut cursor = GraphemeCursor::new(1, s.len(), true);
+    /// assert_eq!(cursor.cur_cat_before(), None);
+    /// assert_eq!(cursor.is_boundary(s, 0), Ok(false));
+    /// assert_eq!(cursor.cur_cat_before(), Some(GraphemeBreakProperty::Other));
+    /// assert_eq!(cursor.cur_cat_after(), Some(GraphemeBreakProperty::Extend));
+    /// ```
+    pub fn cur_cat_before(&self) -> Option<GraphemeBreakProperty> {
+        self.cat_before.map(Into::into)
+    }
+
+    #[inline]
+    /// The category of the character after the cursor, if the cursor has looked it up. See
+    /// `cur_cat_before`.
+    pub fn cur_cat_after(&self) -> Option<GraphemeBreakProperty> {
+        self.cat_after.map(Into::into)
+    }
+
+    #[inline]
+    /// How far the cursor has moved through the string, as the fraction `cur_cursor() / len`
+    /// between 0.0 and 1.0. Returns 1.0 for an empty string.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::GraphemeCursor;
+    /// let s = "abcd";
+    /// let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    /// assert_eq!(cursor.progress(), 0.0);
+    /// cursor.next_boundary(s, 0).unwrap();
+    /// assert_eq!(cursor.progress(), 0.25);
+    /// cursor.set_cursor(s.len());
+    /// assert_eq!(cursor.progress(), 1.0);
+    /// assert_eq!(GraphemeCursor::new(0, 0, true).progress(), 1.0);
+    /// ```
+    pub fn progress(&self) -> f64 {
+        if self.len == 0 {
+            1.0
+        } else {
+            self.offset as f64 / self.len as f64
+        }
+    }
+
+    /// The offset at which the chunk needed by a suspended query must start or end, if any.
+    ///
+    /// After `GraphemeIncomplete::PreContext` this is the offset given in the request, where
+    /// the chunk passed to `provide_context` must end. After `GraphemeIncomplete::NextChunk`
+    /// it is where `next_boundary` stopped scanning, where the next chunk must start, and after
+    /// `GraphemeIncomplete::PrevChunk` it is where `prev_boundary` stopped, where the previous
+    /// chunk must end.
+    ///
+    /// Returns `None` when no query is suspended.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+    /// let s = "ae\u{301}b";
+    /// let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    /// assert_eq!(cursor.pending_scan_offset(), None);
+    /// assert_eq!(cursor.next_boundary(&s[..2], 0), Ok(Some(1)));
+    /// assert_eq!(cursor.pending_scan_offset(), None);
+    /// assert_eq!(cursor.next_boundary(&s[..2], 0), Err(GraphemeIncomplete::NextChunk));
+    /// assert_eq!(cursor.pending_scan_offset(), Some(2));
+    /// assert_eq!(cursor.next_boundary(&s[2..], 2), Ok(Some(4)));
+    /// assert_eq!(cursor.pending_scan_offset(), None);
+    ///
     /// // Two flags (🇷🇸🇮🇴), each flag is two RIS codepoints, each RIS is 4 bytes.
-    /// let flags = "\u{1F1F7}\u{1F1F8}\u{1F1EE}\u{1F1F4}";
-    /// let mut cursor = GraphemeCursor::new(4, flags.len(), false);
-    /// assert_eq!(cursor.cur_cursor(), 4);
-    /// assert_eq!(cursor.next_boundary(flags, 0), Ok(Some(8)));
-    /// assert_eq!(cursor.cur_cursor(), 8);
+    /// let s = "\u{1F1F7}\u{1F1F8}\u{1F1EE}\u{1F1F4}";
+    /// let mut cursor = GraphemeCursor::new(12, s.len(), true);
+    /// assert_eq!(cursor.is_boundary(&s[8..], 8), Err(GraphemeIncomplete::PreContext(8)));
+    /// assert_eq!(cursor.pending_scan_offset(), Some(8));
+    /// cursor.provide_context(&s[..8], 0);
+    /// assert_eq!(cursor.pending_scan_offset(), None);
+    /// assert_eq!(cursor.is_boundary(&s[8..], 8), Ok(false));
     /// ```
-    pub fn cur_cursor(&self) -> usize {
-        self.offset
+    pub fn pending_scan_offset(&self) -> Option<usize> {
+        match self.pre_context_offset.get() {
+            Some(offset) => Some(offset),
+            None if self.resuming() => Some(self.offset),
+            None => None,
+        }
     }
 
     /// Provide additional pre-context when it is needed to decide a boundary.
@@ -412,16 +2967,19 @@ impl GraphemeCursor {
     /// ```
     pub fn provide_context(&mut self, chunk: &str, chunk_start: usize) {
         use crate::tables::grapheme as gr;
-        assert!(chunk_start.saturating_add(chunk.len()) == self.pre_context_offset.unwrap());
-        self.pre_context_offset = None;
-        if self.is_extended && chunk_start + chunk.len() == self.offset {
+        assert!(chunk_start.saturating_add(chunk.len()) == self.pre_context_offset.get().unwrap());
+        self.pre_context_offset = PackedOption::NONE;
+        if self.option(PREPEND_JOINS) && chunk_start + chunk.len() == self.offset {
             let ch = chunk.chars().next_back().unwrap();
-            if self.grapheme_category(ch) == gr::GC_Prepend {
+            let before_control =
+                matches!(self.cat_after, Some(gr::GC_Control | gr::GC_CR | gr::GC_LF));
+            // GB5 takes precedence over GB9b
+            if self.grapheme_category(ch) == gr::GC_Prepend && !before_control {
                 self.decide(false); // GB9b
                 return;
             }
         }
-        match self.state {
+        match self.state() {
             GraphemeState::InCbConsonant => self.handle_incb_consonant(chunk, chunk_start),
             GraphemeState::Regional => self.handle_regional(chunk, chunk_start),
             GraphemeState::Emoji => self.handle_emoji(chunk, chunk_start),
@@ -436,11 +2994,11 @@ impl GraphemeCursor {
 
     #[inline]
     fn decide(&mut self, is_break: bool) {
-        self.state = if is_break {
+        self.set_state(if is_break {
             GraphemeState::Break
         } else {
             GraphemeState::NotBreak
-        };
+        });
     }
 
     #[inline]
@@ -451,11 +3009,11 @@ impl GraphemeCursor {
 
     #[inline]
     fn is_boundary_result(&self) -> Result<bool, GraphemeIncomplete> {
-        if self.state == GraphemeState::Break {
+        if self.state() == GraphemeState::Break {
             Ok(true)
-        } else if self.state == GraphemeState::NotBreak {
+        } else if self.state() == GraphemeState::NotBreak {
             Ok(false)
-        } else if let Some(pre_context_offset) = self.pre_context_offset {
+        } else if let Some(pre_context_offset) = self.pre_context_offset.get() {
             Err(GraphemeIncomplete::PreContext(pre_context_offset))
         } else {
             unreachable!("inconsistent state");
@@ -477,57 +3035,59 @@ impl GraphemeCursor {
         use crate::tables::{self, grapheme as gr};
 
         // GB9c only applies to extended grapheme clusters
-        if !self.is_extended {
+        if !self.option(IS_EXTENDED) {
             self.decide(true);
             return;
         }
 
-        let mut incb_linker_count = self.incb_linker_count.unwrap_or(0);
+        let mut incb_linker_count = self.incb_linker_count.get().unwrap_or(0);
 
         for ch in chunk.chars().rev() {
             if tables::is_incb_linker(ch) {
                 // We found an InCB linker
                 incb_linker_count += 1;
-                self.incb_linker_count = Some(incb_linker_count);
             } else if tables::derived_property::InCB_Extend(ch) {
                 // We ignore InCB extends, continue
             } else {
                 // Prev character is neither linker nor extend, break suppressed iff it's InCB=Consonant
-                let result = !(self.incb_linker_count.unwrap_or(0) > 0
-                    && self.grapheme_category(ch) == gr::GC_InCB_Consonant);
+                self.incb_linker_count = PackedOption(incb_linker_count);
+                let result =
+                    !(incb_linker_count > 0 && self.grapheme_category(ch) == gr::GC_InCB_Consonant);
                 self.decide(result);
                 return;
             }
         }
+        self.incb_linker_count = PackedOption(incb_linker_count);
 
         if chunk_start == 0 {
             // Start of text and we still haven't found a consonant, so break
             self.decide(true);
         } else {
             // We need more context
-            self.pre_context_offset = Some(chunk_start);
-            self.state = GraphemeState::InCbConsonant;
+            self.pre_context_offset = PackedOption(chunk_start);
+            self.set_state(GraphemeState::InCbConsonant);
         }
     }
 
     #[inline]
+    #[allow(clippy::manual_is_multiple_of)]
     fn handle_regional(&mut self, chunk: &str, chunk_start: usize) {
         use crate::tables::grapheme as gr;
-        let mut ris_count = self.ris_count.unwrap_or(0);
+        let mut ris_count = self.ris_count.get().unwrap_or(0);
         for ch in chunk.chars().rev() {
             if self.grapheme_category(ch) != gr::GC_Regional_Indicator {
-                self.ris_count = Some(ris_count);
+                self.ris_count = PackedOption(ris_count);
                 self.decide((ris_count % 2) == 0);
                 return;
             }
             ris_count += 1;
         }
-        self.ris_count = Some(ris_count);
+        self.ris_count = PackedOption(ris_count);
         if chunk_start == 0 {
             self.decide((ris_count % 2) == 0);
         } else {
-            self.pre_context_offset = Some(chunk_start);
-            self.state = GraphemeState::Regional;
+            self.pre_context_offset = PackedOption(chunk_start);
+            self.set_state(GraphemeState::Regional);
         }
     }
 
@@ -535,10 +3095,14 @@ impl GraphemeCursor {
     fn handle_emoji(&mut self, chunk: &str, chunk_start: usize) {
         use crate::tables::grapheme as gr;
         let mut iter = chunk.chars().rev();
-        if let Some(ch) = iter.next() {
-            if self.grapheme_category(ch) != gr::GC_ZWJ {
-                self.decide(true);
-                return;
+        // Only the chunk ending at the cursor holds the ZWJ; a chunk supplied
+        // later as pre-context continues the scan over Extend characters.
+        if chunk_start + chunk.len() == self.offset {
+            if let Some(ch) = iter.next() {
+                if self.grapheme_category(ch) != gr::GC_ZWJ {
+                    self.decide(true);
+                    return;
+                }
             }
         }
         for ch in iter {
@@ -557,8 +3121,24 @@ impl GraphemeCursor {
         if chunk_start == 0 {
             self.decide(true);
         } else {
-            self.pre_context_offset = Some(chunk_start);
-            self.state = GraphemeState::Emoji;
+            self.pre_context_offset = PackedOption(chunk_start);
+            self.set_state(GraphemeState::Emoji);
+        }
+    }
+
+    // An offset that `new` or `set_cursor` put in the middle of a character can't be used to
+    // slice the chunk
+    #[inline]
+    fn check_char_boundary(
+        &self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<(), GraphemeIncomplete> {
+        match self.offset.checked_sub(chunk_start) {
+            Some(offset_in_chunk) if !chunk.is_char_boundary(offset_in_chunk) => {
+                Err(GraphemeIncomplete::InvalidOffset)
+            }
+            _ => Ok(()),
         }
     }
 
@@ -592,10 +3172,10 @@ impl GraphemeCursor {
         chunk_start: usize,
     ) -> Result<bool, GraphemeIncomplete> {
         use crate::tables::grapheme as gr;
-        if self.state == GraphemeState::Break {
+        if self.state() == GraphemeState::Break {
             return Ok(true);
         }
-        if self.state == GraphemeState::NotBreak {
+        if self.state() == GraphemeState::NotBreak {
             return Ok(false);
         }
         if (self.offset < chunk_start || self.offset >= chunk_start.saturating_add(chunk.len()))
@@ -603,7 +3183,8 @@ impl GraphemeCursor {
         {
             return Err(GraphemeIncomplete::InvalidOffset);
         }
-        if let Some(pre_context_offset) = self.pre_context_offset {
+        self.check_char_boundary(chunk, chunk_start)?;
+        if let Some(pre_context_offset) = self.pre_context_offset.get() {
             return Err(GraphemeIncomplete::PreContext(pre_context_offset));
         }
         let offset_in_chunk = self.offset.saturating_sub(chunk_start);
@@ -614,13 +3195,21 @@ impl GraphemeCursor {
         if self.offset == chunk_start {
             let mut need_pre_context = true;
             match self.cat_after.unwrap() {
-                gr::GC_InCB_Consonant => self.state = GraphemeState::InCbConsonant,
-                gr::GC_Regional_Indicator => self.state = GraphemeState::Regional,
-                gr::GC_Extended_Pictographic => self.state = GraphemeState::Emoji,
+                gr::GC_InCB_Consonant => {
+                    // The scan in `provide_context` counts linkers from scratch
+                    self.incb_linker_count = PackedOption::NONE;
+                    self.set_state(GraphemeState::InCbConsonant);
+                }
+                // The scan in `provide_context` adds to `ris_count`, so only start one if
+                // the indicators before the cursor haven't been counted already
+                gr::GC_Regional_Indicator if self.ris_count.get().is_none() => {
+                    self.set_state(GraphemeState::Regional)
+                }
+                gr::GC_Extended_Pictographic => self.set_state(GraphemeState::Emoji),
                 _ => need_pre_context = self.cat_before.is_none(),
             }
             if need_pre_context {
-                self.pre_context_offset = Some(chunk_start);
+                self.pre_context_offset = PackedOption(chunk_start);
                 return Err(GraphemeIncomplete::PreContext(chunk_start));
             }
         }
@@ -632,15 +3221,17 @@ impl GraphemeCursor {
             PairResult::NotBreak => self.decision(false),
             PairResult::Break => self.decision(true),
             PairResult::Extended => {
-                let is_extended = self.is_extended;
-                self.decision(!is_extended)
+                let (before, after) = (self.cat_before.unwrap(), self.cat_after.unwrap());
+                let joins = self.options().joins_extended(before, after);
+                self.decision(!joins)
             }
             PairResult::InCbConsonant => {
+                self.incb_linker_count = PackedOption::NONE;
                 self.handle_incb_consonant(&chunk[..offset_in_chunk], chunk_start);
                 self.is_boundary_result()
             }
             PairResult::Regional => {
-                if let Some(ris_count) = self.ris_count {
+                if let Some(ris_count) = self.ris_count.get() {
                     return self.decision((ris_count % 2) == 0);
                 }
                 self.handle_regional(&chunk[..offset_in_chunk], chunk_start);
@@ -694,32 +3285,55 @@ impl GraphemeCursor {
         if self.offset == self.len {
             return Ok(None);
         }
-        let mut iter = chunk[self.offset.saturating_sub(chunk_start)..].chars();
+        self.check_char_boundary(chunk, chunk_start)?;
+        let offset_in_chunk = self.offset.saturating_sub(chunk_start);
+        if !self.resuming() {
+            // Fast path: there is always a boundary between two printable ASCII characters,
+            // so text made of them doesn't need any table lookups
+            if let [b0, b1, ..] = chunk.as_bytes()[offset_in_chunk..] {
+                if is_ascii_printable(b0) && is_ascii_printable(b1) {
+                    // printable ASCII is `Other`, unless tailored to be Extended_Pictographic
+                    let cat = match self.extended_pictographic {
+                        None => Some(GraphemeCat::GC_Any),
+                        Some(_) => None,
+                    };
+                    self.offset += 1;
+                    self.set_state(GraphemeState::Break);
+                    self.cat_before = cat;
+                    self.cat_after = cat;
+                    self.incb_linker_count = PackedOption(0);
+                    self.ris_count = PackedOption(0);
+                    return Ok(Some(self.offset));
+                }
+            }
+        }
+        let mut iter = chunk[offset_in_chunk..].chars();
         let mut ch = match iter.next() {
             Some(ch) => ch,
             None => return Err(GraphemeIncomplete::NextChunk),
         };
         loop {
-            if self.resuming {
+            if self.resuming() {
                 if self.cat_after.is_none() {
                     self.cat_after = Some(self.grapheme_category(ch));
                 }
             } else {
                 self.offset = self.offset.saturating_add(ch.len_utf8());
-                self.state = GraphemeState::Unknown;
+                self.set_state(GraphemeState::Unknown);
                 self.cat_before = self.cat_after.take();
                 if self.cat_before.is_none() {
                     self.cat_before = Some(self.grapheme_category(ch));
                 }
                 if crate::tables::is_incb_linker(ch) {
-                    self.incb_linker_count = Some(self.incb_linker_count.map_or(1, |c| c + 1));
+                    self.incb_linker_count =
+                        PackedOption(self.incb_linker_count.get().map_or(1, |c| c + 1));
                 } else if !crate::tables::derived_property::InCB_Extend(ch) {
-                    self.incb_linker_count = Some(0);
+                    self.incb_linker_count = PackedOption(0);
                 }
                 if self.cat_before.unwrap() == GraphemeCat::GC_Regional_Indicator {
-                    self.ris_count = self.ris_count.map(|c| c + 1);
+                    self.ris_count = PackedOption::new(self.ris_count.get().map(|c| c + 1));
                 } else {
-                    self.ris_count = Some(0);
+                    self.ris_count = PackedOption(0);
                 }
                 if let Some(next_ch) = iter.next() {
                     ch = next_ch;
@@ -727,17 +3341,80 @@ impl GraphemeCursor {
                 } else if self.offset == self.len {
                     self.decide(true);
                 } else {
-                    self.resuming = true;
+                    self.set_resuming(true);
                     return Err(GraphemeIncomplete::NextChunk);
                 }
             }
-            self.resuming = true;
+            self.set_resuming(true);
             if self.is_boundary(chunk, chunk_start)? {
-                self.resuming = false;
+                self.set_resuming(false);
                 return Ok(Some(self.offset));
             }
-            self.resuming = false;
+            self.set_resuming(false);
+        }
+    }
+
+    /// Move the cursor forward across `n` boundaries, as if calling `next_boundary` `n` times,
+    /// and return its new offset, or `None` if the end of the string comes first.
+    ///
+    /// `n` is decreased for every boundary passed. When this returns an error, supply what
+    /// `next_boundary` would need, then call it again with the same `n` to continue.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+    /// let s = "a\u{301}bcd";
+    /// let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    /// let mut n = 3;
+    /// assert_eq!(cursor.nth_boundary(&mut n, &s[..4], 0), Err(GraphemeIncomplete::NextChunk));
+    /// assert_eq!(n, 2);
+    /// assert_eq!(cursor.nth_boundary(&mut n, &s[4..], 4), Ok(Some(5)));
+    /// assert_eq!(n, 0);
+    /// let mut n = 2;
+    /// assert_eq!(cursor.nth_boundary(&mut n, &s[4..], 4), Ok(None));
+    /// ```
+    pub fn nth_boundary(
+        &mut self,
+        n: &mut usize,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, GraphemeIncomplete> {
+        while *n > 0 {
+            if self.next_boundary(chunk, chunk_start)?.is_none() {
+                return Ok(None);
+            }
+            *n -= 1;
+        }
+        Ok(Some(self.offset))
+    }
+
+    /// Move the cursor back across `n` boundaries, as if calling `prev_boundary` `n` times,
+    /// and return its new offset, or `None` if the start of the string comes first.
+    ///
+    /// `n` is decreased for every boundary passed, as with `nth_boundary`.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::GraphemeCursor;
+    /// let s = "abc\u{1f1fa}\u{1f1f8}";
+    /// let mut cursor = GraphemeCursor::new(s.len(), s.len(), true);
+    /// let mut n = 2;
+    /// assert_eq!(cursor.nth_prev_boundary(&mut n, s, 0), Ok(Some(2)));
+    /// let mut n = 3;
+    /// assert_eq!(cursor.nth_prev_boundary(&mut n, s, 0), Ok(None));
+    /// assert_eq!(n, 1);
+    /// ```
+    pub fn nth_prev_boundary(
+        &mut self,
+        n: &mut usize,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, GraphemeIncomplete> {
+        while *n > 0 {
+            if self.prev_boundary(chunk, chunk_start)?.is_none() {
+                return Ok(None);
+            }
+            *n -= 1;
         }
+        Ok(Some(self.offset))
     }
 
     /// Find the previous boundary after the current cursor position. Only a part
@@ -784,36 +3461,35 @@ impl GraphemeCursor {
         if self.offset == chunk_start {
             return Err(GraphemeIncomplete::PrevChunk);
         }
+        self.check_char_boundary(chunk, chunk_start)?;
         let mut iter = chunk[..self.offset.saturating_sub(chunk_start)]
             .chars()
             .rev();
         let mut ch = iter.next().unwrap();
         loop {
             if self.offset == chunk_start {
-                self.resuming = true;
+                self.set_resuming(true);
                 return Err(GraphemeIncomplete::PrevChunk);
             }
-            if self.resuming {
+            if self.resuming() {
                 self.cat_before = Some(self.grapheme_category(ch));
             } else {
                 self.offset -= ch.len_utf8();
                 self.cat_after = self.cat_before.take();
-                self.state = GraphemeState::Unknown;
-                if let Some(incb_linker_count) = self.incb_linker_count {
-                    self.ris_count = if incb_linker_count > 0 && crate::tables::is_incb_linker(ch) {
-                        Some(incb_linker_count - 1)
-                    } else if crate::tables::derived_property::InCB_Extend(ch) {
-                        Some(incb_linker_count)
-                    } else {
-                        None
-                    };
+                self.set_state(GraphemeState::Unknown);
+                if let Some(incb_linker_count) = self.incb_linker_count.get() {
+                    self.incb_linker_count = PackedOption::new(
+                        if incb_linker_count > 0 && crate::tables::is_incb_linker(ch) {
+                            Some(incb_linker_count - 1)
+                        } else if crate::tables::derived_property::InCB_Extend(ch) {
+                            Some(incb_linker_count)
+                        } else {
+                            None
+                        },
+                    );
                 }
-                if let Some(ris_count) = self.ris_count {
-                    self.ris_count = if ris_count > 0 {
-                        Some(ris_count - 1)
-                    } else {
-                        None
-                    };
+                if let Some(ris_count) = self.ris_count.get() {
+                    self.ris_count = PackedOption::new(ris_count.checked_sub(1));
                 }
                 if let Some(prev_ch) = iter.next() {
                     ch = prev_ch;
@@ -821,17 +3497,17 @@ impl GraphemeCursor {
                 } else if self.offset == 0 {
                     self.decide(true);
                 } else {
-                    self.resuming = true;
+                    self.set_resuming(true);
                     self.cat_after = Some(self.grapheme_category(ch));
                     return Err(GraphemeIncomplete::PrevChunk);
                 }
             }
-            self.resuming = true;
+            self.set_resuming(true);
             if self.is_boundary(chunk, chunk_start)? {
-                self.resuming = false;
+                self.set_resuming(false);
                 return Ok(Some(self.offset));
             }
-            self.resuming = false;
+            self.set_resuming(false);
         }
     }
 }
@@ -860,6 +3536,222 @@ fn test_grapheme_cursor_chunk_start_require_precontext() {
     assert_eq!(c.is_boundary(&s[1..], 1), Ok(false));
 }
 
+#[test]
+fn test_grapheme_cursor_cur_cats() {
+    let s = "\r\n\u{1f1fa}\u{1f1f8}";
+    let mut c = GraphemeCursor::new(1, s.len(), true);
+    assert_eq!(
+        c.is_boundary(&s[1..], 1),
+        Err(GraphemeIncomplete::PreContext(1))
+    );
+    assert_eq!(c.cur_cat_before(), None);
+    assert_eq!(c.cur_cat_after(), Some(GraphemeBreakProperty::LF));
+    c.provide_context(&s[..1], 0);
+    assert_eq!(c.is_boundary(&s[1..], 1), Ok(false));
+    assert_eq!(c.cur_cat_before(), Some(GraphemeBreakProperty::CR));
+
+    assert_eq!(c.next_boundary(s, 0), Ok(Some(2)));
+    assert_eq!(c.cur_cat_before(), Some(GraphemeBreakProperty::LF));
+    assert_eq!(
+        c.cur_cat_after(),
+        Some(GraphemeBreakProperty::RegionalIndicator)
+    );
+
+    c.set_cursor(6);
+    assert_eq!((c.cur_cat_before(), c.cur_cat_after()), (None, None));
+}
+
+#[test]
+fn test_grapheme_cursor_nth_boundary_chunked() {
+    // flags and an odd RIS, with a chunk boundary after every character outside the RIS run
+    let s = "a\u{1f1fa}\u{1f1f8}\u{1f1fa}\u{1f1f8}\u{1f1fa}b\u{301}c";
+    let ris = |c: char| ('\u{1f1e6}'..='\u{1f1ff}').contains(&c);
+    let starts = || {
+        s.char_indices()
+            .map(|(i, _)| i)
+            .filter(|&i| !(s[..i].chars().next_back().is_some_and(ris) && s[i..].starts_with(ris)))
+            .chain(Some(s.len()))
+    };
+    let chunk_at = |i: usize| {
+        let end = starts().find(|&j| j > i).unwrap_or(s.len());
+        &s[i..end]
+    };
+    let context_before = |i: usize| {
+        let start = starts().take_while(|&j| j < i).last().unwrap();
+        (&s[start..i], start)
+    };
+
+    for n in 0..8 {
+        let mut expected = GraphemeCursor::new(0, s.len(), true);
+        let mut result = Some(0);
+        for _ in 0..n {
+            result = expected.next_boundary(s, 0).unwrap();
+        }
+
+        let mut cursor = GraphemeCursor::new(0, s.len(), true);
+        let mut remaining = n;
+        let mut chunk_start = 0;
+        let found = loop {
+            match cursor.nth_boundary(&mut remaining, chunk_at(chunk_start), chunk_start) {
+                Ok(found) => break found,
+                Err(GraphemeIncomplete::NextChunk) => {
+                    chunk_start += chunk_at(chunk_start).len();
+                }
+                Err(GraphemeIncomplete::PreContext(i)) => {
+                    let (context, start) = context_before(i);
+                    cursor.provide_context(context, start);
+                }
+                Err(e) => panic!("{:?}", e),
+            }
+        };
+        assert_eq!(found, result, "n = {}", n);
+
+        let mut back = GraphemeCursor::new(s.len(), s.len(), true);
+        let mut remaining = n;
+        let mut chunk_start = context_before(s.len()).1;
+        let found = loop {
+            match back.nth_prev_boundary(&mut remaining, chunk_at(chunk_start), chunk_start) {
+                Ok(found) => break found,
+                Err(GraphemeIncomplete::PrevChunk) => {
+                    chunk_start = context_before(chunk_start).1;
+                }
+                Err(GraphemeIncomplete::PreContext(i)) => {
+                    let (context, start) = context_before(i);
+                    back.provide_context(context, start);
+                }
+                Err(e) => panic!("{:?}", e),
+            }
+        };
+        let mut expected = GraphemeCursor::new(s.len(), s.len(), true);
+        let mut result = Some(s.len());
+        for _ in 0..n {
+            result = expected.prev_boundary(s, 0).unwrap();
+        }
+        assert_eq!(found, result, "n = {} back", n);
+    }
+}
+
+#[test]
+fn test_grapheme_cursor_raw_round_trip() {
+    let s = "a\u{915}\u{94d}\u{937}\r\n\u{1f468}\u{200d}\u{1f469}\u{1f3fb}b\u{301}\u{903}\
+             \u{1f3f4}\u{e0067}\u{e0062}\u{e007f}";
+    let tailored = GraphemeOptions::new()
+        .spacing_mark_joins(false)
+        .split_tag_sequences(true);
+    for &options in &[
+        GraphemeOptions::new(),
+        GraphemeOptions::new().extended(false),
+        tailored,
+    ] {
+        let mut whole = GraphemeCursor::with_options(0, s.len(), options);
+        let mut cursor = GraphemeCursor::with_options(0, s.len(), options);
+        let mut chunk_start = 0;
+        loop {
+            let expected = whole.next_boundary(s, 0).unwrap();
+            let found = loop {
+                let end = s[chunk_start..]
+                    .char_indices()
+                    .nth(1)
+                    .map_or(s.len(), |(i, _)| chunk_start + i);
+                let result = cursor.next_boundary(&s[chunk_start..end], chunk_start);
+                // restore every cursor waiting for more text from its saved state
+                let raw = cursor.to_raw();
+                cursor = GraphemeCursor::from_raw(raw);
+                assert_eq!(cursor.to_raw(), raw);
+                match result {
+                    Ok(found) => break found,
+                    Err(GraphemeIncomplete::NextChunk) => chunk_start = end,
+                    Err(GraphemeIncomplete::PreContext(i)) => {
+                        let start = s[..i].char_indices().next_back().unwrap().0;
+                        cursor.provide_context(&s[start..i], start);
+                    }
+                    Err(e) => panic!("{:?}", e),
+                }
+            };
+            assert_eq!(found, expected);
+            if found.is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_cursor_reset() {
+    fn assert_same(reused: &mut GraphemeCursor, fresh: &mut GraphemeCursor, s: &str) {
+        loop {
+            let boundary = fresh.next_boundary(s, 0);
+            assert_eq!(reused.next_boundary(s, 0), boundary);
+            if boundary == Ok(None) {
+                break;
+            }
+        }
+    }
+
+    let first = "\u{1f1fa}\u{1f1f8}\u{1f1fa}a\u{301}";
+    let second = "\u{915}\u{94d}\u{937}\r\n\u{1f468}\u{200d}\u{1f469}";
+    let mut reused = GraphemeCursor::new(0, first.len(), true);
+    while reused.next_boundary(first, 0).unwrap().is_some() {}
+    for &s in &[second, first] {
+        for &extended in &[true, false] {
+            // leave the cursor waiting for context from the previous string
+            let mid = s.char_indices().nth(2).unwrap().0;
+            reused.set_cursor(mid);
+            let _ = reused.is_boundary(&s[mid..], mid);
+            reused.reset(0, s.len(), extended);
+            let mut fresh = GraphemeCursor::new(0, s.len(), extended);
+            assert_same(&mut reused, &mut fresh, s);
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_cursor_offset_inside_char() {
+    let s = "\u{1f602}";
+    let mut c = GraphemeCursor::new(1, s.len(), true);
+    assert_eq!(c.is_boundary(s, 0), Err(GraphemeIncomplete::InvalidOffset));
+    assert_eq!(
+        c.next_boundary(s, 0),
+        Err(GraphemeIncomplete::InvalidOffset)
+    );
+    assert_eq!(
+        c.prev_boundary(s, 0),
+        Err(GraphemeIncomplete::InvalidOffset)
+    );
+
+    // and within a later chunk
+    let s = "ab\u{e9}c";
+    let mut c = GraphemeCursor::new(3, s.len(), true);
+    assert_eq!(
+        c.next_boundary(&s[2..], 2),
+        Err(GraphemeIncomplete::InvalidOffset)
+    );
+    c.set_cursor(2);
+    assert_eq!(c.next_boundary(&s[2..], 2), Ok(Some(4)));
+}
+
+#[test]
+fn test_grapheme_cursor_prepend_precontext() {
+    // a prepended character joins what follows it, except a control (GB5)
+    for &(s, expected) in &[
+        ("\u{600}a", false),
+        ("\u{600}\r", true),
+        ("\u{600}\n", true),
+    ] {
+        let mut c = GraphemeCursor::new(2, s.len(), true);
+        assert_eq!(
+            c.is_boundary(&s[2..], 2),
+            Err(GraphemeIncomplete::PreContext(2))
+        );
+        c.provide_context(&s[..2], 0);
+        assert_eq!(c.is_boundary(&s[2..], 2), Ok(expected));
+        assert_eq!(
+            GraphemeCursor::new(2, s.len(), true).is_boundary(s, 0),
+            Ok(expected)
+        );
+    }
+}
+
 #[test]
 fn test_grapheme_cursor_prev_boundary() {
     let s = "abcd";
@@ -881,3 +3773,554 @@ fn test_grapheme_cursor_prev_boundary_chunk_start() {
     );
     assert_eq!(c.prev_boundary(&s[..2], 0), Ok(Some(1)));
 }
+
+#[test]
+fn test_grapheme_category_bmp() {
+    use crate::tables::grapheme as gr;
+
+    // Every character, both outside and inside the cursor's cached range.
+    let mut cursor = GraphemeCursor::new(0, 0, true);
+    for c in (0..=0x10ffff).filter_map(core::char::from_u32) {
+        let expected = gr::grapheme_category(c).2;
+        assert_eq!(
+            gr::grapheme_category_bmp(c).is_some(),
+            c <= '\u{ffff}',
+            "{:?}",
+            c
+        );
+        cursor.grapheme_cat_cache = (0, 0, GraphemeCat::GC_Control);
+        assert_eq!(cursor.table_category(c), expected, "{:?}", c);
+        assert_eq!(cursor.table_category(c), expected, "{:?}", c);
+    }
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_grapheme_cursor_size() {
+    assert_eq!(core::mem::size_of::<GraphemeCursor>(), 64);
+}
+
+#[test]
+fn test_grapheme_cursor_incb_conjunct() {
+    // GB9c: "\u{915}\u{94d}\u{915}" and "\u{915}\u{94d}\u{200d}\u{937}" are single clusters,
+    // but the leading consonant is not part of the first one
+    let s = "\u{915}\u{915}\u{94d}\u{915}a\u{915}\u{94d}\u{200d}\u{937}";
+    let boundaries = [0, 3, 12, 13, 25];
+    // the single-character chunk that starts at `start`
+    let chunk_at = |start: usize| {
+        let len = s[start..].chars().next().unwrap().len_utf8();
+        &s[start..start + len]
+    };
+    let chunk_before = |end: usize| {
+        let len = s[..end].chars().next_back().unwrap().len_utf8();
+        (&s[end - len..end], end - len)
+    };
+
+    // forwards then backwards with the same cursor
+    let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    for &b in &boundaries[1..] {
+        assert_eq!(cursor.next_boundary(s, 0), Ok(Some(b)));
+    }
+    assert_eq!(cursor.next_boundary(s, 0), Ok(None));
+    for &b in boundaries[..boundaries.len() - 1].iter().rev() {
+        assert_eq!(cursor.prev_boundary(s, 0), Ok(Some(b)));
+    }
+    assert_eq!(cursor.prev_boundary(s, 0), Ok(None));
+
+    // forwards, one character per chunk
+    let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    let (mut pos, mut found) = (0, 1);
+    while pos < s.len() {
+        match cursor.next_boundary(chunk_at(pos), pos) {
+            Ok(Some(b)) => {
+                assert_eq!(b, boundaries[found]);
+                found += 1;
+                pos = b;
+            }
+            Err(GraphemeIncomplete::NextChunk) => pos += chunk_at(pos).len(),
+            Err(GraphemeIncomplete::PreContext(n)) => {
+                let (ctx, ctx_start) = chunk_before(n);
+                cursor.provide_context(ctx, ctx_start);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    assert_eq!(found, boundaries.len());
+
+    // backwards, one character per chunk
+    let mut cursor = GraphemeCursor::new(s.len(), s.len(), true);
+    let (mut pos, mut found) = (s.len(), boundaries.len() - 1);
+    while pos > 0 {
+        let (chunk, chunk_start) = chunk_before(pos);
+        match cursor.prev_boundary(chunk, chunk_start) {
+            Ok(Some(b)) => {
+                found -= 1;
+                assert_eq!(b, boundaries[found]);
+                pos = b;
+            }
+            Err(GraphemeIncomplete::PrevChunk) => pos = chunk_start,
+            Err(GraphemeIncomplete::PreContext(n)) => {
+                let (ctx, ctx_start) = chunk_before(n);
+                cursor.provide_context(ctx, ctx_start);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    assert_eq!(found, 0);
+}
+
+#[test]
+fn test_grapheme_cursor_ris_chunked() {
+    // GB12, GB13: eight regional indicators make four flags however the text is chunked
+    let s = "\u{1f1f7}\u{1f1f8}\u{1f1ee}\u{1f1f4}\u{1f1fa}\u{1f1f8}\u{1f1e9}\u{1f1ea}";
+    let boundaries = [0, 8, 16, 24, 32];
+    // a chunk can't split a character, so these are as small as chunks get
+    let chunk_at = |start: usize| (&s[start..start + 4], start);
+    let chunk_before = |end: usize| (&s[end - 4..end], end - 4);
+
+    let mut cursor = GraphemeCursor::new(0, s.len(), true);
+    for &b in &boundaries[1..] {
+        assert_eq!(cursor.next_boundary(s, 0), Ok(Some(b)));
+    }
+    assert_eq!(cursor.next_boundary(s, 0), Ok(None));
+
+    for is_extended in [true, false].iter().cloned() {
+        // forwards, one character per chunk
+        let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+        let (mut pos, mut found) = (0, 1);
+        while pos < s.len() {
+            let (chunk, chunk_start) = chunk_at(pos);
+            match cursor.next_boundary(chunk, chunk_start) {
+                Ok(Some(b)) => {
+                    assert_eq!(b, boundaries[found]);
+                    found += 1;
+                    pos = b;
+                }
+                Err(GraphemeIncomplete::NextChunk) => pos += 4,
+                Err(GraphemeIncomplete::PreContext(n)) => {
+                    let (ctx, ctx_start) = chunk_before(n);
+                    cursor.provide_context(ctx, ctx_start);
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(found, boundaries.len());
+
+        // backwards, one character per chunk
+        let mut cursor = GraphemeCursor::new(s.len(), s.len(), is_extended);
+        let (mut pos, mut found) = (s.len(), boundaries.len() - 1);
+        while pos > 0 {
+            let (chunk, chunk_start) = chunk_before(pos);
+            match cursor.prev_boundary(chunk, chunk_start) {
+                Ok(Some(b)) => {
+                    found -= 1;
+                    assert_eq!(b, boundaries[found]);
+                    pos = b;
+                }
+                Err(GraphemeIncomplete::PrevChunk) => pos = chunk_start,
+                Err(GraphemeIncomplete::PreContext(n)) => {
+                    let (ctx, ctx_start) = chunk_before(n);
+                    cursor.provide_context(ctx, ctx_start);
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(found, 0);
+
+        // from every offset, with only the character after it to start with
+        for offset in (0..s.len()).step_by(4) {
+            let mut cursor = GraphemeCursor::new(offset, s.len(), is_extended);
+            let (chunk, chunk_start) = chunk_at(offset);
+            let is_boundary = loop {
+                match cursor.is_boundary(chunk, chunk_start) {
+                    Ok(b) => break b,
+                    Err(GraphemeIncomplete::PreContext(n)) => {
+                        let (ctx, ctx_start) = chunk_before(n);
+                        cursor.provide_context(ctx, ctx_start);
+                    }
+                    other => panic!("unexpected {:?}", other),
+                }
+            };
+            assert_eq!(is_boundary, offset % 8 == 0, "{}", offset);
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_cursor_prev_boundary_emoji_chunked() {
+    // GB9 joins the modifier to its base, and GB11 looks back past it for the emoji before a
+    // joiner, so each string is split into two chunks at every character boundary
+    let tests: &[(&str, &[usize])] = &[
+        ("\u{1f44d}\u{1f3fc}", &[0, 8]),
+        ("a\u{1f44d}\u{1f3fc}", &[0, 1, 9]),
+        ("\u{1f468}\u{1f3fc}\u{200d}\u{1f469}\u{1f3fd}", &[0, 19]),
+        ("\u{1f44d}\u{1f3fc}\u{301}\u{200d}\u{1f44d}b", &[0, 17, 18]),
+        ("a\u{1f3fc}\u{200d}\u{1f44d}", &[0, 8, 12]),
+    ];
+    for &(s, boundaries) in tests {
+        let starts = boundaries[..boundaries.len() - 1].iter().cloned();
+        assert!(new_grapheme_indices(s, true).map(|(i, _)| i).eq(starts));
+
+        for split in (1..s.len()).filter(|&i| s.is_char_boundary(i)) {
+            let chunks = [(&s[..split], 0), (&s[split..], split)];
+            let mut cursor = GraphemeCursor::new(s.len(), s.len(), true);
+            let mut chunk = 1;
+            let mut found = boundaries.len() - 1;
+            loop {
+                let (text, start) = chunks[chunk];
+                match cursor.prev_boundary(text, start) {
+                    Ok(Some(b)) => {
+                        found -= 1;
+                        assert_eq!(b, boundaries[found], "{:?} split at {}", s, split);
+                    }
+                    Ok(None) => break,
+                    Err(GraphemeIncomplete::PrevChunk) => chunk -= 1,
+                    Err(GraphemeIncomplete::PreContext(n)) => {
+                        assert_eq!(n, split);
+                        cursor.provide_context(chunks[0].0, 0);
+                    }
+                    other => panic!("unexpected {:?}", other),
+                }
+            }
+            assert_eq!(found, 0, "{:?} split at {}", s, split);
+        }
+
+        // one character per chunk
+        let chunk_before = |end: usize| {
+            let len = s[..end].chars().next_back().unwrap().len_utf8();
+            (&s[end - len..end], end - len)
+        };
+        let mut cursor = GraphemeCursor::new(s.len(), s.len(), true);
+        let (mut pos, mut found) = (s.len(), boundaries.len() - 1);
+        while pos > 0 {
+            let (chunk, chunk_start) = chunk_before(pos);
+            match cursor.prev_boundary(chunk, chunk_start) {
+                Ok(Some(b)) => {
+                    found -= 1;
+                    assert_eq!(b, boundaries[found], "{:?}", s);
+                    pos = b;
+                }
+                Err(GraphemeIncomplete::PrevChunk) => pos = chunk_start,
+                Err(GraphemeIncomplete::PreContext(n)) => {
+                    let (ctx, ctx_start) = chunk_before(n);
+                    cursor.provide_context(ctx, ctx_start);
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(found, 0, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_next_boundary_at_end_of_string() {
+    let tests = [
+        "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+        "a\u{1f468}\u{1f3fb}\u{200d}\u{1f469}",
+        "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}",
+        "\u{1f1fa}\u{1f1f8}\u{1f1e6}",
+        "\u{915}\u{94d}\u{915}",
+        "ab\r\n",
+        "a\u{200d}",
+    ];
+    for s in tests.iter() {
+        for &is_extended in &[true, false] {
+            // one chunk covering the whole string never asks for another
+            let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+            let mut last = 0;
+            while let Some(b) = cursor.next_boundary(s, 0).unwrap() {
+                last = b;
+            }
+            assert_eq!(last, s.len());
+
+            // once the chunk reaching the end has been given, neither does the last cluster
+            for (split, _) in s.char_indices().skip(1) {
+                let mut cursor = GraphemeCursor::new(0, s.len(), is_extended);
+                let mut chunk_start = 0;
+                let mut last = 0;
+                loop {
+                    let chunk = if chunk_start == 0 {
+                        &s[..split]
+                    } else {
+                        &s[split..]
+                    };
+                    match cursor.next_boundary(chunk, chunk_start) {
+                        Ok(Some(b)) => last = b,
+                        Ok(None) => break,
+                        Err(GraphemeIncomplete::NextChunk) => {
+                            assert_eq!(chunk_start, 0);
+                            chunk_start = split;
+                        }
+                        Err(GraphemeIncomplete::PreContext(_)) => {
+                            cursor.provide_context(&s[..split], 0)
+                        }
+                        Err(e) => panic!("{:?}", e),
+                    }
+                }
+                assert_eq!(last, s.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_graphemes_over_chunks() {
+    struct Chunks<'a>(&'a [&'a str]);
+
+    impl<'a> TextSource for Chunks<'a> {
+        fn chunk_at(&self, byte: usize) -> (&str, usize) {
+            let mut start = 0;
+            for chunk in self.0 {
+                if byte < start + chunk.len() {
+                    return (chunk, start);
+                }
+                start += chunk.len();
+            }
+            unreachable!()
+        }
+    }
+
+    // Every codepoint gets its own chunk, forcing both NextChunk and PreContext requests.
+    let s = "\u{1f1fa}\u{1f1f8}a\u{301}\r\n\u{915}\u{94d}\u{937}\u{93f}";
+    let mut chunks = [""; 10];
+    let mut n = 0;
+    for (i, ch) in s.char_indices() {
+        chunks[n] = &s[i..i + ch.len_utf8()];
+        n += 1;
+    }
+    assert_eq!(n, chunks.len());
+
+    let source = Chunks(&chunks);
+    let mut whole = graphemes_over(s, s.len(), true);
+    let mut chunked = graphemes_over(&source, s.len(), true);
+    for g in new_grapheme_indices(s, true) {
+        let range = g.0..g.0 + g.1.len();
+        assert_eq!(whole.next(), Some(range.clone()));
+        assert_eq!(chunked.next(), Some(range));
+    }
+    assert_eq!(whole.next(), None);
+    assert_eq!(chunked.next(), None);
+}
+
+#[test]
+fn test_tag_sequences() {
+    let flags = [
+        "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+        "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}",
+        "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+    ];
+    let split = GraphemeOptions::new().split_tag_sequences(true);
+    for flag in flags {
+        assert!(is_tag_sequence_cluster(flag));
+        assert!(new_graphemes(flag, true).eq([flag]));
+        assert!(new_graphemes(flag, false).eq([flag]));
+
+        let mut parts = new_graphemes_with(flag, split);
+        assert_eq!(parts.next(), Some("\u{1f3f4}"));
+        assert!(parts.eq(flag
+            .char_indices()
+            .skip(1)
+            .map(|(i, c)| &flag[i..i + c.len_utf8()])));
+        assert!(new_graphemes_with(flag, split).rev().eq(flag
+            .char_indices()
+            .rev()
+            .map(|(i, c)| &flag[i..i + c.len_utf8()])));
+    }
+
+    assert!(!is_tag_sequence_cluster("\u{1f3f4}\u{e007f}"));
+    assert!(!is_tag_sequence_cluster("\u{1f3f4}\u{e0067}\u{e0062}"));
+    assert!(!is_tag_sequence_cluster("a\u{e0067}\u{e007f}"));
+    assert!(!is_tag_sequence_cluster(
+        "\u{1f3f4}\u{e0067}\u{301}\u{e007f}"
+    ));
+
+    // other clusters are unaffected
+    let s = "e\u{301}\u{1f1ec}\u{1f1e7}\u{1f468}\u{200d}\u{1f469}";
+    assert!(new_graphemes_with(s, split).eq(new_graphemes(s, true)));
+}
+
+#[test]
+fn test_extended_pictographic_override() {
+    fn is_custom(c: char) -> bool {
+        c == '\u{e000}' || c == 'x'
+    }
+    let options = GraphemeOptions::new().extended_pictographic(is_custom);
+
+    let s = "\u{1f468}\u{200d}\u{e000}\u{200d}x";
+    assert!(new_graphemes(s, true).eq(["\u{1f468}\u{200d}", "\u{e000}\u{200d}", "x"]));
+    assert!(new_graphemes_with(s, options).eq([s]));
+    assert!(new_graphemes_with(s, options).rev().eq([s]));
+
+    // characters with other properties keep them
+    let options = GraphemeOptions::new().extended_pictographic(|c| c == '\u{301}');
+    let s = "\u{1f468}\u{200d}\u{301}";
+    assert!(new_graphemes_with(s, options).eq([s]));
+    let s = "\u{301}\u{301}";
+    assert!(new_graphemes_with(s, options).eq([s]));
+}
+
+#[test]
+fn test_spacing_mark_and_prepend_joins() {
+    // KA + spacing vowel sign AA, ARABIC NUMBER SIGN + digit, and a Prepend before a SpacingMark
+    let s = "\u{915}\u{93e}\u{600}1\u{600}\u{93e}";
+    let cases: [(bool, bool, &[&str]); 4] = [
+        (
+            true,
+            true,
+            &["\u{915}\u{93e}", "\u{600}1", "\u{600}\u{93e}"],
+        ),
+        (
+            true,
+            false,
+            &["\u{915}\u{93e}", "\u{600}", "1", "\u{600}\u{93e}"],
+        ),
+        (
+            false,
+            true,
+            &["\u{915}", "\u{93e}", "\u{600}1", "\u{600}\u{93e}"],
+        ),
+        (
+            false,
+            false,
+            &["\u{915}", "\u{93e}", "\u{600}", "1", "\u{600}", "\u{93e}"],
+        ),
+    ];
+    for &(spacing_mark, prepend, expected) in &cases {
+        let options = GraphemeOptions::new()
+            .spacing_mark_joins(spacing_mark)
+            .prepend_joins(prepend);
+        assert_eq!(options.is_extended(), spacing_mark && prepend);
+        assert!(new_graphemes_with(s, options).eq(expected.iter().copied()));
+        assert!(new_graphemes_with(s, options)
+            .rev()
+            .eq(expected.iter().rev().copied()));
+    }
+
+    // both rules on is extended, both off is legacy
+    let both = GraphemeOptions::new();
+    let neither = both.spacing_mark_joins(false).prepend_joins(false);
+    assert!(new_graphemes_with(s, both).eq(new_graphemes(s, true)));
+    assert!(new_graphemes_with(s, neither).eq(new_graphemes(s, false)));
+}
+
+#[test]
+fn test_mark_counts() {
+    let s = "o\u{323}\u{300}\u{307}\u{915}\u{93f}\u{1f44d}\u{1f3fd}\u{1f468}\u{200d}\u{1f469}\r\n";
+    let expected = [
+        ("o\u{323}\u{300}\u{307}", 3),
+        ("\u{915}\u{93f}", 1),
+        ("\u{1f44d}\u{1f3fd}", 1),
+        ("\u{1f468}\u{200d}\u{1f469}", 0),
+        ("\r\n", 0),
+    ];
+    assert!(new_grapheme_mark_counts(s, true).eq(expected));
+    assert!(new_grapheme_mark_counts(s, true)
+        .rev()
+        .eq(expected.iter().copied().rev()));
+
+    // legacy clusters don't include spacing marks
+    assert!(new_grapheme_mark_counts("\u{915}\u{93f}", false).eq([("\u{915}", 0), ("\u{93f}", 0)]));
+}
+
+#[test]
+fn test_pending_scan_offset() {
+    let s = "abcd";
+    let mut cursor = GraphemeCursor::new(4, s.len(), true);
+    assert_eq!(cursor.prev_boundary(&s[2..], 2), Ok(Some(3)));
+    assert_eq!(cursor.pending_scan_offset(), None);
+    assert_eq!(
+        cursor.prev_boundary(&s[2..], 2),
+        Err(GraphemeIncomplete::PrevChunk)
+    );
+    assert_eq!(cursor.pending_scan_offset(), Some(2));
+    assert_eq!(cursor.prev_boundary(&s[..2], 0), Ok(Some(2)));
+    assert_eq!(cursor.pending_scan_offset(), None);
+}
+
+#[test]
+fn test_decide_pair() {
+    let chars = [
+        'a',
+        '\r',
+        '\n',
+        '\u{0}',
+        ' ',
+        '\u{301}',
+        '\u{200d}',
+        '\u{200c}',
+        '\u{915}',
+        '\u{93f}',
+        '\u{94d}',
+        '\u{600}',
+        '\u{1100}',
+        '\u{1161}',
+        '\u{11a8}',
+        '\u{ac00}',
+        '\u{ac01}',
+        '\u{1f1e6}',
+        '\u{1f466}',
+        '\u{a9}',
+        '\u{e0067}',
+        '\u{fe0f}',
+    ];
+    let mut buf = [0; 8];
+    for &before in &chars {
+        for &after in &chars {
+            let len = before.encode_utf8(&mut buf).len();
+            let len2 = after.encode_utf8(&mut buf[len..]).len();
+            let s = core::str::from_utf8(&buf[..len + len2]).unwrap();
+            for &is_extended in &[true, false] {
+                let first = new_graphemes(s, is_extended).next().unwrap();
+                assert_eq!(
+                    decide_pair(before, after, is_extended),
+                    first.len() == len,
+                    "{:?}",
+                    s
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cluster_kind() {
+    // the discriminants are part of the public API and must never change
+    assert_eq!(ClusterKind::Other as u8, 0);
+    assert_eq!(ClusterKind::Newline as u8, 1);
+    assert_eq!(ClusterKind::Control as u8, 2);
+    assert_eq!(ClusterKind::Whitespace as u8, 3);
+    assert_eq!(ClusterKind::Letter as u8, 4);
+    assert_eq!(ClusterKind::Number as u8, 5);
+    assert_eq!(ClusterKind::Emoji as u8, 6);
+    assert_eq!(ClusterKind::Flag as u8, 7);
+    for value in 0..=255 {
+        if let Some(kind) = ClusterKind::from_u8(value) {
+            assert_eq!(u8::from(kind), value);
+        } else {
+            assert!(value > 7);
+        }
+    }
+
+    let cases = [
+        ("", ClusterKind::Other),
+        ("\r\n", ClusterKind::Newline),
+        ("\u{85}", ClusterKind::Whitespace),
+        ("\t", ClusterKind::Whitespace),
+        ("\u{3000}", ClusterKind::Whitespace),
+        ("\u{0}", ClusterKind::Control),
+        ("\u{200b}", ClusterKind::Control),
+        ("e\u{301}", ClusterKind::Letter),
+        ("\u{ac00}", ClusterKind::Letter),
+        ("\u{4e00}", ClusterKind::Letter),
+        ("\u{661}", ClusterKind::Number),
+        ("\u{2163}", ClusterKind::Letter),
+        ("\u{bd}", ClusterKind::Number),
+        ("\u{1f468}\u{200d}\u{1f469}", ClusterKind::Emoji),
+        ("\u{1f1e6}", ClusterKind::Flag),
+        ("(", ClusterKind::Other),
+        ("\u{301}", ClusterKind::Other),
+    ];
+    for &(cluster, kind) in &cases {
+        assert_eq!(ClusterKind::of(cluster), kind, "{:?}", cluster);
+    }
+}
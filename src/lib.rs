@@ -14,9 +14,11 @@
 //! ```rust
 //! extern crate unicode_segmentation;
 //!
+//! # #[cfg(feature = "str-impl")]
 //! use unicode_segmentation::UnicodeSegmentation;
 //!
 //! fn main() {
+//! # #[cfg(feature = "str-impl")] {
 //!     let s = "a̐éö̲\r\n";
 //!     let g = UnicodeSegmentation::graphemes(s, true).collect::<Vec<&str>>();
 //!     let b: &[_] = &["a̐", "é", "ö̲", "\r\n"];
@@ -31,6 +33,7 @@
 //!     let w = s.split_word_bounds().collect::<Vec<&str>>();
 //!     let b: &[_] = &["The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", "  ", "fox"];
 //!     assert_eq!(w, b);
+//! # }
 //! }
 //! ```
 //!
@@ -39,6 +42,15 @@
 //! unicode-segmentation does not depend on libstd, so it can be used in crates
 //! with the `#![no_std]` attribute.
 //!
+//! The default `alloc` feature enables the few APIs that return owned data, such as
+//! `new_owned_graphemes`. Disable default features to build without `alloc`.
+//!
+//! The default `str-impl` feature implements [`UnicodeSegmentation`] and
+//! [`UnicodeSegmentationExt`] for `str`. See their documentation for what is left without it.
+//!
+//! The optional `memchr` feature uses the [memchr](https://crates.io/crates/memchr) crate to
+//! skip ahead faster through long stretches of ASCII text when splitting it into sentences.
+//!
 //! # crates.io
 //!
 //! You can use this package in your project by adding the following
@@ -56,20 +68,95 @@
 )]
 #![no_std]
 
-pub use grapheme::{GraphemeCursor, GraphemeIncomplete};
-pub use grapheme::{GraphemeIndices, Graphemes};
-pub use sentence::{USentenceBoundIndices, USentenceBounds, UnicodeSentences};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::ops::{ControlFlow, Range};
+
+pub use bidi::{new_sentences_with_direction, Direction, SentencesWithDirection};
+#[cfg(feature = "alloc")]
+pub use canonical::{new_canonical_graphemes, CanonicalGraphemes};
+pub use grapheme::new_graphemes_with_first_char;
+pub use grapheme::GraphemesMut;
+pub use grapheme::{decide_pair, graphemes_over, ClusterKind, GraphemesOver, TextSource};
+pub use grapheme::{find_safe_grapheme_boundary, grapheme_count_bytes};
+pub use grapheme::{for_each_grapheme_boundary, grapheme_count_to, grapheme_len_at};
+pub use grapheme::{in_emoji_sequence, is_single_grapheme, last_grapheme_boundary};
+pub use grapheme::{insertion_splits_cluster, trailing_incomplete_cluster_len, would_join_across};
+pub use grapheme::{is_tag_sequence_cluster, GraphemeCursor, GraphemeIncomplete, GraphemeOptions};
+pub use grapheme::{new_char_segments, new_grapheme_chunks, new_grapheme_mode_diff};
+pub use grapheme::{new_grapheme_boundaries_in, new_grapheme_boundary_indices};
+pub use grapheme::{new_grapheme_hashes, new_grapheme_mark_counts, new_graphemes_coalesce};
+pub use grapheme::{new_grapheme_indices, new_grapheme_indices_typed, new_grapheme_ranges};
+pub use grapheme::{new_grapheme_resume_token, resume_graphemes, snap_to_grapheme_boundary};
+pub use grapheme::{new_graphemes, new_graphemes_mut, new_graphemes_typed, new_graphemes_with};
+pub use grapheme::{CharSegments, Graphemes, GraphemesCoalesce, GraphemesWithFirstChar};
+pub use grapheme::{Grapheme, GraphemeIndicesTyped, GraphemesTyped};
+pub use grapheme::{
+    GraphemeBoundariesIn, GraphemeBoundaryIndices, GraphemeChunks, GraphemeIndices,
+    GraphemeMarkCounts,
+};
+pub use grapheme::{GraphemeBreakProperty, GraphemeCursorState, GraphemeMode};
+pub use grapheme::{GraphemeHashes, GraphemeModeDiff, GraphemeRanges, GraphemeResumeToken};
+pub use line::{new_lines_with_terminators, LinesWithTerminators};
+#[cfg(feature = "alloc")]
+pub use owned::new_owned_graphemes;
+#[cfg(feature = "alloc")]
+pub use owned::{collect_graphemes_into, collect_sentence_bounds_into, collect_word_bounds_into};
+#[cfg(feature = "alloc")]
+pub use owned::{graphemes_cow, GraphemesCow, OwnedGraphemes, SmallCluster};
+pub use sentence::new_words_with_sentence_start;
+pub use sentence::UnicodeSentences;
+pub use sentence::{for_each_sentence_boundary, new_enumerate_sentences, new_sentence_starts};
+pub use sentence::{new_sentence_bound_indices, new_sentence_bounds, new_sentence_bounds_with};
+pub use sentence::{new_trimmed_sentences, new_unicode_sentences, new_unicode_sentences_with};
+pub use sentence::{sentence_break_property, SentenceBreakProperty};
+pub use sentence::{EnumerateSentences, USentenceBoundIndices, USentenceBounds};
+pub use sentence::{SentenceCursor, SentenceIncomplete};
+pub use sentence::{SentenceOptions, SentenceStarts, TrimmedSentences, WordsWithSentenceStart};
 pub use tables::UNICODE_VERSION;
-pub use word::{UWordBoundIndices, UWordBounds, UnicodeWordIndices, UnicodeWords};
+pub use width::{display_unit_count, new_grapheme_windows, GraphemeWindows};
+pub use word::{first_word_offset, for_each_word_boundary, new_lex_words, new_mixed_script_words};
+pub use word::{is_word_segment, next_word_boundary_in, word_bounds_bytes};
+pub use word::{new_unicode_word_indices, new_unicode_words, new_unicode_words_with};
+pub use word::{new_word_bound_indices, new_word_bounds, new_word_bounds_with};
+pub use word::{word_break_property, WordBreakProperty};
+pub use word::{LexWords, WordBoundKind, WordOptions};
+pub use word::{MixedScriptWords, UWordBoundIndices, UWordBounds, UnicodeWordIndices};
+pub use word::{UnicodeWords, WordBoundsBytes};
 
+mod bidi;
+#[cfg(feature = "alloc")]
+mod canonical;
 mod grapheme;
+mod line;
+#[cfg(feature = "alloc")]
+mod owned;
+mod script;
 mod sentence;
 #[rustfmt::skip]
 mod tables;
+mod width;
 mod word;
 
 /// Methods for segmenting strings according to
 /// [Unicode Standard Annex #29](http://www.unicode.org/reports/tr29/).
+///
+/// The trait is implemented for `str` when the default `str-impl` feature is enabled. Crates
+/// that wrap this one in an API of their own can disable it, so that these methods don't show
+/// up on every string for their users. Every method is backed by a public function taking the
+/// string as its first argument, such as [`new_graphemes`] for `graphemes` and
+/// [`new_word_bounds`] for `split_word_bounds`, which a wrapping crate can call directly or
+/// use to implement the trait for its own types.
+///
+/// More methods are implemented for `str` by [`UnicodeSegmentationExt`].
+///
+/// ```
+/// use unicode_segmentation::new_graphemes;
+///
+/// let g = new_graphemes("a\u{310}e\u{301}", true).collect::<Vec<&str>>();
+/// assert_eq!(g, ["a\u{310}", "e\u{301}"]);
+/// ```
 pub trait UnicodeSegmentation {
     /// Returns an iterator over the [grapheme clusters][graphemes] of `self`.
     ///
@@ -84,6 +171,7 @@ pub trait UnicodeSegmentation {
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let gr1 = UnicodeSegmentation::graphemes("a\u{310}e\u{301}o\u{308}\u{332}", true)
     ///           .collect::<Vec<&str>>();
@@ -95,6 +183,7 @@ pub trait UnicodeSegmentation {
     /// let b: &[_] = &["a", "\r\n", "b", "🇷🇺", "🇸🇹"];
     ///
     /// assert_eq!(&gr2[..], b);
+    /// # }
     /// ```
     fn graphemes(&self, is_extended: bool) -> Graphemes<'_>;
 
@@ -104,12 +193,14 @@ pub trait UnicodeSegmentation {
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let gr_inds = UnicodeSegmentation::grapheme_indices("a̐éö̲\r\n", true)
     ///               .collect::<Vec<(usize, &str)>>();
     /// let b: &[_] = &[(0, "a̐"), (3, "é"), (6, "ö̲"), (11, "\r\n")];
     ///
     /// assert_eq!(&gr_inds[..], b);
+    /// # }
     /// ```
     fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_>;
 
@@ -126,12 +217,14 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let uws = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
     /// let uw1 = uws.unicode_words().collect::<Vec<&str>>();
     /// let b: &[_] = &["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"];
     ///
     /// assert_eq!(&uw1[..], b);
+    /// # }
     /// ```
     fn unicode_words(&self) -> UnicodeWords<'_>;
 
@@ -149,6 +242,7 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let uwis = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
     /// let uwi1 = uwis.unicode_word_indices().collect::<Vec<(usize, &str)>>();
@@ -156,6 +250,7 @@ pub trait UnicodeSegmentation {
     ///                 (30, "jump"), (35, "32.3"), (40, "feet"), (46, "right")];
     ///
     /// assert_eq!(&uwi1[..], b);
+    /// # }
     /// ```
     fn unicode_word_indices(&self) -> UnicodeWordIndices<'_>;
 
@@ -167,11 +262,13 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let swu1 = "The quick (\"brown\")  fox".split_word_bounds().collect::<Vec<&str>>();
     /// let b: &[_] = &["The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", "  ", "fox"];
     ///
     /// assert_eq!(&swu1[..], b);
+    /// # }
     /// ```
     fn split_word_bounds(&self) -> UWordBounds<'_>;
 
@@ -181,12 +278,14 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let swi1 = "Brr, it's 29.3°F!".split_word_bound_indices().collect::<Vec<(usize, &str)>>();
     /// let b: &[_] = &[(0, "Brr"), (3, ","), (4, " "), (5, "it's"), (9, " "), (10, "29.3"),
     ///                 (14, "°"), (16, "F"), (17, "!")];
     ///
     /// assert_eq!(&swi1[..], b);
+    /// # }
     /// ```
     fn split_word_bound_indices(&self) -> UWordBoundIndices<'_>;
 
@@ -203,12 +302,14 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let uss = "Mr. Fox jumped. [...] The dog was too lazy.";
     /// let us1 = uss.unicode_sentences().collect::<Vec<&str>>();
     /// let b: &[_] = &["Mr. ", "Fox jumped. ", "The dog was too lazy."];
     ///
     /// assert_eq!(&us1[..], b);
+    /// # }
     /// ```
     fn unicode_sentences(&self) -> UnicodeSentences<'_>;
 
@@ -220,12 +321,14 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let ssbs = "Mr. Fox jumped. [...] The dog was too lazy.";
     /// let ssb1 = ssbs.split_sentence_bounds().collect::<Vec<&str>>();
     /// let b: &[_] = &["Mr. ", "Fox jumped. ", "[...] ", "The dog was too lazy."];
     ///
     /// assert_eq!(&ssb1[..], b);
+    /// # }
     /// ```
     fn split_sentence_bounds(&self) -> USentenceBounds<'_>;
 
@@ -235,6 +338,7 @@ pub trait UnicodeSegmentation {
     /// # Example
     ///
     /// ```
+    /// # #[cfg(feature = "str-impl")] {
     /// # use self::unicode_segmentation::UnicodeSegmentation;
     /// let ssis = "Mr. Fox jumped. [...] The dog was too lazy.";
     /// let ssi1 = ssis.split_sentence_bound_indices().collect::<Vec<(usize, &str)>>();
@@ -242,53 +346,1205 @@ pub trait UnicodeSegmentation {
     ///                 (22, "The dog was too lazy.")];
     ///
     /// assert_eq!(&ssi1[..], b);
+    /// # }
     /// ```
     fn split_sentence_bound_indices(&self) -> USentenceBoundIndices<'_>;
 }
 
+/// More methods for segmenting strings, built on the segmentations of [`UnicodeSegmentation`].
+///
+/// The trait is implemented for `str` when the default `str-impl` feature is enabled, and it
+/// can't be implemented outside this crate. As with `UnicodeSegmentation`, every method is
+/// backed by a public function taking the string as its first argument, such as
+/// [`grapheme_count_to`] for `grapheme_count_to`.
+///
+/// ```
+/// # #[cfg(feature = "str-impl")] {
+/// use unicode_segmentation::UnicodeSegmentationExt;
+///
+/// assert_eq!("a\u{310}e\u{301}".grapheme_count_to(3, true), 1);
+/// # }
+/// ```
+pub trait UnicodeSegmentationExt: private::Sealed {
+    /// Returns an iterator over the grapheme clusters of `self` and their byte ranges. See
+    /// `graphemes()` for more information.
+    ///
+    /// The ranges are taken directly from the boundaries found, so `&self[range]` is always
+    /// the cluster yielded with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let gr_ranges = "a\u{310}e\u{301}o\u{308}\u{332}\r\n".grapheme_ranges(true).collect::<Vec<_>>();
+    /// let b: &[_] = &[
+    ///     (0..3, "a\u{310}"),
+    ///     (3..6, "e\u{301}"),
+    ///     (6..11, "o\u{308}\u{332}"),
+    ///     (11..13, "\r\n"),
+    /// ];
+    ///
+    /// assert_eq!(&gr_ranges[..], b);
+    /// # }
+    /// ```
+    fn grapheme_ranges(&self, is_extended: bool) -> GraphemeRanges<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self`, each paired with its first
+    /// `char`. See `graphemes()` for more information.
+    ///
+    /// The `char` is literally the first codepoint of the cluster. For a cluster led by a
+    /// `Prepend` character, such as ARABIC NUMBER SIGN, that is the prepended character and not
+    /// the base it attaches to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let firsts = "a\u{310}\u{600}1\r\n".graphemes_with_first_char(true).collect::<Vec<_>>();
+    /// let b: &[_] = &[("a\u{310}", 'a'), ("\u{600}1", '\u{600}'), ("\r\n", '\r')];
+    ///
+    /// assert_eq!(&firsts[..], b);
+    /// # }
+    /// ```
+    fn graphemes_with_first_char(&self, is_extended: bool) -> GraphemesWithFirstChar<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self` as [`Grapheme`]s, which
+    /// record in their type that they are whole clusters. See `graphemes()` for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{Grapheme, UnicodeSegmentationExt};
+    /// fn is_wide(g: Grapheme<'_>) -> bool {
+    ///     g.chars().count() > 1
+    /// }
+    ///
+    /// let gr = "a\u{310}bc".graphemes_typed(true).collect::<Vec<Grapheme>>();
+    /// assert_eq!(gr, ["a\u{310}", "b", "c"]);
+    /// assert_eq!(gr[0].len_bytes(), 3);
+    /// assert!(is_wide(gr[0]));
+    /// # }
+    /// ```
+    fn graphemes_typed(&self, is_extended: bool) -> GraphemesTyped<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self` as [`Grapheme`]s and their
+    /// byte offsets. See `graphemes()` for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let gr_inds = "a̐é\r\n".grapheme_indices_typed(true).collect::<Vec<_>>();
+    /// assert_eq!(gr_inds[1].0, 3);
+    /// assert_eq!(gr_inds[1].1, "é");
+    /// assert_eq!(gr_inds[2].1.as_str(), "\r\n");
+    /// # }
+    /// ```
+    fn grapheme_indices_typed(&self, is_extended: bool) -> GraphemeIndicesTyped<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self`, each paired with a hash of
+    /// its bytes. See `graphemes()` for more information.
+    ///
+    /// Equal clusters always have equal hashes, so the hashes can be compared first when
+    /// diffing text by clusters. The hash is fast but not cryptographic, can collide, and
+    /// may change between versions of this crate, so it should not be stored or used to
+    /// resist collisions chosen by an attacker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let hashes = "e\u{301}xe\u{301}".graphemes_with_hash(true).collect::<Vec<_>>();
+    /// assert_eq!(hashes[0].0, "e\u{301}");
+    /// assert_eq!(hashes[0].1, hashes[2].1);
+    /// assert_ne!(hashes[0].1, hashes[1].1);
+    /// # }
+    /// ```
+    fn graphemes_with_hash(&self, is_extended: bool) -> GraphemeHashes<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self`, with each run of adjacent
+    /// clusters for which `should_merge` returns `true` joined into a single slice. See
+    /// `graphemes()` for more information.
+    ///
+    /// `should_merge` is called with pairs of adjacent clusters as segmented by the usual rules,
+    /// never with slices it has already merged. Each slice yielded is a contiguous part of
+    /// `self`. Iterating in reverse gives the same slices as long as `should_merge` gives the
+    /// same answer for a pair every time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "a  b\t\u{3000}c";
+    /// let both_space = |a: &str, b: &str| a.trim().is_empty() && b.trim().is_empty();
+    /// let gr1 = s.graphemes_coalesce(true, both_space).collect::<Vec<&str>>();
+    /// let b: &[_] = &["a", "  ", "b", "\t\u{3000}", "c"];
+    ///
+    /// assert_eq!(&gr1[..], b);
+    /// # }
+    /// ```
+    fn graphemes_coalesce<F: Fn(&str, &str) -> bool>(
+        &self,
+        is_extended: bool,
+        should_merge: F,
+    ) -> GraphemesCoalesce<'_, F>;
+
+    /// Returns an iterator over the grapheme clusters of `self`, as tailored by `options`.
+    /// See [`GraphemeOptions`] for the available tailorings. A [`GraphemeMode`] can be passed
+    /// instead to select extended or legacy clusters with no tailoring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{GraphemeMode, GraphemeOptions, UnicodeSegmentationExt};
+    /// let england = "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}";
+    /// let options = GraphemeOptions::new().split_tag_sequences(true);
+    /// let gr1 = england.graphemes_with(options).collect::<Vec<&str>>();
+    /// let b: &[_] = &["\u{1f3f4}", "\u{e0067}", "\u{e0062}", "\u{e0065}", "\u{e006e}",
+    ///                 "\u{e0067}", "\u{e007f}"];
+    ///
+    /// assert_eq!(&gr1[..], b);
+    ///
+    /// let gr2 = "\u{915}\u{93e}".graphemes_with(GraphemeMode::Legacy).collect::<Vec<&str>>();
+    /// assert_eq!(gr2, ["\u{915}", "\u{93e}"]);
+    /// # }
+    /// ```
+    fn graphemes_with<O: Into<GraphemeOptions>>(&self, options: O) -> Graphemes<'_>;
+
+    /// Returns a token recording grapheme segmentation state at byte `offset` of `self`,
+    /// which can later be passed to `resume_graphemes()` to continue iterating from there.
+    ///
+    /// The token does not borrow `self`. If `offset` is not on a grapheme cluster boundary,
+    /// the token refers to the start of the cluster containing `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not on a `char` boundary of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "a\u{310}e\u{301}o\u{308}\u{332}";
+    /// let token = s.grapheme_state_at(3, true);
+    /// assert_eq!(token.offset(), 3);
+    ///
+    /// let rest = s.resume_graphemes(token).collect::<Vec<&str>>();
+    /// let b: &[_] = &["e\u{301}", "o\u{308}\u{332}"];
+    ///
+    /// assert_eq!(&rest[..], b);
+    /// # }
+    /// ```
+    fn grapheme_state_at(&self, offset: usize, is_extended: bool) -> GraphemeResumeToken;
+
+    /// Returns an iterator over the grapheme clusters of `self`, starting at the position
+    /// recorded in `token`. See `grapheme_state_at()` for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `token` was created for a string of a different length than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{UnicodeSegmentation, UnicodeSegmentationExt};
+    /// let s = "🇷🇸🇮🇴 flags";
+    /// let mut token = s.grapheme_state_at(0, true);
+    /// let mut seen = Vec::new();
+    /// loop {
+    ///     // Process one cluster per time slice, keeping only the token in between.
+    ///     let mut iter = s.resume_graphemes(token);
+    ///     match iter.next() {
+    ///         Some(g) => seen.push(g),
+    ///         None => break,
+    ///     }
+    ///     token = s.grapheme_state_at(s.len() - iter.as_str().len(), true);
+    /// }
+    /// assert_eq!(seen, s.graphemes(true).collect::<Vec<&str>>());
+    /// # }
+    /// ```
+    fn resume_graphemes(&self, token: GraphemeResumeToken) -> Graphemes<'_>;
+
+    /// Returns the number of grapheme clusters in `self` that end at or before byte `offset`.
+    ///
+    /// If `offset` is not on a grapheme cluster boundary, the cluster containing it is not
+    /// complete and is not counted, so the result is rounded down. This is the zero-based
+    /// index of the cluster containing `offset`, as an editor would report a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is greater than `self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "a\u{310}e\u{301}o";
+    /// assert_eq!(s.grapheme_count_to(0, true), 0);
+    /// assert_eq!(s.grapheme_count_to(3, true), 1);
+    /// assert_eq!(s.grapheme_count_to(4, true), 1);
+    /// assert_eq!(s.grapheme_count_to(s.len(), true), 3);
+    /// # }
+    /// ```
+    fn grapheme_count_to(&self, offset: usize, is_extended: bool) -> usize;
+
+    /// Returns `true` if `self` is exactly one grapheme cluster. See `graphemes()` for more
+    /// information.
+    ///
+    /// Only the first cluster is scanned, so this is cheaper than counting clusters. The
+    /// empty string is not a grapheme cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// assert!("\u{1f469}\u{200d}\u{1f52c}".is_single_grapheme(true));
+    /// assert!("\r\n".is_single_grapheme(true));
+    /// assert!(!"\u{1f44d}\u{1f44e}".is_single_grapheme(true));
+    /// assert!(!"".is_single_grapheme(true));
+    /// // spacing marks only extend clusters in extended mode
+    /// assert!(!"\u{915}\u{93f}".is_single_grapheme(false));
+    /// # }
+    /// ```
+    fn is_single_grapheme(&self, is_extended: bool) -> bool;
+
+    /// Returns an iterator over the codepoints of `self`, each as a substring.
+    ///
+    /// This is the trivial segmentation that breaks at every character boundary. It gives
+    /// the same items as `chars()` but in the shape of the other segmenters, which makes it
+    /// a convenient baseline for code generic over segmenters, such as differential tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let segments = "e\u{301}\r\n".char_segments().collect::<Vec<&str>>();
+    /// let b: &[_] = &["e", "\u{301}", "\r", "\n"];
+    ///
+    /// assert_eq!(&segments[..], b);
+    /// # }
+    /// ```
+    fn char_segments(&self) -> CharSegments<'_>;
+
+    /// Returns the byte length of the grapheme cluster starting at `offset`. See `graphemes()`
+    /// for more information.
+    ///
+    /// Returns `None` if `offset` is not a grapheme cluster boundary of `self`, or if it is
+    /// `self.len()` so no cluster starts there. Only the one cluster is scanned, which makes
+    /// this suitable for moving a caret forward by one cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "a\u{310}e\u{301}\r\n";
+    /// assert_eq!(s.grapheme_len_at(0, true), Some(3));
+    /// assert_eq!(s.grapheme_len_at(3, true), Some(3));
+    /// assert_eq!(s.grapheme_len_at(6, true), Some(2));
+    /// // inside a cluster
+    /// assert_eq!(s.grapheme_len_at(1, true), None);
+    /// assert_eq!(s.grapheme_len_at(7, true), None);
+    /// // at or past the end
+    /// assert_eq!(s.grapheme_len_at(s.len(), true), None);
+    /// assert_eq!(s.grapheme_len_at(100, true), None);
+    /// # }
+    /// ```
+    fn grapheme_len_at(&self, offset: usize, is_extended: bool) -> Option<usize>;
+
+    /// Returns the byte offset at which the last grapheme cluster of `self` begins, or 0 if
+    /// `self` is empty. See `graphemes()` for more information.
+    ///
+    /// Only the last cluster is scanned, plus any regional indicators before it needed to
+    /// pair up flags, which makes this cheaper than `graphemes(is_extended).next_back()` when
+    /// only the position is needed, for example to delete the last cluster of a text field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let mut s = String::from("caf\u{65}\u{301}\u{1F1EB}\u{1F1F7}");
+    /// s.truncate(s.last_grapheme_boundary(true));
+    /// assert_eq!(s, "cafe\u{301}");
+    /// s.truncate(s.last_grapheme_boundary(true));
+    /// assert_eq!(s, "caf");
+    /// assert_eq!("".last_grapheme_boundary(true), 0);
+    /// # }
+    /// ```
+    fn last_grapheme_boundary(&self, is_extended: bool) -> usize;
+
+    /// Returns the grapheme cluster boundary of `self` nearest to byte `offset`, preferring
+    /// the earlier one when two are equally near. See `graphemes()` for more information.
+    ///
+    /// `offset` does not need to be on a character boundary, and offsets past the end of
+    /// `self` give `self.len()`. This is useful for placing a caret at a byte position that
+    /// came from an arbitrary mapping, such as from screen coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "ab\u{1F1EB}\u{1F1F7}c";
+    /// assert_eq!(s.snap_to_grapheme_boundary(1, true), 1);
+    /// // inside the flag, which spans bytes 2 to 10
+    /// assert_eq!(s.snap_to_grapheme_boundary(5, true), 2);
+    /// assert_eq!(s.snap_to_grapheme_boundary(6, true), 2);
+    /// assert_eq!(s.snap_to_grapheme_boundary(7, true), 10);
+    /// assert_eq!(s.snap_to_grapheme_boundary(100, true), s.len());
+    /// # }
+    /// ```
+    fn snap_to_grapheme_boundary(&self, offset: usize, is_extended: bool) -> usize;
+
+    /// Returns `true` if byte `offset` of `self` falls within an emoji sequence: an extended
+    /// grapheme cluster made up of an emoji and the characters modifying it, such as a skin
+    /// tone modifier, a variation selector, or further emoji joined with U+200D ZERO WIDTH
+    /// JOINER.
+    ///
+    /// Flags, made up of two regional indicators, and keycap sequences such as `1️⃣` also
+    /// count. A lone emoji is not a sequence. `offset` does not need to be on a character
+    /// boundary, and offsets at or past the end of `self` give `false`. This is useful for
+    /// expanding a selection to cover a whole emoji.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// // "👍🏽" spans bytes 1 to 9 and "😀" bytes 9 to 13
+    /// let s = "a\u{1F44D}\u{1F3FD}\u{1F600}";
+    /// assert!(!s.in_emoji_sequence(0));
+    /// assert!(s.in_emoji_sequence(1));
+    /// assert!(s.in_emoji_sequence(6));
+    /// assert!(!s.in_emoji_sequence(9));
+    /// # }
+    /// ```
+    fn in_emoji_sequence(&self, offset: usize) -> bool;
+
+    /// Returns the number of display units of `self`, counting each extended grapheme
+    /// cluster by how much space it takes up: 2 for wide clusters, 0 for clusters made up only
+    /// of invisible characters, and 1 for all others.
+    ///
+    /// A cluster is wide if its first character has the East Asian Width property
+    /// [Wide or Fullwidth](http://www.unicode.org/reports/tr11/), as do ideographs, Hangul
+    /// syllables and most emoji, or if it is a flag or an emoji presentation sequence ending
+    /// in U+FE0F. Invisible characters are controls, format characters, combining marks and
+    /// zero width joiners, but not whitespace. This is a coarse estimate of rendered width,
+    /// suited to limits on text length measured in visible units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// assert_eq!("abc".display_unit_count(), 3);
+    /// assert_eq!("e\u{301}\u{200b}".display_unit_count(), 1);
+    /// assert_eq!("\u{4f60}\u{597d}!".display_unit_count(), 5);
+    /// assert_eq!("\u{1f469}\u{200d}\u{1f4bb} \u{2764}\u{fe0f}".display_unit_count(), 5);
+    /// # }
+    /// ```
+    fn display_unit_count(&self) -> usize;
+
+    /// Returns an iterator over consecutive runs of grapheme clusters of `self` that are each
+    /// at most `max_width` display units wide, as measured by `display_unit_count()`.
+    ///
+    /// Each run is as long as possible, so it only ends early if the next cluster would not
+    /// fit. A single cluster wider than `max_width` is yielded on its own. The runs start and
+    /// end on grapheme cluster boundaries and concatenate to `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let windows = "ab\u{4f60}\u{597d}e\u{301}".grapheme_windows(3, true).collect::<Vec<_>>();
+    /// assert_eq!(windows, ["ab", "\u{4f60}", "\u{597d}e\u{301}"]);
+    ///
+    /// let windows = "\u{4f60}\u{597d}".grapheme_windows(1, true).collect::<Vec<_>>();
+    /// assert_eq!(windows, ["\u{4f60}", "\u{597d}"]);
+    /// # }
+    /// ```
+    fn grapheme_windows(&self, max_width: usize, is_extended: bool) -> GraphemeWindows<'_>;
+
+    /// Returns an iterator over consecutive substrings of `self` that each contain
+    /// `clusters_per_chunk` grapheme clusters, except for the last, which may contain fewer.
+    /// See `graphemes()` for more information.
+    ///
+    /// The substrings start and end on grapheme cluster boundaries and concatenate to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clusters_per_chunk` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let chunks = "a\u{310}e\u{301}o\u{308}\r\nxy".grapheme_chunks(2, true).collect::<Vec<_>>();
+    /// assert_eq!(chunks, ["a\u{310}e\u{301}", "o\u{308}\r\n", "xy"]);
+    ///
+    /// let chunks = "abc".grapheme_chunks(2, true).collect::<Vec<_>>();
+    /// assert_eq!(chunks, ["ab", "c"]);
+    /// # }
+    /// ```
+    fn grapheme_chunks(&self, clusters_per_chunk: usize, is_extended: bool) -> GraphemeChunks<'_>;
+
+    /// Returns an iterator over the byte offsets of all the
+    /// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+    /// of `self`, from `0` to `self.len()` inclusive.
+    ///
+    /// An empty string has the single boundary `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let gb1 = "a\u{310}e\u{301}\r\n".grapheme_boundary_indices(true).collect::<Vec<usize>>();
+    /// let b: &[_] = &[0, 3, 6, 8];
+    ///
+    /// assert_eq!(&gb1[..], b);
+    /// # }
+    /// ```
+    fn grapheme_boundary_indices(&self, is_extended: bool) -> GraphemeBoundaryIndices<'_>;
+
+    /// Returns an iterator over the byte offsets that are
+    /// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+    /// of `self` in one of extended and legacy mode but not in the other, in increasing order.
+    ///
+    /// Extended clusters additionally keep spacing marks with the character before them and
+    /// prepended characters with the character after them, so in practice every offset
+    /// returned is a legacy boundary inside an extended cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// // Devanagari "ki": KA followed by the spacing vowel sign I.
+    /// let s = "a\u{915}\u{93f}b";
+    /// let diff = s.grapheme_mode_diff().collect::<Vec<usize>>();
+    ///
+    /// assert_eq!(diff, [4]);
+    /// # }
+    /// ```
+    fn grapheme_mode_diff(&self) -> GraphemeModeDiff<'_>;
+
+    /// Returns an iterator over the byte offsets of the
+    /// [grapheme cluster boundaries](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+    /// of `self` strictly inside `range`, that is, greater than `range.start` and less than
+    /// `range.end`.
+    ///
+    /// Boundaries are the same as those of the whole string: text before `range.start`, such
+    /// as the start of a run of regional indicators, is taken into account. Besides `range`
+    /// itself, the rest of the cluster containing `range.end` is scanned, and the text before
+    /// `range.start` as far back as the rules need. That is usually a single character, but a
+    /// run of regional indicators, or of marks and joiners after an emoji or a consonant, is
+    /// followed back to its start, which in the worst case is the start of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ends of `range` are out of order or not on `char` boundaries of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// // Three flags: each is two regional indicators of 4 bytes.
+    /// let s = "\u{1f1e6}\u{1f1e8}\u{1f1e6}\u{1f1e9}\u{1f1e6}\u{1f1ea}";
+    /// let gb1 = s.grapheme_boundaries_in(4..s.len(), true).collect::<Vec<usize>>();
+    /// let b: &[_] = &[8, 16];
+    ///
+    /// assert_eq!(&gb1[..], b);
+    /// # }
+    /// ```
+    fn grapheme_boundaries_in(
+        &self,
+        range: Range<usize>,
+        is_extended: bool,
+    ) -> GraphemeBoundariesIn<'_>;
+
+    /// Returns an iterator over the grapheme clusters of `self`, each paired with the number
+    /// of combining marks following its first codepoint. See `graphemes()` for more
+    /// information.
+    ///
+    /// Marks are the codepoints with the `Extend` or `SpacingMark`
+    /// [Grapheme_Cluster_Break](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values)
+    /// property. Besides combining diacritics this includes variation selectors, emoji
+    /// modifiers and tag characters, but not zero width joiners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let gr1 = "o\u{323}\u{300}\u{307}a\u{301}b".graphemes_with_mark_counts(true)
+    ///     .collect::<Vec<(&str, usize)>>();
+    /// let b: &[_] = &[("o\u{323}\u{300}\u{307}", 3), ("a\u{301}", 1), ("b", 0)];
+    ///
+    /// assert_eq!(&gr1[..], b);
+    /// # }
+    /// ```
+    fn graphemes_with_mark_counts(&self, is_extended: bool) -> GraphemeMarkCounts<'_>;
+
+    /// Calls `f` with the byte offset of each
+    /// [grapheme cluster boundary](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+    /// in `self`, in order, stopping early if `f` returns `ControlFlow::Break`.
+    ///
+    /// For a non-empty string `f` sees `0`, every boundary between two clusters, and finally
+    /// `self.len()`; it is never called for the empty string. The return value is
+    /// `ControlFlow::Break(())` if `f` stopped the visit.
+    ///
+    /// This is cheaper than `grapheme_indices()` when only the offsets are needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let mut offsets = Vec::new();
+    /// let flow = "a\r\nb\u{301}c".for_each_grapheme_boundary(true, |offset| {
+    ///     offsets.push(offset);
+    ///     if offset >= 3 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// });
+    ///
+    /// assert_eq!(offsets, [0, 1, 3]);
+    /// assert_eq!(flow, ControlFlow::Break(()));
+    /// # }
+    /// ```
+    fn for_each_grapheme_boundary(
+        &self,
+        is_extended: bool,
+        f: impl FnMut(usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()>;
+
+    /// Returns the byte offset of the first word in `self`, as selected by
+    /// [`unicode_words`](UnicodeSegmentation::unicode_words), or `None` if
+    /// `self` contains no words.
+    ///
+    /// Leading whitespace, punctuation and emoji are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// assert_eq!("  (\"hello\") world".first_word_offset(), Some(4));
+    /// assert_eq!(" \u{1F44D} ok".first_word_offset(), Some(6));
+    /// assert_eq!(" ... ".first_word_offset(), None);
+    /// # }
+    /// ```
+    fn first_word_offset(&self) -> Option<usize>;
+
+    /// Returns an iterator over the substrings of `self` split on UAX#29 word boundaries, as
+    /// the kind of each substring and its byte range. See `split_word_bounds()` for more
+    /// information.
+    ///
+    /// Substrings containing a letter are words and those containing only digits are numbers,
+    /// like for `unicode_words()`. The others are whitespace, punctuation or symbols,
+    /// according to their first character. See [`WordBoundKind`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{UnicodeSegmentationExt, WordBoundKind};
+    /// let tokens = "x = 3.5 + y_2;".lex_words().collect::<Vec<_>>();
+    /// let b: &[_] = &[
+    ///     (WordBoundKind::Word, 0..1), (WordBoundKind::Whitespace, 1..2),
+    ///     (WordBoundKind::Symbol, 2..3), (WordBoundKind::Whitespace, 3..4),
+    ///     (WordBoundKind::Number, 4..7), (WordBoundKind::Whitespace, 7..8),
+    ///     (WordBoundKind::Symbol, 8..9), (WordBoundKind::Whitespace, 9..10),
+    ///     (WordBoundKind::Word, 10..13), (WordBoundKind::Punctuation, 13..14),
+    /// ];
+    ///
+    /// assert_eq!(&tokens[..], b);
+    /// # }
+    /// ```
+    fn lex_words(&self) -> LexWords<'_>;
+
+    /// Returns an iterator over substrings of `self` separated on
+    /// [UAX#29 word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries), as
+    /// tailored by `options`. See [`WordOptions`] for the available tailorings.
+    ///
+    /// The concatenation of the substrings returned by this function is just the original string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let options = WordOptions::new().join_zwnj(true);
+    /// let swu1 = "\u{30c6}\u{200c}\u{30ec}\u{30d3}!".split_word_bounds_with(options).collect::<Vec<&str>>();
+    /// let b: &[_] = &["\u{30c6}\u{200c}\u{30ec}\u{30d3}", "!"];
+    ///
+    /// assert_eq!(&swu1[..], b);
+    /// # }
+    /// ```
+    fn split_word_bounds_with(&self, options: WordOptions) -> UWordBounds<'_>;
+
+    /// Returns an iterator over the words of `self`, separated on
+    /// [UAX#29 word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries) as
+    /// tailored by `options`. See `unicode_words()` for which substrings count as words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let options = WordOptions::new().join_zwnj(true);
+    /// let uw1 = "\u{3072}\u{200c}\u{3089}, \u{6a9}\u{62a}\u{627}\u{628}\u{200c}\u{647}\u{627}"
+    ///     .unicode_words_with(options)
+    ///     .collect::<Vec<&str>>();
+    /// let b: &[_] = &["\u{3072}\u{200c}\u{3089}", "\u{6a9}\u{62a}\u{627}\u{628}\u{200c}\u{647}\u{627}"];
+    ///
+    /// assert_eq!(&uw1[..], b);
+    /// # }
+    /// ```
+    fn unicode_words_with(&self, options: WordOptions) -> UnicodeWords<'_>;
+
+    /// Calls `f` with the byte offset of each
+    /// [UAX#29 word boundary](http://www.unicode.org/reports/tr29/#Word_Boundaries) in `self`,
+    /// in order, stopping early if `f` returns `ControlFlow::Break`. See
+    /// `for_each_grapheme_boundary()` for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let mut offsets = Vec::new();
+    /// let flow = "Hello, world".for_each_word_boundary(|offset| {
+    ///     offsets.push(offset);
+    ///     ControlFlow::Continue(())
+    /// });
+    ///
+    /// assert_eq!(offsets, [0, 5, 6, 7, 12]);
+    /// assert_eq!(flow, ControlFlow::Continue(()));
+    /// # }
+    /// ```
+    fn for_each_word_boundary(&self, f: impl FnMut(usize) -> ControlFlow<()>) -> ControlFlow<()>;
+
+    /// Returns an iterator over the words of `self` whose letters come from more than one
+    /// script, and their offsets. See `unicode_word_indices()` for which substrings count as
+    /// words.
+    ///
+    /// Letters shared between scripts, such as `µ`, are ignored, and Han mixed with the
+    /// scripts it is normally written with in Japanese, Korean or Chinese text doesn't count
+    /// as mixed. This is useful for spotting homograph attacks in user names and URLs.
+    ///
+    /// Only the most widely used scripts are told apart; letters of any other script are
+    /// treated as coming from one other script.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// // The first "a" in "paypal" is U+0430 CYRILLIC SMALL LETTER A.
+    /// let msw = "Log in to p\u{430}ypal or \u{65e5}\u{672c}\u{306e}paypal now";
+    /// let msw1 = msw.mixed_script_words().collect::<Vec<(usize, &str)>>();
+    /// let b: &[_] = &[(10, "p\u{430}ypal")];
+    ///
+    /// assert_eq!(&msw1[..], b);
+    /// # }
+    /// ```
+    fn mixed_script_words(&self) -> MixedScriptWords<'_>;
+
+    /// Returns an iterator over substrings of `self` separated on
+    /// [UAX#29 sentence boundaries](http://www.unicode.org/reports/tr29/#Sentence_Boundaries),
+    /// as tailored by `options`. See [`SentenceOptions`] for the available tailorings.
+    ///
+    /// The concatenation of the substrings returned by this function is just the original string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{SentenceOptions, UnicodeSegmentationExt};
+    /// let ssbs = "\u{201c}Why not?\u{201d} she said. \u{201c}Fine.\u{201d}";
+    /// let options = SentenceOptions::new().quote_aware(true);
+    /// let ssb1 = ssbs.split_sentence_bounds_with(options).collect::<Vec<&str>>();
+    /// let b: &[_] = &["\u{201c}Why not?\u{201d} she said. ", "\u{201c}Fine.\u{201d}"];
+    ///
+    /// assert_eq!(&ssb1[..], b);
+    /// # }
+    /// ```
+    fn split_sentence_bounds_with(&self, options: SentenceOptions) -> USentenceBounds<'_>;
+
+    /// Returns an iterator over the sentences of `self`, separated on
+    /// [UAX#29 sentence boundaries](http://www.unicode.org/reports/tr29/#Sentence_Boundaries) as
+    /// tailored by `options`. See `unicode_sentences()` for which substrings count as sentences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{SentenceOptions, UnicodeSegmentationExt};
+    /// let uss = "\"Really?\" she asked. [...] Yes.";
+    /// let options = SentenceOptions::new().quote_aware(true);
+    /// let us1 = uss.unicode_sentences_with(options).collect::<Vec<&str>>();
+    /// let b: &[_] = &["\"Really?\" she asked. ", "Yes."];
+    ///
+    /// assert_eq!(&us1[..], b);
+    /// # }
+    /// ```
+    fn unicode_sentences_with(&self, options: SentenceOptions) -> UnicodeSentences<'_>;
+
+    /// Returns an iterator over the sentences of `self` with trailing whitespace removed, each
+    /// with the byte offsets of its start and of the end of the trimmed text. See
+    /// `split_sentence_bounds()` for more information.
+    ///
+    /// Whitespace is anything with the
+    /// [White_Space](http://unicode.org/reports/tr44/#White_Space) property, which includes
+    /// line and paragraph separators. Sentences consisting only of whitespace are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let ts = "Mr. Fox jumped.\n\nThe dog was too lazy.  ";
+    /// let ts1 = ts.trimmed_sentences().collect::<Vec<(usize, usize, &str)>>();
+    /// let b: &[_] = &[(0, 3, "Mr."), (4, 15, "Fox jumped."), (17, 38, "The dog was too lazy.")];
+    ///
+    /// assert_eq!(&ts1[..], b);
+    /// # }
+    /// ```
+    fn trimmed_sentences(&self) -> TrimmedSentences<'_>;
+
+    /// Returns an iterator over the byte offsets of the first letter of each sentence of
+    /// `self`. See `split_sentence_bounds()` for more information.
+    ///
+    /// Leading whitespace, quotation marks, brackets, numbers and other symbols are skipped; a
+    /// letter is anything with the [Alphabetic](http://unicode.org/reports/tr44/#Alphabetic)
+    /// property. For a sentence with no letters at all, such as "42.", the offset of the
+    /// sentence itself is given, so there is one offset for every sentence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let ss = "hello! \"why?\" 2 more! ok";
+    /// let starts = ss.sentence_starts().collect::<Vec<usize>>();
+    /// let b: &[_] = &[0, 8, 16, 22];
+    ///
+    /// assert_eq!(&starts[..], b);
+    /// assert!(starts.iter().all(|&i| ss[i..].starts_with(char::is_lowercase)));
+    /// # }
+    /// ```
+    fn sentence_starts(&self) -> SentenceStarts<'_>;
+
+    /// Returns an iterator over substrings of `self` separated on UAX#29 sentence boundaries,
+    /// each with its index, counting from 0, and its byte offset. See
+    /// `split_sentence_bound_indices()` for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let es = "Mr. Fox jumped. The dog was too lazy.".enumerate_sentences().collect::<Vec<_>>();
+    /// let b: &[_] = &[(0, 0, "Mr. "), (1, 4, "Fox jumped. "), (2, 16, "The dog was too lazy.")];
+    ///
+    /// assert_eq!(&es[..], b);
+    /// # }
+    /// ```
+    fn enumerate_sentences(&self) -> EnumerateSentences<'_>;
+
+    /// Returns an iterator over the words of `self`, each paired with `true` if it is the
+    /// first word of its sentence. See `unicode_words()` and `split_sentence_bounds()` for
+    /// more information.
+    ///
+    /// Words are found within each sentence, so a sentence with no words, such as one made up
+    /// only of punctuation, contributes nothing. Abbreviations followed by a capitalized word
+    /// end a sentence under UAX#29, so the word after "Mr. " is flagged as a sentence start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "It works. \"Does it?\" yes, e.g. here";
+    /// let words = s.words_with_sentence_start().collect::<Vec<_>>();
+    /// let b: &[_] = &[
+    ///     ("It", true), ("works", false), ("Does", true), ("it", false),
+    ///     ("yes", true), ("e.g", false), ("here", false),
+    /// ];
+    ///
+    /// assert_eq!(&words[..], b);
+    /// # }
+    /// ```
+    fn words_with_sentence_start(&self) -> WordsWithSentenceStart<'_>;
+
+    /// Returns an iterator over substrings of `self` separated on UAX#29 sentence boundaries,
+    /// each with its base direction. See `split_sentence_bounds()` for more information.
+    ///
+    /// The direction is that of the first strong directional character of the sentence, as
+    /// the [Unicode Bidirectional Algorithm](http://www.unicode.org/reports/tr9/) chooses the
+    /// direction of a paragraph: right-to-left for Hebrew, Arabic and other right-to-left
+    /// scripts, left-to-right for letters of any other script, and [`Direction::Neutral`] if
+    /// the sentence has no letters. This is a heuristic for laying out mixed text, not a full
+    /// implementation of the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::{Direction, UnicodeSegmentationExt};
+    /// let s = "Hello. \u{5e9}\u{5dc}\u{5d5}\u{5dd}. 42!";
+    /// let sentences = s.sentences_with_direction().collect::<Vec<_>>();
+    /// let b: &[_] = &[
+    ///     ("Hello. ", Direction::Ltr),
+    ///     ("\u{5e9}\u{5dc}\u{5d5}\u{5dd}. ", Direction::Rtl),
+    ///     ("42!", Direction::Neutral),
+    /// ];
+    ///
+    /// assert_eq!(&sentences[..], b);
+    /// # }
+    /// ```
+    fn sentences_with_direction(&self) -> SentencesWithDirection<'_>;
+
+    /// Returns an iterator over the lines of `self`, each paired with the exact terminator
+    /// that ends it.
+    ///
+    /// Lines are terminated by a line feed, a carriage return, both together (`"\r\n"`), a
+    /// next line character (U+0085), a line separator (U+2028) or a paragraph separator
+    /// (U+2029). The terminator of a last line that doesn't end in one of these is `""`, and no
+    /// empty line is produced after a final terminator, so concatenating every line with its
+    /// terminator gives back `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// let s = "one\r\ntwo\u{2028}\rfour";
+    /// let lines = s.lines_with_terminators().collect::<Vec<_>>();
+    /// let b: &[_] = &[("one", "\r\n"), ("two", "\u{2028}"), ("", "\r"), ("four", "")];
+    ///
+    /// assert_eq!(&lines[..], b);
+    /// # }
+    /// ```
+    fn lines_with_terminators(&self) -> LinesWithTerminators<'_>;
+
+    /// Calls `f` with the byte offset of each
+    /// [UAX#29 sentence boundary](http://www.unicode.org/reports/tr29/#Sentence_Boundaries) in
+    /// `self`, in order, stopping early if `f` returns `ControlFlow::Break`. See
+    /// `for_each_grapheme_boundary()` for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use self::unicode_segmentation::UnicodeSegmentationExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let mut first_break = None;
+    /// "Mr. Fox jumped. [...] The dog was too lazy.".for_each_sentence_boundary(|offset| {
+    ///     if offset == 0 {
+    ///         return ControlFlow::Continue(());
+    ///     }
+    ///     first_break = Some(offset);
+    ///     ControlFlow::Break(())
+    /// });
+    ///
+    /// assert_eq!(first_break, Some(4));
+    /// # }
+    /// ```
+    fn for_each_sentence_boundary(
+        &self,
+        f: impl FnMut(usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()>;
+}
+
+mod private {
+    // Keeps `UnicodeSegmentationExt` from being implemented outside this crate, so that
+    // methods can be added to it.
+    pub trait Sealed {}
+
+    #[cfg(feature = "str-impl")]
+    impl Sealed for str {}
+}
+
+#[cfg(feature = "str-impl")]
 impl UnicodeSegmentation for str {
     #[inline]
-    fn graphemes(&self, is_extended: bool) -> Graphemes {
+    fn graphemes(&self, is_extended: bool) -> Graphemes<'_> {
         grapheme::new_graphemes(self, is_extended)
     }
 
     #[inline]
-    fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices {
+    fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_> {
         grapheme::new_grapheme_indices(self, is_extended)
     }
 
     #[inline]
-    fn unicode_words(&self) -> UnicodeWords {
+    fn unicode_words(&self) -> UnicodeWords<'_> {
         word::new_unicode_words(self)
     }
 
     #[inline]
-    fn unicode_word_indices(&self) -> UnicodeWordIndices {
+    fn unicode_word_indices(&self) -> UnicodeWordIndices<'_> {
         word::new_unicode_word_indices(self)
     }
 
     #[inline]
-    fn split_word_bounds(&self) -> UWordBounds {
+    fn split_word_bounds(&self) -> UWordBounds<'_> {
         word::new_word_bounds(self)
     }
 
     #[inline]
-    fn split_word_bound_indices(&self) -> UWordBoundIndices {
+    fn split_word_bound_indices(&self) -> UWordBoundIndices<'_> {
         word::new_word_bound_indices(self)
     }
 
     #[inline]
-    fn unicode_sentences(&self) -> UnicodeSentences {
+    fn unicode_sentences(&self) -> UnicodeSentences<'_> {
         sentence::new_unicode_sentences(self)
     }
 
     #[inline]
-    fn split_sentence_bounds(&self) -> USentenceBounds {
+    fn split_sentence_bounds(&self) -> USentenceBounds<'_> {
         sentence::new_sentence_bounds(self)
     }
 
     #[inline]
-    fn split_sentence_bound_indices(&self) -> USentenceBoundIndices {
+    fn split_sentence_bound_indices(&self) -> USentenceBoundIndices<'_> {
         sentence::new_sentence_bound_indices(self)
     }
 }
+
+#[cfg(feature = "str-impl")]
+impl UnicodeSegmentationExt for str {
+    #[inline]
+    fn grapheme_ranges(&self, is_extended: bool) -> GraphemeRanges<'_> {
+        new_grapheme_ranges(self, is_extended)
+    }
+
+    #[inline]
+    fn graphemes_with_first_char(&self, is_extended: bool) -> GraphemesWithFirstChar<'_> {
+        new_graphemes_with_first_char(self, is_extended)
+    }
+
+    #[inline]
+    fn graphemes_typed(&self, is_extended: bool) -> GraphemesTyped<'_> {
+        new_graphemes_typed(self, is_extended)
+    }
+
+    #[inline]
+    fn grapheme_indices_typed(&self, is_extended: bool) -> GraphemeIndicesTyped<'_> {
+        new_grapheme_indices_typed(self, is_extended)
+    }
+
+    #[inline]
+    fn graphemes_with_hash(&self, is_extended: bool) -> GraphemeHashes<'_> {
+        new_grapheme_hashes(self, is_extended)
+    }
+
+    #[inline]
+    fn graphemes_coalesce<F: Fn(&str, &str) -> bool>(
+        &self,
+        is_extended: bool,
+        should_merge: F,
+    ) -> GraphemesCoalesce<'_, F> {
+        new_graphemes_coalesce(self, is_extended, should_merge)
+    }
+
+    #[inline]
+    fn graphemes_with<O: Into<GraphemeOptions>>(&self, options: O) -> Graphemes<'_> {
+        new_graphemes_with(self, options.into())
+    }
+
+    #[inline]
+    fn grapheme_state_at(&self, offset: usize, is_extended: bool) -> GraphemeResumeToken {
+        new_grapheme_resume_token(self, offset, is_extended)
+    }
+
+    #[inline]
+    fn resume_graphemes(&self, token: GraphemeResumeToken) -> Graphemes<'_> {
+        resume_graphemes(self, token)
+    }
+
+    #[inline]
+    fn grapheme_count_to(&self, offset: usize, is_extended: bool) -> usize {
+        grapheme_count_to(self, offset, is_extended)
+    }
+
+    #[inline]
+    fn is_single_grapheme(&self, is_extended: bool) -> bool {
+        is_single_grapheme(self, is_extended)
+    }
+
+    #[inline]
+    fn char_segments(&self) -> CharSegments<'_> {
+        new_char_segments(self)
+    }
+
+    #[inline]
+    fn grapheme_len_at(&self, offset: usize, is_extended: bool) -> Option<usize> {
+        grapheme_len_at(self, offset, is_extended)
+    }
+
+    #[inline]
+    fn last_grapheme_boundary(&self, is_extended: bool) -> usize {
+        last_grapheme_boundary(self, is_extended)
+    }
+
+    #[inline]
+    fn snap_to_grapheme_boundary(&self, offset: usize, is_extended: bool) -> usize {
+        snap_to_grapheme_boundary(self, offset, is_extended)
+    }
+
+    #[inline]
+    fn in_emoji_sequence(&self, offset: usize) -> bool {
+        in_emoji_sequence(self, offset)
+    }
+
+    #[inline]
+    fn display_unit_count(&self) -> usize {
+        display_unit_count(self)
+    }
+
+    #[inline]
+    fn grapheme_windows(&self, max_width: usize, is_extended: bool) -> GraphemeWindows<'_> {
+        new_grapheme_windows(self, max_width, is_extended)
+    }
+
+    #[inline]
+    fn grapheme_chunks(&self, clusters_per_chunk: usize, is_extended: bool) -> GraphemeChunks<'_> {
+        new_grapheme_chunks(self, clusters_per_chunk, is_extended)
+    }
+
+    #[inline]
+    fn grapheme_boundary_indices(&self, is_extended: bool) -> GraphemeBoundaryIndices<'_> {
+        new_grapheme_boundary_indices(self, is_extended)
+    }
+
+    #[inline]
+    fn grapheme_mode_diff(&self) -> GraphemeModeDiff<'_> {
+        new_grapheme_mode_diff(self)
+    }
+
+    #[inline]
+    fn grapheme_boundaries_in(
+        &self,
+        range: Range<usize>,
+        is_extended: bool,
+    ) -> GraphemeBoundariesIn<'_> {
+        new_grapheme_boundaries_in(self, range, is_extended)
+    }
+
+    #[inline]
+    fn graphemes_with_mark_counts(&self, is_extended: bool) -> GraphemeMarkCounts<'_> {
+        new_grapheme_mark_counts(self, is_extended)
+    }
+
+    #[inline]
+    fn for_each_grapheme_boundary(
+        &self,
+        is_extended: bool,
+        f: impl FnMut(usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        for_each_grapheme_boundary(self, is_extended, f)
+    }
+
+    #[inline]
+    fn first_word_offset(&self) -> Option<usize> {
+        first_word_offset(self)
+    }
+
+    #[inline]
+    fn lex_words(&self) -> LexWords<'_> {
+        new_lex_words(self)
+    }
+
+    #[inline]
+    fn split_word_bounds_with(&self, options: WordOptions) -> UWordBounds<'_> {
+        new_word_bounds_with(self, options)
+    }
+
+    #[inline]
+    fn unicode_words_with(&self, options: WordOptions) -> UnicodeWords<'_> {
+        new_unicode_words_with(self, options)
+    }
+
+    #[inline]
+    fn for_each_word_boundary(&self, f: impl FnMut(usize) -> ControlFlow<()>) -> ControlFlow<()> {
+        for_each_word_boundary(self, f)
+    }
+
+    #[inline]
+    fn mixed_script_words(&self) -> MixedScriptWords<'_> {
+        new_mixed_script_words(self)
+    }
+
+    #[inline]
+    fn split_sentence_bounds_with(&self, options: SentenceOptions) -> USentenceBounds<'_> {
+        new_sentence_bounds_with(self, options)
+    }
+
+    #[inline]
+    fn unicode_sentences_with(&self, options: SentenceOptions) -> UnicodeSentences<'_> {
+        new_unicode_sentences_with(self, options)
+    }
+
+    #[inline]
+    fn trimmed_sentences(&self) -> TrimmedSentences<'_> {
+        new_trimmed_sentences(self)
+    }
+
+    #[inline]
+    fn sentence_starts(&self) -> SentenceStarts<'_> {
+        new_sentence_starts(self)
+    }
+
+    #[inline]
+    fn enumerate_sentences(&self) -> EnumerateSentences<'_> {
+        new_enumerate_sentences(self)
+    }
+
+    #[inline]
+    fn words_with_sentence_start(&self) -> WordsWithSentenceStart<'_> {
+        new_words_with_sentence_start(self)
+    }
+
+    #[inline]
+    fn sentences_with_direction(&self) -> SentencesWithDirection<'_> {
+        new_sentences_with_direction(self)
+    }
+
+    #[inline]
+    fn lines_with_terminators(&self) -> LinesWithTerminators<'_> {
+        new_lines_with_terminators(self)
+    }
+
+    #[inline]
+    fn for_each_sentence_boundary(
+        &self,
+        f: impl FnMut(usize) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        for_each_sentence_boundary(self, f)
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp;
+
+/// External iterator for the lines of a string, each paired with the terminator that ends it.
+///
+/// This struct is created by the [`lines_with_terminators`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`lines_with_terminators`]: trait.UnicodeSegmentationExt.html#tymethod.lines_with_terminators
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct LinesWithTerminators<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for LinesWithTerminators<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<(&'a str, &'a str)> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let line = self.rest;
+        let found = line
+            .char_indices()
+            .find(|&(_, c)| matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'));
+        let (end, terminator_len) = match found {
+            Some((i, '\r')) if line[i + 1..].starts_with('\n') => (i, 2),
+            Some((i, c)) => (i, c.len_utf8()),
+            None => (line.len(), 0),
+        };
+        self.rest = &line[end + terminator_len..];
+        Some((&line[..end], &line[end..end + terminator_len]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let slen = self.rest.len();
+        (cmp::min(slen, 1), Some(slen))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for LinesWithTerminators<'a> {}
+
+/// Returns an iterator over the lines of `s`, including their terminators. See
+/// [`UnicodeSegmentationExt::lines_with_terminators`].
+///
+/// [`UnicodeSegmentationExt::lines_with_terminators`]: crate::UnicodeSegmentationExt::lines_with_terminators
+#[inline]
+pub fn new_lines_with_terminators(s: &str) -> LinesWithTerminators<'_> {
+    LinesWithTerminators { rest: s }
+}
@@ -0,0 +1,395 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::grapheme::{new_graphemes, GraphemeCursor, Graphemes};
+use crate::sentence::new_sentence_bounds;
+use crate::word::new_word_bounds;
+
+const INLINE_CAP: usize = 24;
+
+/// An owned grapheme cluster.
+///
+/// Clusters of up to 24 bytes, which covers nearly all clusters in real text, are stored inline
+/// without allocating. Longer clusters are stored in a `String`.
+///
+/// `SmallCluster` dereferences to `str`, and compares and hashes like the string it contains.
+#[derive(Clone)]
+pub struct SmallCluster {
+    repr: Repr,
+}
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAP] },
+    Heap(String),
+}
+
+impl SmallCluster {
+    /// Copy `s` into a new `SmallCluster`, allocating only if it is longer than 24 bytes.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::SmallCluster;
+    /// let c = SmallCluster::new("e\u{301}");
+    /// assert_eq!(c.as_str(), "e\u{301}");
+    /// assert!(c.is_inline());
+    /// ```
+    pub fn new(s: &str) -> SmallCluster {
+        let repr = if s.len() <= INLINE_CAP {
+            let mut buf = [0; INLINE_CAP];
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            Repr::Inline {
+                len: s.len() as u8,
+                buf,
+            }
+        } else {
+            Repr::Heap(String::from(s))
+        };
+        SmallCluster { repr }
+    }
+
+    /// View the cluster as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.repr {
+            Repr::Inline { len, ref buf } => core::str::from_utf8(&buf[..len as usize])
+                .expect("inline clusters are copied from a str and never modified"),
+            Repr::Heap(ref s) => s,
+        }
+    }
+
+    /// Whether the cluster is stored inline rather than on the heap.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+}
+
+impl Deref for SmallCluster {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallCluster {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SmallCluster {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a str> for SmallCluster {
+    #[inline]
+    fn from(s: &'a str) -> SmallCluster {
+        SmallCluster::new(s)
+    }
+}
+
+impl From<SmallCluster> for String {
+    #[inline]
+    fn from(c: SmallCluster) -> String {
+        match c.repr {
+            Repr::Inline { .. } => String::from(c.as_str()),
+            Repr::Heap(s) => s,
+        }
+    }
+}
+
+impl PartialEq for SmallCluster {
+    #[inline]
+    fn eq(&self, other: &SmallCluster) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmallCluster {}
+
+impl PartialEq<str> for SmallCluster {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for SmallCluster {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for SmallCluster {
+    #[inline]
+    fn partial_cmp(&self, other: &SmallCluster) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SmallCluster {
+    #[inline]
+    fn cmp(&self, other: &SmallCluster) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for SmallCluster {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for SmallCluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SmallCluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// External iterator for a string's
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries),
+/// yielding owned [`SmallCluster`]s.
+///
+/// This struct is created by the [`new_owned_graphemes`] function. See its documentation for
+/// more.
+///
+/// [`new_owned_graphemes`]: fn.new_owned_graphemes.html
+#[derive(Clone, Debug)]
+pub struct OwnedGraphemes<'a> {
+    inner: Graphemes<'a>,
+}
+
+impl<'a> Iterator for OwnedGraphemes<'a> {
+    type Item = SmallCluster;
+
+    #[inline]
+    fn next(&mut self) -> Option<SmallCluster> {
+        self.inner.next().map(SmallCluster::new)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for OwnedGraphemes<'a> {}
+
+impl<'a> DoubleEndedIterator for OwnedGraphemes<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<SmallCluster> {
+        self.inner.next_back().map(SmallCluster::new)
+    }
+}
+
+/// Returns an iterator over the grapheme clusters of `s` as owned [`SmallCluster`]s, which do
+/// not borrow `s`. Clusters of up to 24 bytes are stored without allocating. See
+/// [`UnicodeSegmentation::graphemes`] for more information.
+///
+/// Requires the `alloc` feature, which is enabled by default.
+///
+/// [`UnicodeSegmentation::graphemes`]: crate::UnicodeSegmentation::graphemes
+///
+/// # Example
+///
+/// ```
+/// # use unicode_segmentation::new_owned_graphemes;
+/// let owned = {
+///     let s = String::from("a\u{310}e\u{301}");
+///     new_owned_graphemes(&s, true).collect::<Vec<_>>()
+/// };
+///
+/// assert_eq!(owned, ["a\u{310}", "e\u{301}"]);
+/// assert!(owned.iter().all(|c| c.is_inline()));
+/// ```
+#[inline]
+pub fn new_owned_graphemes(s: &str, is_extended: bool) -> OwnedGraphemes<'_> {
+    OwnedGraphemes {
+        inner: new_graphemes(s, is_extended),
+    }
+}
+
+/// A cursor over the
+/// [grapheme clusters](http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries) of
+/// a `Cow<str>` that it owns.
+///
+/// Since the text may be owned, the clusters can only borrow from the `GraphemesCow` itself,
+/// so they are returned by [`next_cluster`](GraphemesCow::next_cluster) rather than by an
+/// `Iterator` implementation, and each lives until the next call.
+///
+/// This struct is created by the [`graphemes_cow`] function. See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct GraphemesCow<'a> {
+    text: Cow<'a, str>,
+    cursor: GraphemeCursor,
+}
+
+impl<'a> GraphemesCow<'a> {
+    /// Returns the next cluster, or `None` once the end of the text is reached.
+    #[inline]
+    pub fn next_cluster(&mut self) -> Option<&str> {
+        let start = self.cursor.cur_cursor();
+        // the cursor is given the whole text, so it never asks for more
+        let end = self.cursor.next_boundary(&self.text, 0).unwrap()?;
+        Some(&self.text[start..end])
+    }
+
+    /// View the part of the text yet to be iterated.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::graphemes_cow;
+    /// let mut iter = graphemes_cow("a\u{310}e\u{301}o", true);
+    /// assert_eq!(iter.as_str(), "a\u{310}e\u{301}o");
+    /// iter.next_cluster();
+    /// assert_eq!(iter.as_str(), "e\u{301}o");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.text[self.cursor.cur_cursor()..]
+    }
+
+    /// Returns the whole text back, whether or not it has all been iterated.
+    #[inline]
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.text
+    }
+}
+
+/// Returns a [`GraphemesCow`] over the grapheme clusters of `text`, which may be owned. See
+/// [`UnicodeSegmentation::graphemes`] for the meaning of `is_extended`.
+///
+/// Borrowed text can be iterated with `graphemes` just as well. This is for when the text has
+/// to move along with the iterator, for example when returning both from a function.
+///
+/// [`UnicodeSegmentation::graphemes`]: trait.UnicodeSegmentation.html#tymethod.graphemes
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use unicode_segmentation::{graphemes_cow, GraphemesCow};
+/// fn normalized(s: &str) -> GraphemesCow<'_> {
+///     let text = if s.contains('\t') {
+///         Cow::Owned(s.replace('\t', " "))
+///     } else {
+///         Cow::Borrowed(s)
+///     };
+///     graphemes_cow(text, true)
+/// }
+///
+/// let mut iter = normalized("e\u{301}\t\u{1f1f7}\u{1f1fa}");
+/// let mut clusters = Vec::new();
+/// while let Some(cluster) = iter.next_cluster() {
+///     clusters.push(cluster.to_string());
+/// }
+/// assert_eq!(clusters, ["e\u{301}", " ", "\u{1f1f7}\u{1f1fa}"]);
+/// ```
+#[inline]
+pub fn graphemes_cow<'a, T: Into<Cow<'a, str>>>(text: T, is_extended: bool) -> GraphemesCow<'a> {
+    let text = text.into();
+    let cursor = GraphemeCursor::new(0, text.len(), is_extended);
+    GraphemesCow { text, cursor }
+}
+
+/// Clears `out` and fills it with the grapheme clusters of `s`. See
+/// [`UnicodeSegmentation::graphemes`] for more information.
+///
+/// This keeps the capacity of `out`, so a buffer can be reused to segment many strings without
+/// allocating for each.
+///
+/// Requires the `alloc` feature, which is enabled by default.
+///
+/// [`UnicodeSegmentation::graphemes`]: crate::UnicodeSegmentation::graphemes
+///
+/// # Example
+///
+/// ```
+/// # use unicode_segmentation::collect_graphemes_into;
+/// let mut out = Vec::new();
+/// collect_graphemes_into("a\u{310}e\u{301}", &mut out, true);
+/// assert_eq!(out, ["a\u{310}", "e\u{301}"]);
+///
+/// collect_graphemes_into("xyz", &mut out, true);
+/// assert_eq!(out, ["x", "y", "z"]);
+/// ```
+#[inline]
+pub fn collect_graphemes_into<'a>(s: &'a str, out: &mut Vec<&'a str>, is_extended: bool) {
+    out.clear();
+    out.extend(new_graphemes(s, is_extended));
+}
+
+/// Clears `out` and fills it with the substrings of `s` split on UAX#29 word boundaries. See
+/// [`UnicodeSegmentation::split_word_bounds`] for more information.
+///
+/// This keeps the capacity of `out`, so a buffer can be reused to segment many strings without
+/// allocating for each.
+///
+/// Requires the `alloc` feature, which is enabled by default.
+///
+/// [`UnicodeSegmentation::split_word_bounds`]: crate::UnicodeSegmentation::split_word_bounds
+///
+/// # Example
+///
+/// ```
+/// # use unicode_segmentation::collect_word_bounds_into;
+/// let mut out = Vec::new();
+/// collect_word_bounds_into("The quick (\"brown\")", &mut out);
+/// assert_eq!(out, ["The", " ", "quick", " ", "(", "\"", "brown", "\"", ")"]);
+/// ```
+#[inline]
+pub fn collect_word_bounds_into<'a>(s: &'a str, out: &mut Vec<&'a str>) {
+    out.clear();
+    out.extend(new_word_bounds(s));
+}
+
+/// Clears `out` and fills it with the substrings of `s` split on UAX#29 sentence boundaries.
+/// See [`UnicodeSegmentation::split_sentence_bounds`] for more information.
+///
+/// This keeps the capacity of `out`, so a buffer can be reused to segment many strings without
+/// allocating for each.
+///
+/// Requires the `alloc` feature, which is enabled by default.
+///
+/// [`UnicodeSegmentation::split_sentence_bounds`]: crate::UnicodeSegmentation::split_sentence_bounds
+///
+/// # Example
+///
+/// ```
+/// # use unicode_segmentation::collect_sentence_bounds_into;
+/// let mut out = Vec::new();
+/// collect_sentence_bounds_into("Mr. Fox jumped. The dog was too lazy.", &mut out);
+/// assert_eq!(out, ["Mr. ", "Fox jumped. ", "The dog was too lazy."]);
+/// ```
+#[inline]
+pub fn collect_sentence_bounds_into<'a>(s: &'a str, out: &mut Vec<&'a str>) {
+    out.clear();
+    out.extend(new_sentence_bounds(s));
+}
@@ -0,0 +1,71 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A coarse version of the Unicode Script property, for detecting words that mix scripts.
+//
+// The table is generated by `scripts/unicode.py` and is only meant to be queried for
+// `Alphabetic` characters, since ranges may cover unrelated non-alphabetic characters. It only
+// distinguishes the scripts listed in `Script`: alphabetic characters with the Common or
+// Inherited script map to `Common`, and those of any other script to `Other`.
+
+use crate::tables::script::{script, Bopomofo, Common, Han, Hangul, Hiragana, Katakana, Script};
+
+// Han is written together with kana in Japanese, with Hangul in Korean and with Bopomofo in
+// Chinese, so these combinations are not considered mixed (see UTS #39, section 5.1).
+const HAN_WITH: &[&[Script]] = &[&[Hiragana, Katakana], &[Hangul], &[Bopomofo]];
+
+/// Whether the alphabetic characters of `s` belong to more than one script, ignoring
+/// characters used with many scripts.
+pub fn is_mixed_script(s: &str) -> bool {
+    use crate::tables::derived_property::Alphabetic;
+
+    let mut seen: u64 = 0;
+    for c in s.chars().filter(|&c| Alphabetic(c)) {
+        match script(c) {
+            Common => {}
+            sc => seen |= 1 << sc as u64,
+        }
+    }
+    if seen.count_ones() <= 1 {
+        return false;
+    }
+    let han = 1 << Han as u64;
+    !HAN_WITH.iter().any(|others| {
+        let allowed = others.iter().fold(han, |set, &sc| set | 1 << sc as u64);
+        seen & !allowed == 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_mixed_script;
+
+    #[test]
+    fn test_mixed_script() {
+        // U+0430 CYRILLIC SMALL LETTER A, U+03BF GREEK SMALL LETTER OMICRON
+        assert!(is_mixed_script("p\u{430}ypal"));
+        assert!(is_mixed_script("g\u{3bf}\u{3bf}gle"));
+        assert!(is_mixed_script("\u{65e5}\u{672c}\u{d55c}\u{30ab}"));
+        assert!(is_mixed_script("\u{5d0}a"));
+
+        assert!(!is_mixed_script("paypal"));
+        assert!(!is_mixed_script("Stra\u{df}e"));
+        assert!(!is_mixed_script(
+            "\u{43f}\u{440}\u{438}\u{432}\u{435}\u{442}"
+        ));
+        assert!(!is_mixed_script("10\u{b5}m"));
+        assert!(!is_mixed_script("e\u{301}t\u{e9}"));
+        assert!(!is_mixed_script(
+            "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30c6}\u{30ad}\u{30b9}\u{30c8}\u{30fc}"
+        ));
+        assert!(!is_mixed_script("\u{d55c}\u{5b57}"));
+        assert!(!is_mixed_script(""));
+    }
+}
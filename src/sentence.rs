@@ -8,8 +8,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use core::cmp;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::iter::Filter;
+use core::ops::ControlFlow;
+
+use crate::tables::sentence::SentenceCat;
+use crate::word::{new_unicode_words, UnicodeWords};
 
 // All of the logic for forward iteration over sentences
 mod fwd {
@@ -33,8 +38,24 @@ mod fwd {
         STerm,
     }
 
+    impl StatePart {
+        fn of(cat: SentenceCat) -> StatePart {
+            match cat {
+                SentenceCat::SC_CR => StatePart::CR,
+                SentenceCat::SC_LF => StatePart::LF,
+                SentenceCat::SC_Sep => StatePart::Sep,
+                SentenceCat::SC_ATerm => StatePart::ATerm,
+                SentenceCat::SC_Upper | SentenceCat::SC_Lower => StatePart::UpperLower,
+                SentenceCat::SC_Close => StatePart::ClosePlus,
+                SentenceCat::SC_Sp => StatePart::SpPlus,
+                SentenceCat::SC_STerm => StatePart::STerm,
+                _ => StatePart::Other,
+            }
+        }
+    }
+
     #[derive(Debug, Clone, PartialEq, Eq)]
-    struct SentenceBreaksState(pub [StatePart; 4]);
+    pub struct SentenceBreaksState([StatePart; 4]);
 
     const INITIAL_STATE: SentenceBreaksState = SentenceBreaksState([
         StatePart::Sot,
@@ -48,6 +69,56 @@ mod fwd {
         pub string: &'a str,
         pos: usize,
         state: SentenceBreaksState,
+        pub overrides: Overrides,
+    }
+
+    // Properties given by `SentenceOptions::property_override`
+    pub type Overrides = Option<Override>;
+
+    // Compared by address, so that `SentenceOptions` can stay `Eq` and `Hash`
+    #[derive(Clone, Copy, Debug)]
+    pub struct Override(pub fn(char) -> Option<super::SentenceBreakProperty>);
+
+    impl PartialEq for Override {
+        fn eq(&self, other: &Override) -> bool {
+            self.0 as usize == other.0 as usize
+        }
+    }
+
+    impl Eq for Override {}
+
+    impl core::hash::Hash for Override {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            (self.0 as usize).hash(state)
+        }
+    }
+
+    // The category of `c`, as changed by `overrides`. Paragraph separators are left alone, so
+    // that paragraphs start in the same places as with the UAX#29 rules.
+    #[inline]
+    pub fn category(c: char, overrides: Overrides) -> SentenceCat {
+        use crate::tables::sentence as se;
+
+        let cat = se::sentence_category(c).2;
+        let overridden = match overrides {
+            Some(Override(f)) if !matches!(cat, se::SC_Sep | se::SC_CR | se::SC_LF) => {
+                f(c).map(Into::into)
+            }
+            _ => return cat,
+        };
+        match overridden {
+            Some(se::SC_Sep | se::SC_CR | se::SC_LF) | None => cat,
+            Some(overridden) => overridden,
+        }
+    }
+
+    // Whether there is a boundary before a character, as decided by `decide`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Decision {
+        Break,
+        Keep,
+        // A boundary unless the text from the character on reaches a `Lower` (SB8)
+        BreakUnlessLower,
     }
 
     impl SentenceBreaksState {
@@ -58,29 +129,62 @@ mod fwd {
             let parts = match (parts[3], cat) {
                 (StatePart::ClosePlus, SentenceCat::SC_Close) => parts,
                 (StatePart::SpPlus, SentenceCat::SC_Sp) => parts,
-                _ => [
-                    parts[1],
-                    parts[2],
-                    parts[3],
-                    match cat {
-                        SentenceCat::SC_CR => StatePart::CR,
-                        SentenceCat::SC_LF => StatePart::LF,
-                        SentenceCat::SC_Sep => StatePart::Sep,
-                        SentenceCat::SC_ATerm => StatePart::ATerm,
-                        SentenceCat::SC_Upper | SentenceCat::SC_Lower => StatePart::UpperLower,
-                        SentenceCat::SC_Close => StatePart::ClosePlus,
-                        SentenceCat::SC_Sp => StatePart::SpPlus,
-                        SentenceCat::SC_STerm => StatePart::STerm,
-                        _ => StatePart::Other,
-                    },
-                ],
+                _ => [parts[1], parts[2], parts[3], StatePart::of(cat)],
             };
-            SentenceBreaksState(parts)
+            let state = SentenceBreaksState(parts);
+            debug_assert!(
+                state.is_consistent(),
+                "impossible sentence state {:?}",
+                state
+            );
+            state
+        }
+
+        // Advance the state past a character, leaving it as is for the characters SB5 ignores
+        pub fn advance(&self, cat: SentenceCat) -> SentenceBreaksState {
+            match cat {
+                SentenceCat::SC_Extend | SentenceCat::SC_Format
+                    if !(self.match1(StatePart::Sot)
+                        || self.match1(StatePart::Sep)
+                        || self.match1(StatePart::CR)
+                        || self.match1(StatePart::LF)) =>
+                {
+                    self.clone()
+                }
+                _ => self.next(cat),
+            }
         }
 
         fn end(&self) -> SentenceBreaksState {
             let &SentenceBreaksState(parts) = self;
-            SentenceBreaksState([parts[1], parts[2], parts[3], StatePart::Eot])
+            let state = SentenceBreaksState([parts[1], parts[2], parts[3], StatePart::Eot]);
+            debug_assert!(
+                state.is_consistent(),
+                "impossible sentence state {:?}",
+                state
+            );
+            state
+        }
+
+        // Check for combinations of parts that no input can produce: `Sot` only
+        // as a prefix, `Eot` only as the newest part, and no repeated `ClosePlus`
+        // or `SpPlus` since runs of those collapse into a single part.
+        fn is_consistent(&self) -> bool {
+            let &SentenceBreaksState(parts) = self;
+            parts.windows(2).all(|pair| match (pair[0], pair[1]) {
+                (StatePart::Sot, _) => true,
+                (_, StatePart::Sot) => false,
+                (StatePart::Eot, _) => false,
+                (StatePart::ClosePlus, StatePart::ClosePlus) => false,
+                (StatePart::SpPlus, StatePart::SpPlus) => false,
+                _ => true,
+            })
+        }
+
+        // Whether the newest part is an ordinary character, after which nothing in the state
+        // can lead to a boundary until a terminator or paragraph separator comes
+        fn is_plain(&self) -> bool {
+            matches!(self.0[3], StatePart::Other | StatePart::UpperLower)
         }
 
         // Helper function to check if state head matches a single `StatePart`
@@ -97,35 +201,129 @@ mod fwd {
         }
     }
 
+    // Rebuilds the state at some position from the text before it, read backwards. Only the
+    // last four parts matter, so reading usually stops after a few characters.
+    #[derive(Debug, Clone)]
+    pub struct StateRebuild {
+        // The position whose state is rebuilt
+        pub at: usize,
+        // Where the text read so far starts
+        pub pos: usize,
+        // The parts found so far, newest first
+        parts: [StatePart; 4],
+        found: usize,
+        // Set while the oldest part found is preceded by `Extend` or `Format` characters
+        extended: bool,
+    }
+
+    impl StateRebuild {
+        pub fn new(at: usize) -> StateRebuild {
+            StateRebuild {
+                at,
+                pos: at,
+                parts: [StatePart::Sot; 4],
+                found: 0,
+                extended: false,
+            }
+        }
+
+        // Read the text ending at `pos` backwards, until the state is known
+        pub fn feed(&mut self, text: &str) {
+            use crate::tables::sentence as se;
+
+            for ch in text.chars().rev() {
+                if self.found == 4 {
+                    return;
+                }
+                self.pos -= ch.len_utf8();
+                let cat = se::sentence_category(ch).2;
+                if let SentenceCat::SC_Extend | SentenceCat::SC_Format = cat {
+                    self.extended = true;
+                    continue;
+                }
+                let part = StatePart::of(cat);
+                // SB5 ignores the `Extend` and `Format` characters, except right after a
+                // paragraph separator where SB4 treats the first one as a character of its own
+                if core::mem::take(&mut self.extended)
+                    && matches!(part, StatePart::Sep | StatePart::CR | StatePart::LF)
+                {
+                    self.push(StatePart::Other);
+                }
+                let collapses = self.found > 0
+                    && self.parts[self.found - 1] == part
+                    && matches!(part, StatePart::ClosePlus | StatePart::SpPlus);
+                if !collapses {
+                    self.push(part);
+                }
+            }
+        }
+
+        fn push(&mut self, part: StatePart) {
+            if self.found < 4 {
+                self.parts[self.found] = part;
+                self.found += 1;
+            }
+        }
+
+        // The rebuilt state, once enough text has been read
+        pub fn state(&self) -> Option<SentenceBreaksState> {
+            let mut rebuild = self.clone();
+            if rebuild.found < 4 {
+                if rebuild.pos > 0 {
+                    return None;
+                }
+                // Like after a paragraph separator, a leading `Extend` counts on its own
+                if rebuild.extended {
+                    rebuild.push(StatePart::Other);
+                }
+            }
+            let p = rebuild.parts;
+            let state = SentenceBreaksState([p[3], p[2], p[1], p[0]]);
+            debug_assert!(
+                state.is_consistent(),
+                "impossible sentence state {:?}",
+                state
+            );
+            Some(state)
+        }
+    }
+
     // https://unicode.org/reports/tr29/#SB8
-    // TODO cache this, it is currently quadratic
-    fn match_sb8(state: &SentenceBreaksState, ahead: &str) -> bool {
+    fn match_sb8(state: &SentenceBreaksState) -> bool {
+        // ATerm Close* Sp*
         let &SentenceBreaksState(parts) = state;
         let mut idx = if parts[3] == StatePart::SpPlus { 2 } else { 3 };
         if parts[idx] == StatePart::ClosePlus {
             idx -= 1
         }
+        parts[idx] == StatePart::ATerm
+    }
 
-        if parts[idx] == StatePart::ATerm {
-            use crate::tables::sentence as se;
+    // The part of SB8 that looks ahead: whether a character decides if the text ahead
+    // continues to a `Lower`, and how
+    pub fn lookahead_step(cat: SentenceCat) -> Option<bool> {
+        use crate::tables::sentence as se;
 
-            for next_char in ahead.chars() {
-                //( ¬(OLetter | Upper | Lower | ParaSep | SATerm) )* Lower
-                match se::sentence_category(next_char).2 {
-                    se::SC_Lower => return true,
-                    se::SC_OLetter
-                    | se::SC_Upper
-                    | se::SC_Sep
-                    | se::SC_CR
-                    | se::SC_LF
-                    | se::SC_STerm
-                    | se::SC_ATerm => return false,
-                    _ => continue,
-                }
-            }
+        //( ¬(OLetter | Upper | Lower | ParaSep | SATerm) )* Lower
+        match cat {
+            se::SC_Lower => Some(true),
+            se::SC_OLetter
+            | se::SC_Upper
+            | se::SC_Sep
+            | se::SC_CR
+            | se::SC_LF
+            | se::SC_STerm
+            | se::SC_ATerm => Some(false),
+            _ => None,
         }
+    }
 
-        false
+    // Whether `ahead` continues to a `Lower` as SB8 requires, or `None` if it ends too soon
+    // to tell
+    pub fn lookahead_lower(ahead: &str, overrides: Overrides) -> Option<bool> {
+        ahead
+            .chars()
+            .find_map(|next_char| lookahead_step(category(next_char, overrides)))
     }
 
     // https://unicode.org/reports/tr29/#SB8a
@@ -170,6 +368,111 @@ mod fwd {
         parts[idx] == StatePart::STerm || parts[idx] == StatePart::ATerm
     }
 
+    // Decide whether there is a boundary before a character of category `next_cat`, given
+    // the state before it
+    pub fn decide(state_before: &SentenceBreaksState, next_cat: SentenceCat) -> Decision {
+        match next_cat {
+            // SB1 https://unicode.org/reports/tr29/#SB1
+            _ if state_before.match1(StatePart::Sot) => Decision::Break,
+
+            // SB2 is handled when the text runs out
+
+            // SB3 https://unicode.org/reports/tr29/#SB3
+            SentenceCat::SC_LF if state_before.match1(StatePart::CR) => Decision::Keep,
+
+            // SB4 https://unicode.org/reports/tr29/#SB4
+            _ if state_before.match1(StatePart::Sep)
+                || state_before.match1(StatePart::CR)
+                || state_before.match1(StatePart::LF) =>
+            {
+                Decision::Break
+            }
+
+            // SB5 https://unicode.org/reports/tr29/#SB5
+            SentenceCat::SC_Extend | SentenceCat::SC_Format => Decision::Keep,
+
+            // SB6 https://unicode.org/reports/tr29/#SB6
+            SentenceCat::SC_Numeric if state_before.match1(StatePart::ATerm) => Decision::Keep,
+
+            // SB7 https://unicode.org/reports/tr29/#SB7
+            SentenceCat::SC_Upper
+                if state_before.match2(StatePart::UpperLower, StatePart::ATerm) =>
+            {
+                Decision::Keep
+            }
+
+            // SB8 comes next, but it can only overrule the boundary SB11 would find, so it
+            // is applied there. Its lookahead is then needed once per terminator at most.
+
+            // SB8a https://unicode.org/reports/tr29/#SB8a
+            SentenceCat::SC_SContinue | SentenceCat::SC_STerm | SentenceCat::SC_ATerm
+                if match_sb8a(state_before) =>
+            {
+                Decision::Keep
+            }
+
+            // SB9 https://unicode.org/reports/tr29/#SB9
+            SentenceCat::SC_Close
+            | SentenceCat::SC_Sp
+            | SentenceCat::SC_Sep
+            | SentenceCat::SC_CR
+            | SentenceCat::SC_LF
+                if match_sb9(state_before) =>
+            {
+                Decision::Keep
+            }
+
+            // SB10 https://unicode.org/reports/tr29/#SB10
+            SentenceCat::SC_Sp | SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF
+                if match_sb8a(state_before) =>
+            {
+                Decision::Keep
+            }
+
+            // SB11 https://unicode.org/reports/tr29/#SB11, unless SB8
+            // https://unicode.org/reports/tr29/#SB8 applies
+            _ if match_sb11(state_before) => {
+                if match_sb8(state_before) {
+                    Decision::BreakUnlessLower
+                } else {
+                    Decision::Break
+                }
+            }
+
+            // SB998 https://unicode.org/reports/tr29/#SB998
+            _ => Decision::Keep,
+        }
+    }
+
+    // Decide whether there is a boundary between two characters from their categories alone,
+    // without the state before them, which is the common case. Only valid away from the
+    // start of the text.
+    pub fn decide_adjacent(before: SentenceCat, after: SentenceCat) -> Option<bool> {
+        match (before, after) {
+            // SB3
+            (SentenceCat::SC_CR, SentenceCat::SC_LF) => Some(false),
+            // SB4
+            (SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF, _) => Some(true),
+            // SB5
+            (_, SentenceCat::SC_Extend | SentenceCat::SC_Format) => Some(false),
+            // Nothing before ends a sentence that SB11 could break after
+            (
+                SentenceCat::SC_Upper
+                | SentenceCat::SC_Lower
+                | SentenceCat::SC_OLetter
+                | SentenceCat::SC_Numeric
+                | SentenceCat::SC_SContinue
+                | SentenceCat::SC_Any,
+                _,
+            ) => Some(false),
+            // Runs of `Close` or `Sp` are kept together by SB9 and SB10, or else not preceded
+            // by a terminator
+            (SentenceCat::SC_Close, SentenceCat::SC_Close)
+            | (SentenceCat::SC_Sp, SentenceCat::SC_Sp) => Some(false),
+            _ => None,
+        }
+    }
+
     impl<'a> Iterator for SentenceBreaks<'a> {
         // Returns the index of the character which follows a break
         type Item = usize;
@@ -183,82 +486,30 @@ mod fwd {
 
         #[inline]
         fn next(&mut self) -> Option<usize> {
-            use crate::tables::sentence as se;
-
-            for next_char in self.string[self.pos..].chars() {
+            while let Some(next_char) = self.string[self.pos..].chars().next() {
+                if self.overrides.is_none() && self.state.is_plain() {
+                    let len = plain_ascii_len(&self.string.as_bytes()[self.pos..]);
+                    if len > 0 {
+                        self.skip_plain(len);
+                        continue;
+                    }
+                }
                 let position_before = self.pos;
                 let state_before = self.state.clone();
 
-                let next_cat = se::sentence_category(next_char).2;
+                let next_cat = category(next_char, self.overrides);
 
                 self.pos += next_char.len_utf8();
-                self.state = self.state.next(next_cat);
+                self.state = state_before.advance(next_cat);
 
-                match next_cat {
-                    // SB1 https://unicode.org/reports/tr29/#SB1
-                    _ if state_before.match1(StatePart::Sot) => return Some(position_before),
-
-                    // SB2 is handled when inner iterator (chars) is finished
-
-                    // SB3 https://unicode.org/reports/tr29/#SB3
-                    SentenceCat::SC_LF if state_before.match1(StatePart::CR) => continue,
-
-                    // SB4 https://unicode.org/reports/tr29/#SB4
-                    _ if state_before.match1(StatePart::Sep)
-                        || state_before.match1(StatePart::CR)
-                        || state_before.match1(StatePart::LF) =>
+                match decide(&state_before, next_cat) {
+                    Decision::Break => return Some(position_before),
+                    Decision::BreakUnlessLower
+                        if lookahead_lower(&self.string[position_before..], self.overrides)
+                            != Some(true) =>
                     {
                         return Some(position_before)
                     }
-
-                    // SB5 https://unicode.org/reports/tr29/#SB5
-                    SentenceCat::SC_Extend | SentenceCat::SC_Format => self.state = state_before,
-
-                    // SB6 https://unicode.org/reports/tr29/#SB6
-                    SentenceCat::SC_Numeric if state_before.match1(StatePart::ATerm) => continue,
-
-                    // SB7 https://unicode.org/reports/tr29/#SB7
-                    SentenceCat::SC_Upper
-                        if state_before.match2(StatePart::UpperLower, StatePart::ATerm) =>
-                    {
-                        continue
-                    }
-
-                    // SB8 https://unicode.org/reports/tr29/#SB8
-                    _ if match_sb8(&state_before, &self.string[position_before..]) => continue,
-
-                    // SB8a https://unicode.org/reports/tr29/#SB8a
-                    SentenceCat::SC_SContinue | SentenceCat::SC_STerm | SentenceCat::SC_ATerm
-                        if match_sb8a(&state_before) =>
-                    {
-                        continue
-                    }
-
-                    // SB9 https://unicode.org/reports/tr29/#SB9
-                    SentenceCat::SC_Close
-                    | SentenceCat::SC_Sp
-                    | SentenceCat::SC_Sep
-                    | SentenceCat::SC_CR
-                    | SentenceCat::SC_LF
-                        if match_sb9(&state_before) =>
-                    {
-                        continue
-                    }
-
-                    // SB10 https://unicode.org/reports/tr29/#SB10
-                    SentenceCat::SC_Sp
-                    | SentenceCat::SC_Sep
-                    | SentenceCat::SC_CR
-                    | SentenceCat::SC_LF
-                        if match_sb8a(&state_before) =>
-                    {
-                        continue
-                    }
-
-                    // SB11 https://unicode.org/reports/tr29/#SB11
-                    _ if match_sb11(&state_before) => return Some(position_before),
-
-                    // SB998 https://unicode.org/reports/tr29/#SB998
                     _ => continue,
                 }
             }
@@ -273,15 +524,204 @@ mod fwd {
         }
     }
 
+    impl<'a> SentenceBreaks<'a> {
+        // Move past the next `len` bytes, found by `plain_ascii_len` when the state is plain.
+        // None of those characters can follow a boundary, so only the state needs updating,
+        // and it only depends on the characters that make up the last four parts, which are
+        // found going backwards.
+        fn skip_plain(&mut self, len: usize) {
+            use crate::tables::sentence as se;
+
+            // Short runs are quicker to go through from the start
+            const SHORT: usize = 8;
+
+            let end = self.pos + len;
+            let bytes = self.string.as_bytes();
+            let mut tail = if len > SHORT { end } else { self.pos };
+            let mut parts = 0;
+            let mut last = None;
+            while tail > self.pos {
+                let part = StatePart::of(se::sentence_category(bytes[tail - 1] as char).2);
+                let collapses =
+                    last == Some(part) && matches!(part, StatePart::ClosePlus | StatePart::SpPlus);
+                if !collapses {
+                    if parts == 4 {
+                        break;
+                    }
+                    parts += 1;
+                    last = Some(part);
+                }
+                tail -= 1;
+            }
+            for &b in &bytes[tail..end] {
+                self.state = self.state.advance(se::sentence_category(b as char).2);
+            }
+            self.pos = end;
+        }
+    }
+
+    // Whether a byte may be the start of a character that ends a sentence or a paragraph. Any
+    // character outside ASCII might.
+    #[inline]
+    fn may_end_sentence(b: u8) -> bool {
+        matches!(b, b'.' | b'!' | b'?' | b'\r' | b'\n') || !b.is_ascii()
+    }
+
+    // The length of the start of `bytes` made of ASCII characters that can't end a sentence or
+    // a paragraph
+    #[cfg(not(feature = "memchr"))]
+    #[inline]
+    fn plain_ascii_len(bytes: &[u8]) -> usize {
+        bytes
+            .iter()
+            .position(|&b| may_end_sentence(b))
+            .unwrap_or(bytes.len())
+    }
+
+    #[cfg(feature = "memchr")]
+    #[inline]
+    fn plain_ascii_len(bytes: &[u8]) -> usize {
+        // Runs between the characters of other scripts are mostly short, and quicker to check
+        // a byte at a time
+        const SHORT: usize = 16;
+        // Searched a window at a time after that, so that the work done stays in proportion
+        // to the length found
+        const WINDOW: usize = 256;
+
+        let short = cmp::min(SHORT, bytes.len());
+        if let Some(len) = bytes[..short].iter().position(|&b| may_end_sentence(b)) {
+            return len;
+        }
+        let mut start = short;
+        while start < bytes.len() {
+            let window = &bytes[start..cmp::min(start + WINDOW, bytes.len())];
+            let end = memchr::memchr3(b'.', b'!', b'?', window).unwrap_or(window.len());
+            let end = memchr::memchr2(b'\r', b'\n', &window[..end]).unwrap_or(end);
+            if !window[..end].is_ascii() {
+                return start + window.iter().position(|b| !b.is_ascii()).unwrap();
+            }
+            if end < window.len() {
+                return start + end;
+            }
+            start += window.len();
+        }
+        bytes.len()
+    }
+
     pub fn new_sentence_breaks(source: &str) -> SentenceBreaks<'_> {
+        new_sentence_breaks_from(source, 0)
+    }
+
+    // Start at `pos`, which must follow a paragraph separator, where the state is as at the
+    // start of the text
+    pub fn new_sentence_breaks_from(source: &str, pos: usize) -> SentenceBreaks<'_> {
         SentenceBreaks {
             string: source,
-            pos: 0,
+            pos,
             state: INITIAL_STATE,
+            overrides: None,
+        }
+    }
+}
+
+/// The [Sentence_Break](http://www.unicode.org/reports/tr29/#Table_Sentence_Break_Property_Values)
+/// property of a character, as used by the sentence segmentation in this crate.
+///
+/// This is returned by [`sentence_break_property`].
+///
+/// [`sentence_break_property`]: fn.sentence_break_property.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SentenceBreakProperty {
+    /// A full stop or similar character that may end a sentence or an abbreviation, such as `.`.
+    ATerm,
+    /// A character that closes or opens a quotation or parenthetical, such as `"`, `)` or `»`.
+    Close,
+    /// U+000D CARRIAGE RETURN.
+    CR,
+    /// A combining mark or other character that extends the preceding character.
+    Extend,
+    /// A format character, such as U+200B ZERO WIDTH SPACE.
+    Format,
+    /// U+000A LINE FEED.
+    LF,
+    /// A lowercase letter.
+    Lower,
+    /// A digit.
+    Numeric,
+    /// A letter that is neither uppercase nor lowercase, such as an ideograph.
+    OLetter,
+    /// A character that may continue a sentence after a terminator, such as `,` or `;`.
+    SContinue,
+    /// A character that always ends a sentence, such as `!`, `?` or `。`.
+    STerm,
+    /// A paragraph separator, such as U+0085 NEXT LINE or U+2029 PARAGRAPH SEPARATOR.
+    Sep,
+    /// A space character, other than the line and paragraph separators.
+    Sp,
+    /// An uppercase or titlecase letter.
+    Upper,
+    /// Any other character.
+    Other,
+}
+
+impl From<SentenceCat> for SentenceBreakProperty {
+    fn from(cat: SentenceCat) -> SentenceBreakProperty {
+        match cat {
+            SentenceCat::SC_ATerm => SentenceBreakProperty::ATerm,
+            SentenceCat::SC_Close => SentenceBreakProperty::Close,
+            SentenceCat::SC_CR => SentenceBreakProperty::CR,
+            SentenceCat::SC_Extend => SentenceBreakProperty::Extend,
+            SentenceCat::SC_Format => SentenceBreakProperty::Format,
+            SentenceCat::SC_LF => SentenceBreakProperty::LF,
+            SentenceCat::SC_Lower => SentenceBreakProperty::Lower,
+            SentenceCat::SC_Numeric => SentenceBreakProperty::Numeric,
+            SentenceCat::SC_OLetter => SentenceBreakProperty::OLetter,
+            SentenceCat::SC_SContinue => SentenceBreakProperty::SContinue,
+            SentenceCat::SC_STerm => SentenceBreakProperty::STerm,
+            SentenceCat::SC_Sep => SentenceBreakProperty::Sep,
+            SentenceCat::SC_Sp => SentenceBreakProperty::Sp,
+            SentenceCat::SC_Upper => SentenceBreakProperty::Upper,
+            SentenceCat::SC_Any => SentenceBreakProperty::Other,
         }
     }
 }
 
+impl From<SentenceBreakProperty> for SentenceCat {
+    fn from(property: SentenceBreakProperty) -> SentenceCat {
+        match property {
+            SentenceBreakProperty::ATerm => SentenceCat::SC_ATerm,
+            SentenceBreakProperty::Close => SentenceCat::SC_Close,
+            SentenceBreakProperty::CR => SentenceCat::SC_CR,
+            SentenceBreakProperty::Extend => SentenceCat::SC_Extend,
+            SentenceBreakProperty::Format => SentenceCat::SC_Format,
+            SentenceBreakProperty::LF => SentenceCat::SC_LF,
+            SentenceBreakProperty::Lower => SentenceCat::SC_Lower,
+            SentenceBreakProperty::Numeric => SentenceCat::SC_Numeric,
+            SentenceBreakProperty::OLetter => SentenceCat::SC_OLetter,
+            SentenceBreakProperty::SContinue => SentenceCat::SC_SContinue,
+            SentenceBreakProperty::STerm => SentenceCat::SC_STerm,
+            SentenceBreakProperty::Sep => SentenceCat::SC_Sep,
+            SentenceBreakProperty::Sp => SentenceCat::SC_Sp,
+            SentenceBreakProperty::Upper => SentenceCat::SC_Upper,
+            SentenceBreakProperty::Other => SentenceCat::SC_Any,
+        }
+    }
+}
+
+/// The Sentence_Break property of `c`, from the same Unicode data the sentence iterators use.
+///
+/// ```rust
+/// use unicode_segmentation::{sentence_break_property, SentenceBreakProperty};
+///
+/// assert_eq!(sentence_break_property('.'), SentenceBreakProperty::ATerm);
+/// assert_eq!(sentence_break_property('?'), SentenceBreakProperty::STerm);
+/// assert_eq!(sentence_break_property('a'), SentenceBreakProperty::Lower);
+/// assert_eq!(sentence_break_property('$'), SentenceBreakProperty::Other);
+/// ```
+pub fn sentence_break_property(c: char) -> SentenceBreakProperty {
+    crate::tables::sentence::sentence_category(c).2.into()
+}
+
 /// An iterator over the substrings of a string which, after splitting the string on
 /// [sentence boundaries](http://www.unicode.org/reports/tr29/#Sentence_Boundaries),
 /// contain any characters with the
@@ -311,6 +751,251 @@ pub struct UnicodeSentences<'a> {
 pub struct USentenceBounds<'a> {
     iter: fwd::SentenceBreaks<'a>,
     sentence_start: Option<usize>,
+    options: SentenceOptions,
+    quotes: QuoteState,
+    // The end of a sentence whose start has been split off by `SentenceOptions`.
+    pending_end: Option<usize>,
+    // Where the sentences taken from the back start, found with `back`
+    sentence_end: usize,
+    back: SentenceCursor,
+    // The starts of the tailored sentences before `sentence_end` in its paragraph
+    #[cfg(feature = "alloc")]
+    back_starts: Vec<usize>,
+}
+
+/// Options tailoring how strings are split on sentence boundaries.
+///
+/// The default options give the segmentation described in
+/// [UAX#29](http://www.unicode.org/reports/tr29/#Sentence_Boundaries). Options are passed to the
+/// [`split_sentence_bounds_with`] or [`unicode_sentences_with`] methods on the
+/// [`UnicodeSegmentationExt`] trait.
+///
+/// [`split_sentence_bounds_with`]: trait.UnicodeSegmentationExt.html#tymethod.split_sentence_bounds_with
+/// [`unicode_sentences_with`]: trait.UnicodeSegmentationExt.html#tymethod.unicode_sentences_with
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SentenceOptions {
+    quote_aware: bool,
+    split_on_semicolon: bool,
+    require_space_after_terminator: bool,
+    overrides: fwd::Overrides,
+}
+
+impl SentenceOptions {
+    /// Create options giving the default UAX#29 sentence segmentation.
+    pub const fn new() -> SentenceOptions {
+        SentenceOptions {
+            quote_aware: false,
+            split_on_semicolon: false,
+            require_space_after_terminator: false,
+            overrides: None,
+        }
+    }
+
+    /// Don't break after a `?`, `!` or other `STerm` character that appears inside an
+    /// unclosed quotation, so that dialogue like `"Really?" she asked.` stays one sentence.
+    ///
+    /// Quotation spans are tracked with straight and curly double and single quotes and with
+    /// guillemets. A straight single quote only opens a span when not preceded by a letter or
+    /// digit, and only closes one when not followed by one, so apostrophes are ignored. Spans
+    /// never extend past the end of a paragraph.
+    ///
+    /// Whether a quoted question ends the surrounding sentence depends on the text, so this
+    /// also joins `He asked "why?" Nobody knew.` into a single sentence.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{SentenceOptions, UnicodeSegmentationExt};
+    /// let s = "\"Really?\" she asked. 'Yes!' he said.";
+    /// let options = SentenceOptions::new().quote_aware(true);
+    /// let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(sentences, ["\"Really?\" she asked. ", "'Yes!' he said."]);
+    /// # }
+    /// ```
+    pub fn quote_aware(mut self, quote_aware: bool) -> SentenceOptions {
+        self.quote_aware = quote_aware;
+        self
+    }
+
+    /// Also break after every semicolon, `;` or the fullwidth `；`, treating the clauses it
+    /// separates as sentences.
+    ///
+    /// As after a full stop, spaces and a paragraph separator following the semicolon stay with
+    /// the clause before it.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{SentenceOptions, UnicodeSegmentationExt};
+    /// let s = "I came; I saw; I conquered. Then I left.";
+    /// let options = SentenceOptions::new().split_on_semicolon(true);
+    /// let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(sentences, ["I came; ", "I saw; ", "I conquered. ", "Then I left."]);
+    /// # }
+    /// ```
+    pub fn split_on_semicolon(mut self, split_on_semicolon: bool) -> SentenceOptions {
+        self.split_on_semicolon = split_on_semicolon;
+        self
+    }
+
+    /// Only break after a sentence terminator that is followed by whitespace or the end of the
+    /// text, so that `Done!Next` stays one sentence while `Done! Next` is still split.
+    ///
+    /// The UAX#29 rules already avoid breaking after a full stop followed directly by a letter
+    /// or digit, like in `example.com` or `3.14`. This catches the cases they leave, such as a
+    /// `?` or `!` followed directly by a letter, or a full stop followed by a bracket or an
+    /// ideograph. Breaks after paragraph separators are unaffected.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{SentenceOptions, UnicodeSegmentationExt};
+    /// let s = "Is it?Yes!See example.com. Done";
+    /// let options = SentenceOptions::new().require_space_after_terminator(true);
+    /// let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(sentences, ["Is it?Yes!See example.com. ", "Done"]);
+    /// # }
+    /// ```
+    pub fn require_space_after_terminator(
+        mut self,
+        require_space_after_terminator: bool,
+    ) -> SentenceOptions {
+        self.require_space_after_terminator = require_space_after_terminator;
+        self
+    }
+
+    /// Give some characters a different
+    /// [Sentence_Break](http://www.unicode.org/reports/tr29/#Table_Sentence_Break_Property_Values)
+    /// property, to add or remove sentence terminators. `overrides` returns the property to use
+    /// for a character, or `None` to keep its own.
+    ///
+    /// Paragraph separators can't be changed, and no other character can be made one, so
+    /// properties `Sep`, `CR` and `LF` are ignored.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{SentenceBreakProperty, SentenceOptions, UnicodeSegmentationExt};
+    /// let s = "Ready; steady. Go!";
+    /// let options = SentenceOptions::new().property_override(|c| match c {
+    ///     ';' => Some(SentenceBreakProperty::STerm),
+    ///     '.' => Some(SentenceBreakProperty::SContinue),
+    ///     _ => None,
+    /// });
+    /// let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(sentences, ["Ready; ", "steady. Go!"]);
+    /// # }
+    /// ```
+    pub fn property_override(
+        mut self,
+        overrides: fn(char) -> Option<SentenceBreakProperty>,
+    ) -> SentenceOptions {
+        self.overrides = Some(fwd::Override(overrides));
+        self
+    }
+
+    // The category of `c`, with any overrides applied
+    #[inline]
+    fn category(&self, c: char) -> SentenceCat {
+        fwd::category(c, self.overrides)
+    }
+
+    #[inline]
+    fn is_default(&self) -> bool {
+        *self == SentenceOptions::new()
+    }
+
+    // The offset just past the first semicolon in `sentence` and the spaces and paragraph
+    // separator after it, if that is before the end of the sentence.
+    fn split(&self, sentence: &str) -> Option<usize> {
+        if !self.split_on_semicolon {
+            return None;
+        }
+        let (i, semicolon) = sentence.match_indices(&[';', '\u{ff1b}'][..]).next()?;
+        let rest = &sentence[i + semicolon.len()..];
+        let spaces = rest
+            .char_indices()
+            .find(|&(_, c)| self.category(c) != SentenceCat::SC_Sp)
+            .map_or(rest.len(), |(j, _)| j);
+        let rest = &rest[spaces..];
+        let separator = match rest.chars().next() {
+            _ if rest.starts_with("\r\n") => 2,
+            Some(c)
+                if matches!(
+                    self.category(c),
+                    SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF
+                ) =>
+            {
+                c.len_utf8()
+            }
+            _ => 0,
+        };
+        let split = sentence.len() - rest.len() + separator;
+        if split < sentence.len() {
+            Some(split)
+        } else {
+            None
+        }
+    }
+}
+
+// Whether a sentence produced by the UAX#29 rules ends with spaces or a paragraph separator,
+// ignoring any Extend or Format characters attached to them, for
+// `SentenceOptions::require_space_after_terminator`.
+fn ends_in_space(sentence: &str, options: &SentenceOptions) -> bool {
+    let last = sentence
+        .chars()
+        .rev()
+        .map(|c| options.category(c))
+        .find(|&cat| !matches!(cat, SentenceCat::SC_Extend | SentenceCat::SC_Format));
+    matches!(
+        last,
+        Some(SentenceCat::SC_Sp | SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF)
+    )
+}
+
+// Quotation spans open at the current position, for `SentenceOptions::quote_aware`.
+#[derive(Debug, Clone, Copy, Default)]
+struct QuoteState {
+    double: bool,
+    single: bool,
+    depth: usize,
+}
+
+impl QuoteState {
+    fn is_open(&self) -> bool {
+        self.double || self.single || self.depth > 0
+    }
+
+    // Scan a sentence produced by the UAX#29 rules, returning whether the break after it should
+    // be suppressed because its terminator was quoted.
+    fn quoted_terminator(&mut self, sentence: &str, options: &SentenceOptions) -> bool {
+        use crate::tables::util::is_alphanumeric;
+
+        let mut quoted = false;
+        let mut prev = None;
+        let mut chars = sentence.chars().peekable();
+        while let Some(ch) = chars.next() {
+            let after_alnum = matches!(prev, Some(c) if is_alphanumeric(c));
+            let before_alnum = matches!(chars.peek(), Some(&c) if is_alphanumeric(c));
+            match ch {
+                '"' => self.double = !self.double,
+                '\'' | '\u{2019}' if self.single && !before_alnum => self.single = false,
+                '\'' if !after_alnum => self.single = true,
+                '\u{2018}' => self.single = true,
+                '\u{201c}' | '\u{201e}' | '\u{ab}' | '\u{2039}' => self.depth += 1,
+                '\u{201d}' | '\u{bb}' | '\u{203a}' => self.depth = self.depth.saturating_sub(1),
+                _ => match options.category(ch) {
+                    SentenceCat::SC_STerm => quoted = self.is_open(),
+                    SentenceCat::SC_ATerm => quoted = false,
+                    SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF => {
+                        *self = QuoteState::default();
+                        quoted = false;
+                    }
+                    _ => {}
+                },
+            }
+            prev = Some(ch);
+        }
+        quoted
+    }
 }
 
 /// External iterator for sentence boundaries and byte offsets.
@@ -326,14 +1011,233 @@ pub struct USentenceBoundIndices<'a> {
     iter: USentenceBounds<'a>,
 }
 
+/// External iterator for the sentences of a string with trailing whitespace removed, and their
+/// byte ranges.
+///
+/// This struct is created by the [`trimmed_sentences`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`trimmed_sentences`]: trait.UnicodeSegmentationExt.html#tymethod.trimmed_sentences
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Debug, Clone)]
+pub struct TrimmedSentences<'a> {
+    iter: USentenceBoundIndices<'a>,
+}
+
+impl<'a> Iterator for TrimmedSentences<'a> {
+    type Item = (usize, usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize, &'a str)> {
+        for (start, sentence) in &mut self.iter {
+            let trimmed = sentence.trim_end();
+            if !trimmed.is_empty() {
+                return Some((start, start + trimmed.len(), trimmed));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for TrimmedSentences<'a> {}
+
+/// Returns an iterator over the sentences of `source` without surrounding whitespace. See
+/// [`UnicodeSegmentationExt::trimmed_sentences`].
+///
+/// [`UnicodeSegmentationExt::trimmed_sentences`]: crate::UnicodeSegmentationExt::trimmed_sentences
+#[inline]
+pub fn new_trimmed_sentences(source: &str) -> TrimmedSentences<'_> {
+    TrimmedSentences {
+        iter: new_sentence_bound_indices(source),
+    }
+}
+
+/// External iterator for the byte offsets of the first letter of each sentence of a string.
+///
+/// This struct is created by the [`sentence_starts`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`sentence_starts`]: trait.UnicodeSegmentationExt.html#tymethod.sentence_starts
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Debug, Clone)]
+pub struct SentenceStarts<'a> {
+    iter: USentenceBoundIndices<'a>,
+}
+
+impl<'a> Iterator for SentenceStarts<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        use crate::tables::derived_property;
+
+        let (start, sentence) = self.iter.next()?;
+        let first = sentence
+            .char_indices()
+            .find(|&(_, c)| derived_property::Alphabetic(c));
+        Some(first.map_or(start, |(i, _)| start + i))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for SentenceStarts<'a> {}
+
+/// Returns an iterator over the offsets at which the sentences of `source` start. See
+/// [`UnicodeSegmentationExt::sentence_starts`].
+///
+/// [`UnicodeSegmentationExt::sentence_starts`]: crate::UnicodeSegmentationExt::sentence_starts
+#[inline]
+pub fn new_sentence_starts(source: &str) -> SentenceStarts<'_> {
+    SentenceStarts {
+        iter: new_sentence_bound_indices(source),
+    }
+}
+
+/// External iterator for the sentence boundaries of a string, with the index and byte offset of
+/// each sentence.
+///
+/// This struct is created by the [`enumerate_sentences`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`enumerate_sentences`]: trait.UnicodeSegmentationExt.html#tymethod.enumerate_sentences
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Debug, Clone)]
+pub struct EnumerateSentences<'a> {
+    iter: USentenceBoundIndices<'a>,
+    index: usize,
+}
+
+impl<'a> Iterator for EnumerateSentences<'a> {
+    type Item = (usize, usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize, &'a str)> {
+        let (start, sentence) = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some((index, start, sentence))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for EnumerateSentences<'a> {}
+
+/// Returns an iterator over the sentences of `source`, numbered. See
+/// [`UnicodeSegmentationExt::enumerate_sentences`].
+///
+/// [`UnicodeSegmentationExt::enumerate_sentences`]: crate::UnicodeSegmentationExt::enumerate_sentences
+#[inline]
+pub fn new_enumerate_sentences(source: &str) -> EnumerateSentences<'_> {
+    EnumerateSentences {
+        iter: new_sentence_bound_indices(source),
+        index: 0,
+    }
+}
+
+/// External iterator for the words of a string, each flagged with whether it is the first
+/// word of its sentence.
+///
+/// This struct is created by the [`words_with_sentence_start`] method on the
+/// [`UnicodeSegmentationExt`] trait. See its documentation for more.
+///
+/// [`words_with_sentence_start`]: trait.UnicodeSegmentationExt.html#tymethod.words_with_sentence_start
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Debug)]
+pub struct WordsWithSentenceStart<'a> {
+    sentences: USentenceBounds<'a>,
+    words: UnicodeWords<'a>,
+    first: bool,
+}
+
+impl<'a> Iterator for WordsWithSentenceStart<'a> {
+    type Item = (&'a str, bool);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, bool)> {
+        loop {
+            if let Some(word) = self.words.next() {
+                let first = self.first;
+                self.first = false;
+                return Some((word, first));
+            }
+            self.words = new_unicode_words(self.sentences.next()?);
+            self.first = true;
+        }
+    }
+}
+
+impl<'a> core::iter::FusedIterator for WordsWithSentenceStart<'a> {}
+
+/// Returns an iterator over the words of `source`, flagging those that start a sentence. See
+/// [`UnicodeSegmentationExt::words_with_sentence_start`].
+///
+/// [`UnicodeSegmentationExt::words_with_sentence_start`]: crate::UnicodeSegmentationExt::words_with_sentence_start
+#[inline]
+pub fn new_words_with_sentence_start(source: &str) -> WordsWithSentenceStart<'_> {
+    WordsWithSentenceStart {
+        sentences: new_sentence_bounds(source),
+        words: new_unicode_words(""),
+        first: true,
+    }
+}
+
+/// Returns an iterator over the substrings of `source` separated on sentence boundaries. See
+/// [`UnicodeSegmentation::split_sentence_bounds`].
+///
+/// [`UnicodeSegmentation::split_sentence_bounds`]: crate::UnicodeSegmentation::split_sentence_bounds
 #[inline]
 pub fn new_sentence_bounds(source: &str) -> USentenceBounds<'_> {
+    new_sentence_bounds_with(source, SentenceOptions::new())
+}
+
+/// Returns an iterator over the substrings of `source` separated on sentence boundaries, as
+/// tailored by `options`. See [`UnicodeSegmentationExt::split_sentence_bounds_with`].
+///
+/// [`UnicodeSegmentationExt::split_sentence_bounds_with`]: crate::UnicodeSegmentationExt::split_sentence_bounds_with
+#[inline]
+pub fn new_sentence_bounds_with(source: &str, options: SentenceOptions) -> USentenceBounds<'_> {
+    let mut iter = fwd::new_sentence_breaks(source);
+    iter.overrides = options.overrides;
     USentenceBounds {
-        iter: fwd::new_sentence_breaks(source),
+        iter,
         sentence_start: None,
+        options,
+        quotes: QuoteState::default(),
+        pending_end: None,
+        sentence_end: source.len(),
+        back: SentenceCursor::new(source.len(), source.len()),
+        #[cfg(feature = "alloc")]
+        back_starts: Vec::new(),
     }
 }
 
+/// Calls `f` with each sentence boundary of `s`, until it returns `ControlFlow::Break`. See
+/// [`UnicodeSegmentationExt::for_each_sentence_boundary`].
+///
+/// [`UnicodeSegmentationExt::for_each_sentence_boundary`]: crate::UnicodeSegmentationExt::for_each_sentence_boundary
+#[inline]
+pub fn for_each_sentence_boundary(
+    source: &str,
+    mut f: impl FnMut(usize) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    for offset in fwd::new_sentence_breaks(source) {
+        f(offset)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Returns an iterator over the substrings of `source` separated on sentence boundaries, and
+/// their byte offsets. See [`UnicodeSegmentation::split_sentence_bound_indices`].
+///
+/// [`UnicodeSegmentation::split_sentence_bound_indices`]: crate::UnicodeSegmentation::split_sentence_bound_indices
 #[inline]
 pub fn new_sentence_bound_indices(source: &str) -> USentenceBoundIndices<'_> {
     USentenceBoundIndices {
@@ -342,9 +1246,12 @@ pub fn new_sentence_bound_indices(source: &str) -> USentenceBoundIndices<'_> {
     }
 }
 
+/// Returns an iterator over the sentences of `s`. See
+/// [`UnicodeSegmentation::unicode_sentences`].
+///
+/// [`UnicodeSegmentation::unicode_sentences`]: crate::UnicodeSegmentation::unicode_sentences
 #[inline]
 pub fn new_unicode_sentences(s: &str) -> UnicodeSentences<'_> {
-    use super::UnicodeSegmentation;
     use crate::tables::util::is_alphanumeric;
 
     fn has_alphanumeric(s: &&str) -> bool {
@@ -353,7 +1260,32 @@ pub fn new_unicode_sentences(s: &str) -> UnicodeSentences<'_> {
     let has_alphanumeric: fn(&&str) -> bool = has_alphanumeric; // coerce to fn pointer
 
     UnicodeSentences {
-        inner: s.split_sentence_bounds().filter(has_alphanumeric),
+        inner: new_sentence_bounds(s).filter(has_alphanumeric),
+    }
+}
+
+/// Returns an iterator over the sentences of `s`, as tailored by `options`. See
+/// [`UnicodeSegmentationExt::unicode_sentences_with`].
+///
+/// [`UnicodeSegmentationExt::unicode_sentences_with`]: crate::UnicodeSegmentationExt::unicode_sentences_with
+#[inline]
+pub fn new_unicode_sentences_with(s: &str, options: SentenceOptions) -> UnicodeSentences<'_> {
+    use crate::tables::util::is_alphanumeric;
+
+    fn has_alphanumeric(s: &&str) -> bool {
+        s.chars().any(is_alphanumeric)
+    }
+    let has_alphanumeric: fn(&&str) -> bool = has_alphanumeric; // coerce to fn pointer
+
+    UnicodeSentences {
+        inner: new_sentence_bounds_with(s, options).filter(has_alphanumeric),
+    }
+}
+
+impl<'a> DoubleEndedIterator for UnicodeSentences<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
     }
 }
 
@@ -371,17 +1303,88 @@ impl<'a> Iterator for UnicodeSentences<'a> {
     }
 }
 
+impl<'a> core::iter::FusedIterator for UnicodeSentences<'a> {}
+
 impl<'a> Iterator for USentenceBounds<'a> {
     type Item = &'a str;
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.iter.size_hint();
-        (cmp::max(0, lower - 1), upper.map(|u| cmp::max(0, u - 1)))
+        (lower.saturating_sub(1), upper.map(|u| u.saturating_sub(1)))
     }
 
     #[inline]
     fn next(&mut self) -> Option<&'a str> {
+        if self.options.is_default() {
+            self.next_sentence()
+        } else {
+            self.next_tailored()
+        }
+    }
+}
+
+impl<'a> core::iter::FusedIterator for USentenceBounds<'a> {}
+
+impl<'a> USentenceBounds<'a> {
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::UnicodeSegmentation;
+    /// let mut iter = "Hi. Bye. Ok.".split_sentence_bounds();
+    /// assert_eq!(iter.as_str(), "Hi. Bye. Ok.");
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "Bye. Ok.");
+    /// iter.next_back();
+    /// assert_eq!(iter.as_str(), "Bye. ");
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "");
+    /// # }
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        &self.iter.string[self.sentence_start.unwrap_or(0)..self.sentence_end]
+    }
+
+    // Apply `SentenceOptions` by joining sentences produced by the UAX#29 rules, then splitting
+    // the result. The rest of a split sentence is kept in `pending_end` for the next call.
+    fn next_tailored(&mut self) -> Option<&'a str> {
+        let source = self.iter.string;
+        let start = self.sentence_start.unwrap_or(0);
+        let end = match self.pending_end.take() {
+            // The back may have taken the rest already
+            Some(_) if start == self.sentence_end => return None,
+            Some(end) => end.min(self.sentence_end),
+            None => {
+                let mut sentence = self.next_sentence()?;
+                loop {
+                    let quoted = self.options.quote_aware
+                        && self.quotes.quoted_terminator(sentence, &self.options);
+                    let unspaced = self.options.require_space_after_terminator
+                        && !ends_in_space(sentence, &self.options);
+                    if !quoted && !unspaced {
+                        break;
+                    }
+                    match self.next_sentence() {
+                        Some(next) => sentence = next,
+                        None => break,
+                    }
+                }
+                self.sentence_start.unwrap()
+            }
+        };
+        if let Some(split) = self.options.split(&source[start..end]) {
+            self.sentence_start = Some(start + split);
+            self.pending_end = Some(end);
+            return Some(&source[start..start + split]);
+        }
+        self.sentence_start = Some(end);
+        Some(&source[start..end])
+    }
+
+    #[inline]
+    fn next_sentence(&mut self) -> Option<&'a str> {
         if self.sentence_start.is_none() {
             if let Some(start_pos) = self.iter.next() {
                 self.sentence_start = Some(start_pos)
@@ -389,8 +1392,13 @@ impl<'a> Iterator for USentenceBounds<'a> {
                 return None;
             }
         }
+        if self.sentence_start == Some(self.sentence_end) {
+            return None;
+        }
 
         if let Some(break_pos) = self.iter.next() {
+            // Sentences split off by `SentenceOptions` can end between the UAX#29 boundaries
+            let break_pos = break_pos.min(self.sentence_end);
             let start_pos = self.sentence_start.unwrap();
             let sentence = &self.iter.string[start_pos..break_pos];
             self.sentence_start = Some(break_pos);
@@ -401,6 +1409,101 @@ impl<'a> Iterator for USentenceBounds<'a> {
     }
 }
 
+impl<'a> DoubleEndedIterator for USentenceBounds<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.options.is_default() {
+            self.next_sentence_back()
+        } else {
+            self.next_tailored_back()
+        }
+    }
+}
+
+impl<'a> USentenceBounds<'a> {
+    // The boundary before `sentence_end`, found by moving `back` there
+    fn prev_break(&mut self) -> usize {
+        let source = self.iter.string;
+        match self.back.prev_boundary(source, 0) {
+            Ok(Some(pos)) => pos,
+            result => unreachable!("{:?} with the whole string", result),
+        }
+    }
+
+    #[inline]
+    fn next_sentence_back(&mut self) -> Option<&'a str> {
+        let end = self.sentence_end;
+        if end <= self.sentence_start.unwrap_or(0) {
+            return None;
+        }
+        let start = self.prev_break();
+        self.sentence_end = start;
+        Some(&self.iter.string[start..end])
+    }
+
+    // `SentenceOptions` are applied going forward, and their effect never reaches past a
+    // paragraph separator. So apply them from the last paragraph start before the end, or from
+    // the front if that comes later, and take the last sentence. The starts of the others are
+    // kept for the next calls when there is an allocator, and found again otherwise.
+    fn next_tailored_back(&mut self) -> Option<&'a str> {
+        use crate::tables::sentence::{self as se, SentenceCat};
+
+        let source = self.iter.string;
+        let front = self.sentence_start.unwrap_or(0);
+        if self.sentence_end <= front {
+            return None;
+        }
+        #[cfg(feature = "alloc")]
+        if let Some(start) = self.back_starts.pop() {
+            return Some(self.take_back(start.max(front)));
+        }
+        let mut forward = loop {
+            let pos = self.prev_break();
+            if pos <= front {
+                break self.clone();
+            }
+            let after_paragraph = source[..pos].chars().next_back().is_some_and(|c| {
+                matches!(
+                    se::sentence_category(c).2,
+                    SentenceCat::SC_Sep | SentenceCat::SC_CR | SentenceCat::SC_LF
+                )
+            });
+            if after_paragraph {
+                let mut iter = fwd::new_sentence_breaks_from(source, pos);
+                iter.overrides = self.options.overrides;
+                // SB1 puts the first break at `pos` itself
+                let sentence_start = iter.next();
+                break USentenceBounds {
+                    iter,
+                    sentence_start,
+                    options: self.options,
+                    quotes: QuoteState::default(),
+                    pending_end: None,
+                    sentence_end: self.sentence_end,
+                    back: self.back.clone(),
+                    #[cfg(feature = "alloc")]
+                    back_starts: Vec::new(),
+                };
+            }
+        };
+        let mut last = None;
+        while let Some(sentence) = forward.next_tailored() {
+            #[cfg(feature = "alloc")]
+            self.back_starts.extend(last);
+            last = Some(sentence.as_ptr() as usize - source.as_ptr() as usize);
+        }
+        Some(self.take_back(last.unwrap()))
+    }
+
+    // The sentence from `start` to `sentence_end`, which then moves to `start`
+    fn take_back(&mut self, start: usize) -> &'a str {
+        let end = self.sentence_end;
+        self.sentence_end = start;
+        self.back.set_cursor(start);
+        &self.iter.string[start..end]
+    }
+}
+
 impl<'a> Iterator for USentenceBoundIndices<'a> {
     type Item = (usize, &'a str);
 
@@ -416,3 +1519,409 @@ impl<'a> Iterator for USentenceBoundIndices<'a> {
         self.iter.size_hint()
     }
 }
+
+impl<'a> core::iter::FusedIterator for USentenceBoundIndices<'a> {}
+
+impl<'a> DoubleEndedIterator for USentenceBoundIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.iter
+            .next_back()
+            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    }
+}
+
+/// Cursor-based segmenter for sentences.
+///
+/// Like [`GraphemeCursor`], this allows working with ropes and other datastructures where the
+/// string is not contiguous or fully known at initialization time. The boundaries are those of
+/// [`split_sentence_bounds`].
+///
+/// Some boundaries take arbitrarily long lookahead to decide: by rule SB8, a full stop followed
+/// by closing punctuation and spaces does not end a sentence if the text goes on to a lowercase
+/// letter. When that lookahead runs past the chunk, `next_boundary` asks for the following
+/// chunks, and may then return a boundary that lies before the last chunk given. The cursor
+/// keeps track of how far it has read, so the next call should be given that last chunk again.
+///
+/// [`GraphemeCursor`]: struct.GraphemeCursor.html
+/// [`split_sentence_bounds`]: trait.UnicodeSegmentation.html#tymethod.split_sentence_bounds
+#[derive(Clone, Debug)]
+pub struct SentenceCursor {
+    /// Current cursor position.
+    offset: usize,
+    /// Total length of the string.
+    len: usize,
+    /// How far `next_boundary` has read. Only past `offset` after a boundary decided by
+    /// lookahead, in which case there is no other boundary in between.
+    scan: usize,
+    /// The state of the sentence break rules at `scan`, if known.
+    state: Option<fwd::SentenceBreaksState>,
+    /// A boundary found by `next_boundary` that stands unless the lookahead for SB8, which
+    /// continues into the following chunks, reaches a lowercase letter.
+    pending: Option<usize>,
+    /// The state at some position being rebuilt from the text before it, while pre-context
+    /// is needed.
+    rebuild: Option<fwd::StateRebuild>,
+    /// How far a suspended `prev_boundary` has moved back.
+    walk: Option<Walk>,
+    /// Where `prev_boundary` continues the lookahead for SB8 from the cursor, when it runs
+    /// past the chunk.
+    lookahead_scan: Option<usize>,
+    /// The outcome of the lookahead for SB8 from a position, if known.
+    lookahead: Option<(usize, bool)>,
+}
+
+/// How far `prev_boundary` has moved back, and what it knows about the text from there on.
+#[derive(Clone, Copy, Debug)]
+struct Walk {
+    pos: usize,
+    /// The category of the character at `pos`, unless the walk started there.
+    cat_after: Option<SentenceCat>,
+    /// Whether the text from `pos` continues to a lowercase letter, as SB8 looks for.
+    lookahead: bool,
+}
+
+/// An error return indicating that not enough content was available in the
+/// provided chunk to satisfy the query, and that more content must be provided.
+#[derive(PartialEq, Eq, Debug)]
+pub enum SentenceIncomplete {
+    /// More pre-context is needed. The caller should call `provide_context`
+    /// with a chunk ending at the offset given, then retry. This
+    /// will only be returned if the `chunk_start` parameter is nonzero.
+    PreContext(usize),
+
+    /// The chunk before the one given is requested. This is returned by `prev_boundary` when
+    /// the cursor is moving past the beginning of the current chunk, and after looking ahead
+    /// into the following chunks as described for `NextChunk`.
+    PrevChunk,
+
+    /// The chunk following the one given is requested. This is returned by `next_boundary`
+    /// when the cursor is moving past the end of the current chunk, and by `prev_boundary`
+    /// when rule SB8 needs the text after the cursor. It will only be returned if the chunk
+    /// ends before the `len` parameter provided on creation of the cursor.
+    NextChunk,
+
+    /// An error returned when the chunk given does not contain the cursor position.
+    InvalidOffset,
+}
+
+impl SentenceCursor {
+    /// Create a new cursor. The string and initial offset are given at creation
+    /// time, but the contents of the string are not.
+    ///
+    /// The `offset` parameter must be on a codepoint boundary.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::SentenceCursor;
+    /// let s = "Hi. Bye.";
+    /// let mut cursor = SentenceCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(4)));
+    /// ```
+    pub fn new(offset: usize, len: usize) -> SentenceCursor {
+        SentenceCursor {
+            offset,
+            len,
+            scan: offset,
+            state: None,
+            pending: None,
+            rebuild: None,
+            walk: None,
+            lookahead_scan: None,
+            lookahead: None,
+        }
+    }
+
+    /// Set the cursor to a new location in the same string.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::SentenceCursor;
+    /// let s = "Hi. Bye.";
+    /// let mut cursor = SentenceCursor::new(0, s.len());
+    /// cursor.set_cursor(5);
+    /// assert_eq!(cursor.cur_cursor(), 5);
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(8)));
+    /// ```
+    pub fn set_cursor(&mut self, offset: usize) {
+        if offset != self.offset {
+            *self = SentenceCursor::new(offset, self.len);
+        }
+    }
+
+    #[inline]
+    /// The current offset of the cursor. Equal to the last value provided to
+    /// `new()` or `set_cursor()`, or returned from `next_boundary()` or
+    /// `prev_boundary()`.
+    pub fn cur_cursor(&self) -> usize {
+        self.offset
+    }
+
+    /// Provide additional pre-context when it is needed to decide a boundary.
+    /// The end of the chunk must coincide with the value given in the
+    /// `SentenceIncomplete::PreContext` request.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{SentenceCursor, SentenceIncomplete};
+    /// let s = "Hi. Bye.";
+    /// let mut cursor = SentenceCursor::new(3, s.len());
+    /// // Whether the space is part of the first sentence depends on the text before it.
+    /// assert_eq!(cursor.next_boundary(&s[3..], 3), Err(SentenceIncomplete::PreContext(3)));
+    /// cursor.provide_context(&s[..3], 0);
+    /// assert_eq!(cursor.next_boundary(&s[3..], 3), Ok(Some(4)));
+    /// ```
+    pub fn provide_context(&mut self, chunk: &str, chunk_start: usize) {
+        let rebuild = self.rebuild.as_mut().unwrap();
+        assert!(chunk_start.saturating_add(chunk.len()) == rebuild.pos);
+        rebuild.feed(chunk);
+    }
+
+    // The state of the sentence break rules at `pos`, rebuilt from the text before it, which
+    // `chunk` must reach.
+    fn state_at(
+        &mut self,
+        pos: usize,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<fwd::SentenceBreaksState, SentenceIncomplete> {
+        let rebuild = match &mut self.rebuild {
+            Some(rebuild) if rebuild.at == pos => rebuild,
+            rebuild => rebuild.insert(fwd::StateRebuild::new(pos)),
+        };
+        if rebuild.pos > chunk_start {
+            rebuild.feed(&chunk[..rebuild.pos - chunk_start]);
+        }
+        match rebuild.state() {
+            Some(state) => {
+                self.rebuild = None;
+                Ok(state)
+            }
+            None => Err(SentenceIncomplete::PreContext(rebuild.pos)),
+        }
+    }
+
+    /// Find the next boundary after the current cursor position. Only a part of
+    /// the string need be supplied. If the chunk is incomplete, then this
+    /// method might return `SentenceIncomplete::PreContext` or
+    /// `SentenceIncomplete::NextChunk`. In the former case, the caller should
+    /// call `provide_context` with the requested chunk, then retry. In the
+    /// latter case, the caller should provide the chunk following the one
+    /// given, then retry.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{SentenceCursor, SentenceIncomplete};
+    /// let s = "Hi. Bye.";
+    /// let mut cursor = SentenceCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(&s[..2], 0), Err(SentenceIncomplete::NextChunk));
+    /// assert_eq!(cursor.next_boundary(&s[2..6], 2), Ok(Some(4)));
+    /// assert_eq!(cursor.next_boundary(&s[2..6], 2), Err(SentenceIncomplete::NextChunk));
+    /// assert_eq!(cursor.next_boundary(&s[6..], 6), Ok(Some(8)));
+    /// assert_eq!(cursor.next_boundary(&s[6..], 6), Ok(None));
+    /// ```
+    ///
+    /// A boundary decided by looking ahead can lie in a chunk given earlier:
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{SentenceCursor, SentenceIncomplete};
+    /// let s = "etc. (1, 2) But";
+    /// let mut cursor = SentenceCursor::new(0, s.len());
+    /// // "etc." would not end the sentence if a lowercase letter came before "But"
+    /// assert_eq!(cursor.next_boundary(&s[..6], 0), Err(SentenceIncomplete::NextChunk));
+    /// assert_eq!(cursor.next_boundary(&s[6..], 6), Ok(Some(5)));
+    /// assert_eq!(cursor.next_boundary(&s[6..], 6), Ok(Some(15)));
+    /// ```
+    pub fn next_boundary(
+        &mut self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, SentenceIncomplete> {
+        use crate::tables::sentence as se;
+
+        self.walk = None;
+        self.lookahead_scan = None;
+        if self.offset == self.len {
+            return Ok(None);
+        }
+        let chunk_end = chunk_start + chunk.len();
+        if self.scan < chunk_start || self.scan > chunk_end {
+            return Err(SentenceIncomplete::InvalidOffset);
+        }
+        let mut state = match self.state.take() {
+            Some(state) => state,
+            None => self.state_at(self.scan, chunk, chunk_start)?,
+        };
+        let mut pos = self.scan;
+        for ch in chunk[pos - chunk_start..].chars() {
+            let cat = se::sentence_category(ch).2;
+            if let Some(pending) = self.pending {
+                match fwd::lookahead_step(cat) {
+                    Some(true) => self.pending = None,
+                    Some(false) => {
+                        self.pending = None;
+                        self.offset = pending;
+                        self.scan = pos;
+                        self.state = Some(state);
+                        self.lookahead = Some((pending, false));
+                        return Ok(Some(pending));
+                    }
+                    None => {
+                        // Nothing in between can end a sentence until the lookahead is over
+                        debug_assert_eq!(fwd::decide(&state, cat), fwd::Decision::Keep);
+                        state = state.advance(cat);
+                        pos += ch.len_utf8();
+                        continue;
+                    }
+                }
+            }
+            if pos > self.offset {
+                let is_break = match fwd::decide(&state, cat) {
+                    fwd::Decision::Break => true,
+                    fwd::Decision::Keep => false,
+                    fwd::Decision::BreakUnlessLower => {
+                        match fwd::lookahead_lower(&chunk[pos - chunk_start..], None) {
+                            Some(lower) => !lower,
+                            None if chunk_end == self.len => true,
+                            None => {
+                                self.pending = Some(pos);
+                                false
+                            }
+                        }
+                    }
+                };
+                if is_break {
+                    self.offset = pos;
+                    self.scan = pos;
+                    self.state = Some(state);
+                    self.lookahead = fwd::lookahead_step(cat).map(|lower| (pos, lower));
+                    return Ok(Some(pos));
+                }
+            }
+            state = state.advance(cat);
+            pos += ch.len_utf8();
+        }
+        self.scan = pos;
+        self.state = Some(state);
+        if pos < self.len {
+            return Err(SentenceIncomplete::NextChunk);
+        }
+        // SB2, where a pending boundary stands since the lookahead found no lowercase letter
+        self.offset = self.pending.take().unwrap_or(pos);
+        self.lookahead = Some((self.offset, false));
+        Ok(Some(self.offset))
+    }
+
+    /// Find the previous boundary before the current cursor position. Only a part
+    /// of the string need be supplied. If the chunk is incomplete, then this
+    /// method might return `SentenceIncomplete::PreContext`,
+    /// `SentenceIncomplete::PrevChunk` or `SentenceIncomplete::NextChunk`. In the
+    /// first case, the caller should call `provide_context` with the requested
+    /// chunk, then retry. In the others, the caller should provide the chunk
+    /// preceding or following the one given, then retry.
+    ///
+    /// ```rust
+    /// # use unicode_segmentation::{SentenceCursor, SentenceIncomplete};
+    /// let s = "Hi. Bye. Ok.";
+    /// let mut cursor = SentenceCursor::new(s.len(), s.len());
+    /// assert_eq!(cursor.prev_boundary(&s[4..], 4), Ok(Some(9)));
+    /// assert_eq!(cursor.prev_boundary(&s[4..], 4), Err(SentenceIncomplete::PrevChunk));
+    /// assert_eq!(cursor.prev_boundary(&s[..4], 0), Ok(Some(4)));
+    /// assert_eq!(cursor.prev_boundary(&s[..4], 0), Ok(Some(0)));
+    /// assert_eq!(cursor.prev_boundary(&s[..4], 0), Ok(None));
+    /// ```
+    pub fn prev_boundary(
+        &mut self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, SentenceIncomplete> {
+        use crate::tables::sentence as se;
+
+        if self.offset == 0 {
+            return Ok(None);
+        }
+        let chunk_end = chunk_start + chunk.len();
+        if self.walk.is_none() {
+            self.scan = self.offset;
+            self.state = None;
+            self.pending = None;
+            if let Some(scan) = self.lookahead_scan {
+                if chunk_start != scan {
+                    return Err(SentenceIncomplete::InvalidOffset);
+                }
+                self.lookahead_scan = None;
+                self.lookahead = None;
+                let lower = match fwd::lookahead_lower(chunk, None) {
+                    Some(lower) => lower,
+                    None if chunk_end == self.len => false,
+                    None => {
+                        self.lookahead_scan = Some(chunk_end);
+                        return Err(SentenceIncomplete::NextChunk);
+                    }
+                };
+                self.lookahead = Some((self.offset, lower));
+            }
+            let lookahead = match self.lookahead {
+                Some((pos, lower)) if pos == self.offset => lower,
+                _ if self.offset == self.len => false,
+                _ => {
+                    if self.offset < chunk_start || self.offset > chunk_end {
+                        return Err(SentenceIncomplete::InvalidOffset);
+                    }
+                    match fwd::lookahead_lower(&chunk[self.offset - chunk_start..], None) {
+                        Some(lower) => lower,
+                        None if chunk_end == self.len => false,
+                        None => {
+                            self.lookahead_scan = Some(chunk_end);
+                            return Err(SentenceIncomplete::NextChunk);
+                        }
+                    }
+                }
+            };
+            self.walk = Some(Walk {
+                pos: self.offset,
+                cat_after: None,
+                lookahead,
+            });
+        }
+        loop {
+            let mut walk = self.walk.unwrap();
+            let mut state = None;
+            if walk.pos > 0 {
+                if walk.pos <= chunk_start {
+                    return Err(SentenceIncomplete::PrevChunk);
+                }
+                if walk.pos > chunk_end {
+                    return Err(SentenceIncomplete::InvalidOffset);
+                }
+                let ch = chunk[..walk.pos - chunk_start].chars().next_back().unwrap();
+                let cat_before = se::sentence_category(ch).2;
+                let is_break = match walk.cat_after {
+                    None => false,
+                    Some(cat) => match fwd::decide_adjacent(cat_before, cat) {
+                        Some(is_break) => is_break,
+                        None => {
+                            let state_before = self.state_at(walk.pos, chunk, chunk_start)?;
+                            let decision = fwd::decide(&state_before, cat);
+                            state = Some(state_before);
+                            match decision {
+                                fwd::Decision::Break => true,
+                                fwd::Decision::Keep => false,
+                                fwd::Decision::BreakUnlessLower => !walk.lookahead,
+                            }
+                        }
+                    },
+                };
+                if !is_break {
+                    walk.lookahead = fwd::lookahead_step(cat_before).unwrap_or(walk.lookahead);
+                    walk.cat_after = Some(cat_before);
+                    walk.pos -= ch.len_utf8();
+                    self.walk = Some(walk);
+                    continue;
+                }
+            }
+            self.walk = None;
+            self.offset = walk.pos;
+            self.scan = walk.pos;
+            self.state = state;
+            self.lookahead = Some((walk.pos, walk.lookahead));
+            return Ok(Some(walk.pos));
+        }
+    }
+}
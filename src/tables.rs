@@ -29,6 +29,16 @@ pub mod util {
         }).is_ok()
     }
 
+    #[inline]
+    pub fn bsearch_range_value_table<T: Copy>(c: char, r: &[(char, char, T)]) -> Option<T> {
+        use core::cmp::Ordering::{Equal, Less, Greater};
+        r.binary_search_by(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }).ok().map(|idx| r[idx].2)
+    }
+
     #[inline]
     fn is_alphabetic(c: char) -> bool {
         if super::UNICODE_VERSION_U8 == char::UNICODE_VERSION {
@@ -115,6 +125,116 @@ pub mod general_category {
         super::util::bsearch_range_table(c, N_table)
     }
 
+    const P_table: &[(char, char)] = &[
+        ('\u{21}', '\u{23}'), ('\u{25}', '\u{2a}'), ('\u{2c}', '\u{2f}'), ('\u{3a}', '\u{3b}'),
+        ('\u{3f}', '\u{40}'), ('\u{5b}', '\u{5d}'), ('\u{5f}', '\u{5f}'), ('\u{7b}', '\u{7b}'),
+        ('\u{7d}', '\u{7d}'), ('\u{a1}', '\u{a1}'), ('\u{a7}', '\u{a7}'), ('\u{ab}', '\u{ab}'),
+        ('\u{b6}', '\u{b7}'), ('\u{bb}', '\u{bb}'), ('\u{bf}', '\u{bf}'), ('\u{37e}', '\u{37e}'),
+        ('\u{387}', '\u{387}'), ('\u{55a}', '\u{55f}'), ('\u{589}', '\u{58a}'), ('\u{5be}',
+        '\u{5be}'), ('\u{5c0}', '\u{5c0}'), ('\u{5c3}', '\u{5c3}'), ('\u{5c6}', '\u{5c6}'),
+        ('\u{5f3}', '\u{5f4}'), ('\u{609}', '\u{60a}'), ('\u{60c}', '\u{60d}'), ('\u{61b}',
+        '\u{61b}'), ('\u{61d}', '\u{61f}'), ('\u{66a}', '\u{66d}'), ('\u{6d4}', '\u{6d4}'),
+        ('\u{700}', '\u{70d}'), ('\u{7f7}', '\u{7f9}'), ('\u{830}', '\u{83e}'), ('\u{85e}',
+        '\u{85e}'), ('\u{964}', '\u{965}'), ('\u{970}', '\u{970}'), ('\u{9fd}', '\u{9fd}'),
+        ('\u{a76}', '\u{a76}'), ('\u{af0}', '\u{af0}'), ('\u{c77}', '\u{c77}'), ('\u{c84}',
+        '\u{c84}'), ('\u{df4}', '\u{df4}'), ('\u{e4f}', '\u{e4f}'), ('\u{e5a}', '\u{e5b}'),
+        ('\u{f04}', '\u{f12}'), ('\u{f14}', '\u{f14}'), ('\u{f3a}', '\u{f3d}'), ('\u{f85}',
+        '\u{f85}'), ('\u{fd0}', '\u{fd4}'), ('\u{fd9}', '\u{fda}'), ('\u{104a}', '\u{104f}'),
+        ('\u{10fb}', '\u{10fb}'), ('\u{1360}', '\u{1368}'), ('\u{1400}', '\u{1400}'), ('\u{166e}',
+        '\u{166e}'), ('\u{169b}', '\u{169c}'), ('\u{16eb}', '\u{16ed}'), ('\u{1735}', '\u{1736}'),
+        ('\u{17d4}', '\u{17d6}'), ('\u{17d8}', '\u{17da}'), ('\u{1800}', '\u{180a}'), ('\u{1944}',
+        '\u{1945}'), ('\u{1a1e}', '\u{1a1f}'), ('\u{1aa0}', '\u{1aa6}'), ('\u{1aa8}', '\u{1aad}'),
+        ('\u{1b4e}', '\u{1b4f}'), ('\u{1b5a}', '\u{1b60}'), ('\u{1b7d}', '\u{1b7f}'), ('\u{1bfc}',
+        '\u{1bff}'), ('\u{1c3b}', '\u{1c3f}'), ('\u{1c7e}', '\u{1c7f}'), ('\u{1cc0}', '\u{1cc7}'),
+        ('\u{1cd3}', '\u{1cd3}'), ('\u{2010}', '\u{2027}'), ('\u{2030}', '\u{2043}'), ('\u{2045}',
+        '\u{2051}'), ('\u{2053}', '\u{205e}'), ('\u{207d}', '\u{207e}'), ('\u{208d}', '\u{208e}'),
+        ('\u{2308}', '\u{230b}'), ('\u{2329}', '\u{232a}'), ('\u{2768}', '\u{2775}'), ('\u{27c5}',
+        '\u{27c6}'), ('\u{27e6}', '\u{27ef}'), ('\u{2983}', '\u{2998}'), ('\u{29d8}', '\u{29db}'),
+        ('\u{29fc}', '\u{29fd}'), ('\u{2cf9}', '\u{2cfc}'), ('\u{2cfe}', '\u{2cff}'), ('\u{2d70}',
+        '\u{2d70}'), ('\u{2e00}', '\u{2e2e}'), ('\u{2e30}', '\u{2e4f}'), ('\u{2e52}', '\u{2e5d}'),
+        ('\u{3001}', '\u{3003}'), ('\u{3008}', '\u{3011}'), ('\u{3014}', '\u{301f}'), ('\u{3030}',
+        '\u{3030}'), ('\u{303d}', '\u{303d}'), ('\u{30a0}', '\u{30a0}'), ('\u{30fb}', '\u{30fb}'),
+        ('\u{a4fe}', '\u{a4ff}'), ('\u{a60d}', '\u{a60f}'), ('\u{a673}', '\u{a673}'), ('\u{a67e}',
+        '\u{a67e}'), ('\u{a6f2}', '\u{a6f7}'), ('\u{a874}', '\u{a877}'), ('\u{a8ce}', '\u{a8cf}'),
+        ('\u{a8f8}', '\u{a8fa}'), ('\u{a8fc}', '\u{a8fc}'), ('\u{a92e}', '\u{a92f}'), ('\u{a95f}',
+        '\u{a95f}'), ('\u{a9c1}', '\u{a9cd}'), ('\u{a9de}', '\u{a9df}'), ('\u{aa5c}', '\u{aa5f}'),
+        ('\u{aade}', '\u{aadf}'), ('\u{aaf0}', '\u{aaf1}'), ('\u{abeb}', '\u{abeb}'), ('\u{fd3e}',
+        '\u{fd3f}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'), ('\u{fe54}', '\u{fe61}'),
+        ('\u{fe63}', '\u{fe63}'), ('\u{fe68}', '\u{fe68}'), ('\u{fe6a}', '\u{fe6b}'), ('\u{ff01}',
+        '\u{ff03}'), ('\u{ff05}', '\u{ff0a}'), ('\u{ff0c}', '\u{ff0f}'), ('\u{ff1a}', '\u{ff1b}'),
+        ('\u{ff1f}', '\u{ff20}'), ('\u{ff3b}', '\u{ff3d}'), ('\u{ff3f}', '\u{ff3f}'), ('\u{ff5b}',
+        '\u{ff5b}'), ('\u{ff5d}', '\u{ff5d}'), ('\u{ff5f}', '\u{ff65}'), ('\u{10100}', '\u{10102}'),
+        ('\u{1039f}', '\u{1039f}'), ('\u{103d0}', '\u{103d0}'), ('\u{1056f}', '\u{1056f}'),
+        ('\u{10857}', '\u{10857}'), ('\u{1091f}', '\u{1091f}'), ('\u{1093f}', '\u{1093f}'),
+        ('\u{10a50}', '\u{10a58}'), ('\u{10a7f}', '\u{10a7f}'), ('\u{10af0}', '\u{10af6}'),
+        ('\u{10b39}', '\u{10b3f}'), ('\u{10b99}', '\u{10b9c}'), ('\u{10d6e}', '\u{10d6e}'),
+        ('\u{10ead}', '\u{10ead}'), ('\u{10f55}', '\u{10f59}'), ('\u{10f86}', '\u{10f89}'),
+        ('\u{11047}', '\u{1104d}'), ('\u{110bb}', '\u{110bc}'), ('\u{110be}', '\u{110c1}'),
+        ('\u{11140}', '\u{11143}'), ('\u{11174}', '\u{11175}'), ('\u{111c5}', '\u{111c8}'),
+        ('\u{111cd}', '\u{111cd}'), ('\u{111db}', '\u{111db}'), ('\u{111dd}', '\u{111df}'),
+        ('\u{11238}', '\u{1123d}'), ('\u{112a9}', '\u{112a9}'), ('\u{113d4}', '\u{113d5}'),
+        ('\u{113d7}', '\u{113d8}'), ('\u{1144b}', '\u{1144f}'), ('\u{1145a}', '\u{1145b}'),
+        ('\u{1145d}', '\u{1145d}'), ('\u{114c6}', '\u{114c6}'), ('\u{115c1}', '\u{115d7}'),
+        ('\u{11641}', '\u{11643}'), ('\u{11660}', '\u{1166c}'), ('\u{116b9}', '\u{116b9}'),
+        ('\u{1173c}', '\u{1173e}'), ('\u{1183b}', '\u{1183b}'), ('\u{11944}', '\u{11946}'),
+        ('\u{119e2}', '\u{119e2}'), ('\u{11a3f}', '\u{11a46}'), ('\u{11a9a}', '\u{11a9c}'),
+        ('\u{11a9e}', '\u{11aa2}'), ('\u{11b00}', '\u{11b09}'), ('\u{11be1}', '\u{11be1}'),
+        ('\u{11c41}', '\u{11c45}'), ('\u{11c70}', '\u{11c71}'), ('\u{11ef7}', '\u{11ef8}'),
+        ('\u{11f43}', '\u{11f4f}'), ('\u{11fff}', '\u{11fff}'), ('\u{12470}', '\u{12474}'),
+        ('\u{12ff1}', '\u{12ff2}'), ('\u{16a6e}', '\u{16a6f}'), ('\u{16af5}', '\u{16af5}'),
+        ('\u{16b37}', '\u{16b3b}'), ('\u{16b44}', '\u{16b44}'), ('\u{16d6d}', '\u{16d6f}'),
+        ('\u{16e97}', '\u{16e9a}'), ('\u{16fe2}', '\u{16fe2}'), ('\u{1bc9f}', '\u{1bc9f}'),
+        ('\u{1da87}', '\u{1da8b}'), ('\u{1e5ff}', '\u{1e5ff}'), ('\u{1e95e}', '\u{1e95f}')
+    ];
+
+    #[inline]
+    pub fn P(c: char) -> bool {
+        super::util::bsearch_range_table(c, P_table)
+    }
+
+    const Pi_table: &[(char, char)] = &[
+        ('\u{ab}', '\u{ab}'), ('\u{2018}', '\u{2018}'), ('\u{201b}', '\u{201c}'), ('\u{201f}',
+        '\u{201f}'), ('\u{2039}', '\u{2039}'), ('\u{2e02}', '\u{2e02}'), ('\u{2e04}', '\u{2e04}'),
+        ('\u{2e09}', '\u{2e09}'), ('\u{2e0c}', '\u{2e0c}'), ('\u{2e1c}', '\u{2e1c}'), ('\u{2e20}',
+        '\u{2e20}')
+    ];
+
+    #[inline]
+    pub fn Pi(c: char) -> bool {
+        super::util::bsearch_range_table(c, Pi_table)
+    }
+
+    const Ps_table: &[(char, char)] = &[
+        ('\u{28}', '\u{28}'), ('\u{5b}', '\u{5b}'), ('\u{7b}', '\u{7b}'), ('\u{f3a}', '\u{f3a}'),
+        ('\u{f3c}', '\u{f3c}'), ('\u{169b}', '\u{169b}'), ('\u{201a}', '\u{201a}'), ('\u{201e}',
+        '\u{201e}'), ('\u{2045}', '\u{2045}'), ('\u{207d}', '\u{207d}'), ('\u{208d}', '\u{208d}'),
+        ('\u{2308}', '\u{2308}'), ('\u{230a}', '\u{230a}'), ('\u{2329}', '\u{2329}'), ('\u{2768}',
+        '\u{2768}'), ('\u{276a}', '\u{276a}'), ('\u{276c}', '\u{276c}'), ('\u{276e}', '\u{276e}'),
+        ('\u{2770}', '\u{2770}'), ('\u{2772}', '\u{2772}'), ('\u{2774}', '\u{2774}'), ('\u{27c5}',
+        '\u{27c5}'), ('\u{27e6}', '\u{27e6}'), ('\u{27e8}', '\u{27e8}'), ('\u{27ea}', '\u{27ea}'),
+        ('\u{27ec}', '\u{27ec}'), ('\u{27ee}', '\u{27ee}'), ('\u{2983}', '\u{2983}'), ('\u{2985}',
+        '\u{2985}'), ('\u{2987}', '\u{2987}'), ('\u{2989}', '\u{2989}'), ('\u{298b}', '\u{298b}'),
+        ('\u{298d}', '\u{298d}'), ('\u{298f}', '\u{298f}'), ('\u{2991}', '\u{2991}'), ('\u{2993}',
+        '\u{2993}'), ('\u{2995}', '\u{2995}'), ('\u{2997}', '\u{2997}'), ('\u{29d8}', '\u{29d8}'),
+        ('\u{29da}', '\u{29da}'), ('\u{29fc}', '\u{29fc}'), ('\u{2e22}', '\u{2e22}'), ('\u{2e24}',
+        '\u{2e24}'), ('\u{2e26}', '\u{2e26}'), ('\u{2e28}', '\u{2e28}'), ('\u{2e42}', '\u{2e42}'),
+        ('\u{2e55}', '\u{2e55}'), ('\u{2e57}', '\u{2e57}'), ('\u{2e59}', '\u{2e59}'), ('\u{2e5b}',
+        '\u{2e5b}'), ('\u{3008}', '\u{3008}'), ('\u{300a}', '\u{300a}'), ('\u{300c}', '\u{300c}'),
+        ('\u{300e}', '\u{300e}'), ('\u{3010}', '\u{3010}'), ('\u{3014}', '\u{3014}'), ('\u{3016}',
+        '\u{3016}'), ('\u{3018}', '\u{3018}'), ('\u{301a}', '\u{301a}'), ('\u{301d}', '\u{301d}'),
+        ('\u{fd3f}', '\u{fd3f}'), ('\u{fe17}', '\u{fe17}'), ('\u{fe35}', '\u{fe35}'), ('\u{fe37}',
+        '\u{fe37}'), ('\u{fe39}', '\u{fe39}'), ('\u{fe3b}', '\u{fe3b}'), ('\u{fe3d}', '\u{fe3d}'),
+        ('\u{fe3f}', '\u{fe3f}'), ('\u{fe41}', '\u{fe41}'), ('\u{fe43}', '\u{fe43}'), ('\u{fe47}',
+        '\u{fe47}'), ('\u{fe59}', '\u{fe59}'), ('\u{fe5b}', '\u{fe5b}'), ('\u{fe5d}', '\u{fe5d}'),
+        ('\u{ff08}', '\u{ff08}'), ('\u{ff3b}', '\u{ff3b}'), ('\u{ff5b}', '\u{ff5b}'), ('\u{ff5f}',
+        '\u{ff5f}'), ('\u{ff62}', '\u{ff62}')
+    ];
+
+    #[inline]
+    pub fn Ps(c: char) -> bool {
+        super::util::bsearch_range_table(c, Ps_table)
+    }
+
 }
 
 pub mod derived_property {
@@ -482,6 +602,343 @@ pub mod derived_property {
 
 }
 
+pub mod bidi_class {
+    const RTL_table: &[(char, char)] = &[
+        ('\u{590}', '\u{590}'), ('\u{5be}', '\u{5be}'), ('\u{5c0}', '\u{5c0}'), ('\u{5c3}',
+        '\u{5c3}'), ('\u{5c6}', '\u{5c6}'), ('\u{5c8}', '\u{5ff}'), ('\u{608}', '\u{608}'),
+        ('\u{60b}', '\u{60b}'), ('\u{60d}', '\u{60d}'), ('\u{61b}', '\u{64a}'), ('\u{66d}',
+        '\u{66f}'), ('\u{671}', '\u{6d5}'), ('\u{6e5}', '\u{6e6}'), ('\u{6ee}', '\u{6ef}'),
+        ('\u{6fa}', '\u{710}'), ('\u{712}', '\u{72f}'), ('\u{74b}', '\u{7a5}'), ('\u{7b1}',
+        '\u{7ea}'), ('\u{7f4}', '\u{7f5}'), ('\u{7fa}', '\u{7fc}'), ('\u{7fe}', '\u{815}'),
+        ('\u{81a}', '\u{81a}'), ('\u{824}', '\u{824}'), ('\u{828}', '\u{828}'), ('\u{82e}',
+        '\u{858}'), ('\u{85c}', '\u{88f}'), ('\u{892}', '\u{896}'), ('\u{8a0}', '\u{8c9}'),
+        ('\u{200f}', '\u{200f}'), ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}',
+        '\u{fd3d}'), ('\u{fd50}', '\u{fdce}'), ('\u{fdf0}', '\u{fdfc}'), ('\u{fe70}', '\u{fefe}'),
+        ('\u{10800}', '\u{1091e}'), ('\u{10920}', '\u{10a00}'), ('\u{10a04}', '\u{10a04}'),
+        ('\u{10a07}', '\u{10a0b}'), ('\u{10a10}', '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'),
+        ('\u{10a40}', '\u{10ae4}'), ('\u{10ae7}', '\u{10b38}'), ('\u{10b40}', '\u{10d23}'),
+        ('\u{10d28}', '\u{10d2f}'), ('\u{10d3a}', '\u{10d3f}'), ('\u{10d4a}', '\u{10d68}'),
+        ('\u{10d6e}', '\u{10e5f}'), ('\u{10e7f}', '\u{10eaa}'), ('\u{10ead}', '\u{10efb}'),
+        ('\u{10f00}', '\u{10f45}'), ('\u{10f51}', '\u{10f81}'), ('\u{10f86}', '\u{10fff}'),
+        ('\u{1e800}', '\u{1e8cf}'), ('\u{1e8d7}', '\u{1e943}'), ('\u{1e94b}', '\u{1eeef}'),
+        ('\u{1eef2}', '\u{1efff}')
+    ];
+
+    #[inline]
+    pub fn RTL(c: char) -> bool {
+        super::util::bsearch_range_table(c, RTL_table)
+    }
+
+}
+
+pub mod east_asian_width {
+    const Wide_table: &[(char, char)] = &[
+        ('\u{1100}', '\u{115f}'), ('\u{231a}', '\u{231b}'), ('\u{2329}', '\u{232a}'), ('\u{23e9}',
+        '\u{23ec}'), ('\u{23f0}', '\u{23f0}'), ('\u{23f3}', '\u{23f3}'), ('\u{25fd}', '\u{25fe}'),
+        ('\u{2614}', '\u{2615}'), ('\u{2630}', '\u{2637}'), ('\u{2648}', '\u{2653}'), ('\u{267f}',
+        '\u{267f}'), ('\u{268a}', '\u{268f}'), ('\u{2693}', '\u{2693}'), ('\u{26a1}', '\u{26a1}'),
+        ('\u{26aa}', '\u{26ab}'), ('\u{26bd}', '\u{26be}'), ('\u{26c4}', '\u{26c5}'), ('\u{26ce}',
+        '\u{26ce}'), ('\u{26d4}', '\u{26d4}'), ('\u{26ea}', '\u{26ea}'), ('\u{26f2}', '\u{26f3}'),
+        ('\u{26f5}', '\u{26f5}'), ('\u{26fa}', '\u{26fa}'), ('\u{26fd}', '\u{26fd}'), ('\u{2705}',
+        '\u{2705}'), ('\u{270a}', '\u{270b}'), ('\u{2728}', '\u{2728}'), ('\u{274c}', '\u{274c}'),
+        ('\u{274e}', '\u{274e}'), ('\u{2753}', '\u{2755}'), ('\u{2757}', '\u{2757}'), ('\u{2795}',
+        '\u{2797}'), ('\u{27b0}', '\u{27b0}'), ('\u{27bf}', '\u{27bf}'), ('\u{2b1b}', '\u{2b1c}'),
+        ('\u{2b50}', '\u{2b50}'), ('\u{2b55}', '\u{2b55}'), ('\u{2e80}', '\u{2e99}'), ('\u{2e9b}',
+        '\u{2ef3}'), ('\u{2f00}', '\u{2fd5}'), ('\u{2ff0}', '\u{303e}'), ('\u{3041}', '\u{3096}'),
+        ('\u{3099}', '\u{30ff}'), ('\u{3105}', '\u{312f}'), ('\u{3131}', '\u{318e}'), ('\u{3190}',
+        '\u{31e5}'), ('\u{31ef}', '\u{321e}'), ('\u{3220}', '\u{3247}'), ('\u{3250}', '\u{a48c}'),
+        ('\u{a490}', '\u{a4c6}'), ('\u{a960}', '\u{a97c}'), ('\u{ac00}', '\u{d7a3}'), ('\u{f900}',
+        '\u{faff}'), ('\u{fe10}', '\u{fe19}'), ('\u{fe30}', '\u{fe52}'), ('\u{fe54}', '\u{fe66}'),
+        ('\u{fe68}', '\u{fe6b}'), ('\u{ff01}', '\u{ff60}'), ('\u{ffe0}', '\u{ffe6}'), ('\u{16fe0}',
+        '\u{16fe4}'), ('\u{16ff0}', '\u{16ff6}'), ('\u{17000}', '\u{18cd5}'), ('\u{18cff}',
+        '\u{18d1e}'), ('\u{18d80}', '\u{18df2}'), ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}',
+        '\u{1affb}'), ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b122}'), ('\u{1b132}',
+        '\u{1b132}'), ('\u{1b150}', '\u{1b152}'), ('\u{1b155}', '\u{1b155}'), ('\u{1b164}',
+        '\u{1b167}'), ('\u{1b170}', '\u{1b2fb}'), ('\u{1d300}', '\u{1d356}'), ('\u{1d360}',
+        '\u{1d376}'), ('\u{1f004}', '\u{1f004}'), ('\u{1f0cf}', '\u{1f0cf}'), ('\u{1f18e}',
+        '\u{1f18e}'), ('\u{1f191}', '\u{1f19a}'), ('\u{1f200}', '\u{1f202}'), ('\u{1f210}',
+        '\u{1f23b}'), ('\u{1f240}', '\u{1f248}'), ('\u{1f250}', '\u{1f251}'), ('\u{1f260}',
+        '\u{1f265}'), ('\u{1f300}', '\u{1f320}'), ('\u{1f32d}', '\u{1f335}'), ('\u{1f337}',
+        '\u{1f37c}'), ('\u{1f37e}', '\u{1f393}'), ('\u{1f3a0}', '\u{1f3ca}'), ('\u{1f3cf}',
+        '\u{1f3d3}'), ('\u{1f3e0}', '\u{1f3f0}'), ('\u{1f3f4}', '\u{1f3f4}'), ('\u{1f3f8}',
+        '\u{1f43e}'), ('\u{1f440}', '\u{1f440}'), ('\u{1f442}', '\u{1f4fc}'), ('\u{1f4ff}',
+        '\u{1f53d}'), ('\u{1f54b}', '\u{1f54e}'), ('\u{1f550}', '\u{1f567}'), ('\u{1f57a}',
+        '\u{1f57a}'), ('\u{1f595}', '\u{1f596}'), ('\u{1f5a4}', '\u{1f5a4}'), ('\u{1f5fb}',
+        '\u{1f64f}'), ('\u{1f680}', '\u{1f6c5}'), ('\u{1f6cc}', '\u{1f6cc}'), ('\u{1f6d0}',
+        '\u{1f6d2}'), ('\u{1f6d5}', '\u{1f6d8}'), ('\u{1f6dc}', '\u{1f6df}'), ('\u{1f6eb}',
+        '\u{1f6ec}'), ('\u{1f6f4}', '\u{1f6fc}'), ('\u{1f7e0}', '\u{1f7eb}'), ('\u{1f7f0}',
+        '\u{1f7f0}'), ('\u{1f90c}', '\u{1f93a}'), ('\u{1f93c}', '\u{1f945}'), ('\u{1f947}',
+        '\u{1f9ff}'), ('\u{1fa70}', '\u{1fa7c}'), ('\u{1fa80}', '\u{1fa8a}'), ('\u{1fa8e}',
+        '\u{1fac6}'), ('\u{1fac8}', '\u{1fac8}'), ('\u{1facd}', '\u{1fadc}'), ('\u{1fadf}',
+        '\u{1faea}'), ('\u{1faef}', '\u{1faf8}'), ('\u{20000}', '\u{2fffd}'), ('\u{30000}',
+        '\u{3fffd}')
+    ];
+
+    #[inline]
+    pub fn Wide(c: char) -> bool {
+        super::util::bsearch_range_table(c, Wide_table)
+    }
+
+}
+
+#[cfg(feature = "alloc")]
+pub mod canonical_combining_class {
+    const combining_class_table: &[(char, char, u8)] = &[
+        ('\u{300}', '\u{314}', 230), ('\u{315}', '\u{315}', 232), ('\u{316}', '\u{319}', 220),
+        ('\u{31a}', '\u{31a}', 232), ('\u{31b}', '\u{31b}', 216), ('\u{31c}', '\u{320}', 220),
+        ('\u{321}', '\u{322}', 202), ('\u{323}', '\u{326}', 220), ('\u{327}', '\u{328}', 202),
+        ('\u{329}', '\u{333}', 220), ('\u{334}', '\u{338}', 1), ('\u{339}', '\u{33c}', 220),
+        ('\u{33d}', '\u{344}', 230), ('\u{345}', '\u{345}', 240), ('\u{346}', '\u{346}', 230),
+        ('\u{347}', '\u{349}', 220), ('\u{34a}', '\u{34c}', 230), ('\u{34d}', '\u{34e}', 220),
+        ('\u{350}', '\u{352}', 230), ('\u{353}', '\u{356}', 220), ('\u{357}', '\u{357}', 230),
+        ('\u{358}', '\u{358}', 232), ('\u{359}', '\u{35a}', 220), ('\u{35b}', '\u{35b}', 230),
+        ('\u{35c}', '\u{35c}', 233), ('\u{35d}', '\u{35e}', 234), ('\u{35f}', '\u{35f}', 233),
+        ('\u{360}', '\u{361}', 234), ('\u{362}', '\u{362}', 233), ('\u{363}', '\u{36f}', 230),
+        ('\u{483}', '\u{487}', 230), ('\u{591}', '\u{591}', 220), ('\u{592}', '\u{595}', 230),
+        ('\u{596}', '\u{596}', 220), ('\u{597}', '\u{599}', 230), ('\u{59a}', '\u{59a}', 222),
+        ('\u{59b}', '\u{59b}', 220), ('\u{59c}', '\u{5a1}', 230), ('\u{5a2}', '\u{5a7}', 220),
+        ('\u{5a8}', '\u{5a9}', 230), ('\u{5aa}', '\u{5aa}', 220), ('\u{5ab}', '\u{5ac}', 230),
+        ('\u{5ad}', '\u{5ad}', 222), ('\u{5ae}', '\u{5ae}', 228), ('\u{5af}', '\u{5af}', 230),
+        ('\u{5b0}', '\u{5b0}', 10), ('\u{5b1}', '\u{5b1}', 11), ('\u{5b2}', '\u{5b2}', 12),
+        ('\u{5b3}', '\u{5b3}', 13), ('\u{5b4}', '\u{5b4}', 14), ('\u{5b5}', '\u{5b5}', 15),
+        ('\u{5b6}', '\u{5b6}', 16), ('\u{5b7}', '\u{5b7}', 17), ('\u{5b8}', '\u{5b8}', 18),
+        ('\u{5b9}', '\u{5ba}', 19), ('\u{5bb}', '\u{5bb}', 20), ('\u{5bc}', '\u{5bc}', 21),
+        ('\u{5bd}', '\u{5bd}', 22), ('\u{5bf}', '\u{5bf}', 23), ('\u{5c1}', '\u{5c1}', 24),
+        ('\u{5c2}', '\u{5c2}', 25), ('\u{5c4}', '\u{5c4}', 230), ('\u{5c5}', '\u{5c5}', 220),
+        ('\u{5c7}', '\u{5c7}', 18), ('\u{610}', '\u{617}', 230), ('\u{618}', '\u{618}', 30),
+        ('\u{619}', '\u{619}', 31), ('\u{61a}', '\u{61a}', 32), ('\u{64b}', '\u{64b}', 27),
+        ('\u{64c}', '\u{64c}', 28), ('\u{64d}', '\u{64d}', 29), ('\u{64e}', '\u{64e}', 30),
+        ('\u{64f}', '\u{64f}', 31), ('\u{650}', '\u{650}', 32), ('\u{651}', '\u{651}', 33),
+        ('\u{652}', '\u{652}', 34), ('\u{653}', '\u{654}', 230), ('\u{655}', '\u{656}', 220),
+        ('\u{657}', '\u{65b}', 230), ('\u{65c}', '\u{65c}', 220), ('\u{65d}', '\u{65e}', 230),
+        ('\u{65f}', '\u{65f}', 220), ('\u{670}', '\u{670}', 35), ('\u{6d6}', '\u{6dc}', 230),
+        ('\u{6df}', '\u{6e2}', 230), ('\u{6e3}', '\u{6e3}', 220), ('\u{6e4}', '\u{6e4}', 230),
+        ('\u{6e7}', '\u{6e8}', 230), ('\u{6ea}', '\u{6ea}', 220), ('\u{6eb}', '\u{6ec}', 230),
+        ('\u{6ed}', '\u{6ed}', 220), ('\u{711}', '\u{711}', 36), ('\u{730}', '\u{730}', 230),
+        ('\u{731}', '\u{731}', 220), ('\u{732}', '\u{733}', 230), ('\u{734}', '\u{734}', 220),
+        ('\u{735}', '\u{736}', 230), ('\u{737}', '\u{739}', 220), ('\u{73a}', '\u{73a}', 230),
+        ('\u{73b}', '\u{73c}', 220), ('\u{73d}', '\u{73d}', 230), ('\u{73e}', '\u{73e}', 220),
+        ('\u{73f}', '\u{741}', 230), ('\u{742}', '\u{742}', 220), ('\u{743}', '\u{743}', 230),
+        ('\u{744}', '\u{744}', 220), ('\u{745}', '\u{745}', 230), ('\u{746}', '\u{746}', 220),
+        ('\u{747}', '\u{747}', 230), ('\u{748}', '\u{748}', 220), ('\u{749}', '\u{74a}', 230),
+        ('\u{7eb}', '\u{7f1}', 230), ('\u{7f2}', '\u{7f2}', 220), ('\u{7f3}', '\u{7f3}', 230),
+        ('\u{7fd}', '\u{7fd}', 220), ('\u{816}', '\u{819}', 230), ('\u{81b}', '\u{823}', 230),
+        ('\u{825}', '\u{827}', 230), ('\u{829}', '\u{82d}', 230), ('\u{859}', '\u{85b}', 220),
+        ('\u{897}', '\u{898}', 230), ('\u{899}', '\u{89b}', 220), ('\u{89c}', '\u{89f}', 230),
+        ('\u{8ca}', '\u{8ce}', 230), ('\u{8cf}', '\u{8d3}', 220), ('\u{8d4}', '\u{8e1}', 230),
+        ('\u{8e3}', '\u{8e3}', 220), ('\u{8e4}', '\u{8e5}', 230), ('\u{8e6}', '\u{8e6}', 220),
+        ('\u{8e7}', '\u{8e8}', 230), ('\u{8e9}', '\u{8e9}', 220), ('\u{8ea}', '\u{8ec}', 230),
+        ('\u{8ed}', '\u{8ef}', 220), ('\u{8f0}', '\u{8f0}', 27), ('\u{8f1}', '\u{8f1}', 28),
+        ('\u{8f2}', '\u{8f2}', 29), ('\u{8f3}', '\u{8f5}', 230), ('\u{8f6}', '\u{8f6}', 220),
+        ('\u{8f7}', '\u{8f8}', 230), ('\u{8f9}', '\u{8fa}', 220), ('\u{8fb}', '\u{8ff}', 230),
+        ('\u{93c}', '\u{93c}', 7), ('\u{94d}', '\u{94d}', 9), ('\u{951}', '\u{951}', 230),
+        ('\u{952}', '\u{952}', 220), ('\u{953}', '\u{954}', 230), ('\u{9bc}', '\u{9bc}', 7),
+        ('\u{9cd}', '\u{9cd}', 9), ('\u{9fe}', '\u{9fe}', 230), ('\u{a3c}', '\u{a3c}', 7),
+        ('\u{a4d}', '\u{a4d}', 9), ('\u{abc}', '\u{abc}', 7), ('\u{acd}', '\u{acd}', 9), ('\u{b3c}',
+        '\u{b3c}', 7), ('\u{b4d}', '\u{b4d}', 9), ('\u{bcd}', '\u{bcd}', 9), ('\u{c3c}', '\u{c3c}',
+        7), ('\u{c4d}', '\u{c4d}', 9), ('\u{c55}', '\u{c55}', 84), ('\u{c56}', '\u{c56}', 91),
+        ('\u{cbc}', '\u{cbc}', 7), ('\u{ccd}', '\u{ccd}', 9), ('\u{d3b}', '\u{d3c}', 9), ('\u{d4d}',
+        '\u{d4d}', 9), ('\u{dca}', '\u{dca}', 9), ('\u{e38}', '\u{e39}', 103), ('\u{e3a}',
+        '\u{e3a}', 9), ('\u{e48}', '\u{e4b}', 107), ('\u{eb8}', '\u{eb9}', 118), ('\u{eba}',
+        '\u{eba}', 9), ('\u{ec8}', '\u{ecb}', 122), ('\u{f18}', '\u{f19}', 220), ('\u{f35}',
+        '\u{f35}', 220), ('\u{f37}', '\u{f37}', 220), ('\u{f39}', '\u{f39}', 216), ('\u{f71}',
+        '\u{f71}', 129), ('\u{f72}', '\u{f72}', 130), ('\u{f74}', '\u{f74}', 132), ('\u{f7a}',
+        '\u{f7d}', 130), ('\u{f80}', '\u{f80}', 130), ('\u{f82}', '\u{f83}', 230), ('\u{f84}',
+        '\u{f84}', 9), ('\u{f86}', '\u{f87}', 230), ('\u{fc6}', '\u{fc6}', 220), ('\u{1037}',
+        '\u{1037}', 7), ('\u{1039}', '\u{103a}', 9), ('\u{108d}', '\u{108d}', 220), ('\u{135d}',
+        '\u{135f}', 230), ('\u{1714}', '\u{1715}', 9), ('\u{1734}', '\u{1734}', 9), ('\u{17d2}',
+        '\u{17d2}', 9), ('\u{17dd}', '\u{17dd}', 230), ('\u{18a9}', '\u{18a9}', 228), ('\u{1939}',
+        '\u{1939}', 222), ('\u{193a}', '\u{193a}', 230), ('\u{193b}', '\u{193b}', 220), ('\u{1a17}',
+        '\u{1a17}', 230), ('\u{1a18}', '\u{1a18}', 220), ('\u{1a60}', '\u{1a60}', 9), ('\u{1a75}',
+        '\u{1a7c}', 230), ('\u{1a7f}', '\u{1a7f}', 220), ('\u{1ab0}', '\u{1ab4}', 230), ('\u{1ab5}',
+        '\u{1aba}', 220), ('\u{1abb}', '\u{1abc}', 230), ('\u{1abd}', '\u{1abd}', 220), ('\u{1abf}',
+        '\u{1ac0}', 220), ('\u{1ac1}', '\u{1ac2}', 230), ('\u{1ac3}', '\u{1ac4}', 220), ('\u{1ac5}',
+        '\u{1ac9}', 230), ('\u{1aca}', '\u{1aca}', 220), ('\u{1acb}', '\u{1ace}', 230), ('\u{1b34}',
+        '\u{1b34}', 7), ('\u{1b44}', '\u{1b44}', 9), ('\u{1b6b}', '\u{1b6b}', 230), ('\u{1b6c}',
+        '\u{1b6c}', 220), ('\u{1b6d}', '\u{1b73}', 230), ('\u{1baa}', '\u{1bab}', 9), ('\u{1be6}',
+        '\u{1be6}', 7), ('\u{1bf2}', '\u{1bf3}', 9), ('\u{1c37}', '\u{1c37}', 7), ('\u{1cd0}',
+        '\u{1cd2}', 230), ('\u{1cd4}', '\u{1cd4}', 1), ('\u{1cd5}', '\u{1cd9}', 220), ('\u{1cda}',
+        '\u{1cdb}', 230), ('\u{1cdc}', '\u{1cdf}', 220), ('\u{1ce0}', '\u{1ce0}', 230), ('\u{1ce2}',
+        '\u{1ce8}', 1), ('\u{1ced}', '\u{1ced}', 220), ('\u{1cf4}', '\u{1cf4}', 230), ('\u{1cf8}',
+        '\u{1cf9}', 230), ('\u{1dc0}', '\u{1dc1}', 230), ('\u{1dc2}', '\u{1dc2}', 220), ('\u{1dc3}',
+        '\u{1dc9}', 230), ('\u{1dca}', '\u{1dca}', 220), ('\u{1dcb}', '\u{1dcc}', 230), ('\u{1dcd}',
+        '\u{1dcd}', 234), ('\u{1dce}', '\u{1dce}', 214), ('\u{1dcf}', '\u{1dcf}', 220), ('\u{1dd0}',
+        '\u{1dd0}', 202), ('\u{1dd1}', '\u{1df5}', 230), ('\u{1df6}', '\u{1df6}', 232), ('\u{1df7}',
+        '\u{1df8}', 228), ('\u{1df9}', '\u{1df9}', 220), ('\u{1dfa}', '\u{1dfa}', 218), ('\u{1dfb}',
+        '\u{1dfb}', 230), ('\u{1dfc}', '\u{1dfc}', 233), ('\u{1dfd}', '\u{1dfd}', 220), ('\u{1dfe}',
+        '\u{1dfe}', 230), ('\u{1dff}', '\u{1dff}', 220), ('\u{20d0}', '\u{20d1}', 230), ('\u{20d2}',
+        '\u{20d3}', 1), ('\u{20d4}', '\u{20d7}', 230), ('\u{20d8}', '\u{20da}', 1), ('\u{20db}',
+        '\u{20dc}', 230), ('\u{20e1}', '\u{20e1}', 230), ('\u{20e5}', '\u{20e6}', 1), ('\u{20e7}',
+        '\u{20e7}', 230), ('\u{20e8}', '\u{20e8}', 220), ('\u{20e9}', '\u{20e9}', 230), ('\u{20ea}',
+        '\u{20eb}', 1), ('\u{20ec}', '\u{20ef}', 220), ('\u{20f0}', '\u{20f0}', 230), ('\u{2cef}',
+        '\u{2cf1}', 230), ('\u{2d7f}', '\u{2d7f}', 9), ('\u{2de0}', '\u{2dff}', 230), ('\u{302a}',
+        '\u{302a}', 218), ('\u{302b}', '\u{302b}', 228), ('\u{302c}', '\u{302c}', 232), ('\u{302d}',
+        '\u{302d}', 222), ('\u{302e}', '\u{302f}', 224), ('\u{3099}', '\u{309a}', 8), ('\u{a66f}',
+        '\u{a66f}', 230), ('\u{a674}', '\u{a67d}', 230), ('\u{a69e}', '\u{a69f}', 230), ('\u{a6f0}',
+        '\u{a6f1}', 230), ('\u{a806}', '\u{a806}', 9), ('\u{a82c}', '\u{a82c}', 9), ('\u{a8c4}',
+        '\u{a8c4}', 9), ('\u{a8e0}', '\u{a8f1}', 230), ('\u{a92b}', '\u{a92d}', 220), ('\u{a953}',
+        '\u{a953}', 9), ('\u{a9b3}', '\u{a9b3}', 7), ('\u{a9c0}', '\u{a9c0}', 9), ('\u{aab0}',
+        '\u{aab0}', 230), ('\u{aab2}', '\u{aab3}', 230), ('\u{aab4}', '\u{aab4}', 220), ('\u{aab7}',
+        '\u{aab8}', 230), ('\u{aabe}', '\u{aabf}', 230), ('\u{aac1}', '\u{aac1}', 230), ('\u{aaf6}',
+        '\u{aaf6}', 9), ('\u{abed}', '\u{abed}', 9), ('\u{fb1e}', '\u{fb1e}', 26), ('\u{fe20}',
+        '\u{fe26}', 230), ('\u{fe27}', '\u{fe2d}', 220), ('\u{fe2e}', '\u{fe2f}', 230),
+        ('\u{101fd}', '\u{101fd}', 220), ('\u{102e0}', '\u{102e0}', 220), ('\u{10376}', '\u{1037a}',
+        230), ('\u{10a0d}', '\u{10a0d}', 220), ('\u{10a0f}', '\u{10a0f}', 230), ('\u{10a38}',
+        '\u{10a38}', 230), ('\u{10a39}', '\u{10a39}', 1), ('\u{10a3a}', '\u{10a3a}', 220),
+        ('\u{10a3f}', '\u{10a3f}', 9), ('\u{10ae5}', '\u{10ae5}', 230), ('\u{10ae6}', '\u{10ae6}',
+        220), ('\u{10d24}', '\u{10d27}', 230), ('\u{10d69}', '\u{10d6d}', 230), ('\u{10eab}',
+        '\u{10eac}', 230), ('\u{10efd}', '\u{10eff}', 220), ('\u{10f46}', '\u{10f47}', 220),
+        ('\u{10f48}', '\u{10f4a}', 230), ('\u{10f4b}', '\u{10f4b}', 220), ('\u{10f4c}', '\u{10f4c}',
+        230), ('\u{10f4d}', '\u{10f50}', 220), ('\u{10f82}', '\u{10f82}', 230), ('\u{10f83}',
+        '\u{10f83}', 220), ('\u{10f84}', '\u{10f84}', 230), ('\u{10f85}', '\u{10f85}', 220),
+        ('\u{11046}', '\u{11046}', 9), ('\u{11070}', '\u{11070}', 9), ('\u{1107f}', '\u{1107f}', 9),
+        ('\u{110b9}', '\u{110b9}', 9), ('\u{110ba}', '\u{110ba}', 7), ('\u{11100}', '\u{11102}',
+        230), ('\u{11133}', '\u{11134}', 9), ('\u{11173}', '\u{11173}', 7), ('\u{111c0}',
+        '\u{111c0}', 9), ('\u{111ca}', '\u{111ca}', 7), ('\u{11235}', '\u{11235}', 9), ('\u{11236}',
+        '\u{11236}', 7), ('\u{112e9}', '\u{112e9}', 7), ('\u{112ea}', '\u{112ea}', 9), ('\u{1133b}',
+        '\u{1133c}', 7), ('\u{1134d}', '\u{1134d}', 9), ('\u{11366}', '\u{1136c}', 230),
+        ('\u{11370}', '\u{11374}', 230), ('\u{113ce}', '\u{113d0}', 9), ('\u{11442}', '\u{11442}',
+        9), ('\u{11446}', '\u{11446}', 7), ('\u{1145e}', '\u{1145e}', 230), ('\u{114c2}',
+        '\u{114c2}', 9), ('\u{114c3}', '\u{114c3}', 7), ('\u{115bf}', '\u{115bf}', 9), ('\u{115c0}',
+        '\u{115c0}', 7), ('\u{1163f}', '\u{1163f}', 9), ('\u{116b6}', '\u{116b6}', 9), ('\u{116b7}',
+        '\u{116b7}', 7), ('\u{1172b}', '\u{1172b}', 9), ('\u{11839}', '\u{11839}', 9), ('\u{1183a}',
+        '\u{1183a}', 7), ('\u{1193d}', '\u{1193e}', 9), ('\u{11943}', '\u{11943}', 7), ('\u{119e0}',
+        '\u{119e0}', 9), ('\u{11a34}', '\u{11a34}', 9), ('\u{11a47}', '\u{11a47}', 9), ('\u{11a99}',
+        '\u{11a99}', 9), ('\u{11c3f}', '\u{11c3f}', 9), ('\u{11d42}', '\u{11d42}', 7), ('\u{11d44}',
+        '\u{11d45}', 9), ('\u{11d97}', '\u{11d97}', 9), ('\u{11f41}', '\u{11f42}', 9), ('\u{1612f}',
+        '\u{1612f}', 9), ('\u{16af0}', '\u{16af4}', 1), ('\u{16b30}', '\u{16b36}', 230),
+        ('\u{16ff0}', '\u{16ff1}', 6), ('\u{1bc9e}', '\u{1bc9e}', 1), ('\u{1d165}', '\u{1d166}',
+        216), ('\u{1d167}', '\u{1d169}', 1), ('\u{1d16d}', '\u{1d16d}', 226), ('\u{1d16e}',
+        '\u{1d172}', 216), ('\u{1d17b}', '\u{1d182}', 220), ('\u{1d185}', '\u{1d189}', 230),
+        ('\u{1d18a}', '\u{1d18b}', 220), ('\u{1d1aa}', '\u{1d1ad}', 230), ('\u{1d242}', '\u{1d244}',
+        230), ('\u{1e000}', '\u{1e006}', 230), ('\u{1e008}', '\u{1e018}', 230), ('\u{1e01b}',
+        '\u{1e021}', 230), ('\u{1e023}', '\u{1e024}', 230), ('\u{1e026}', '\u{1e02a}', 230),
+        ('\u{1e08f}', '\u{1e08f}', 230), ('\u{1e130}', '\u{1e136}', 230), ('\u{1e2ae}', '\u{1e2ae}',
+        230), ('\u{1e2ec}', '\u{1e2ef}', 230), ('\u{1e4ec}', '\u{1e4ed}', 232), ('\u{1e4ee}',
+        '\u{1e4ee}', 220), ('\u{1e4ef}', '\u{1e4ef}', 230), ('\u{1e5ee}', '\u{1e5ee}', 230),
+        ('\u{1e5ef}', '\u{1e5ef}', 220), ('\u{1e8d0}', '\u{1e8d6}', 220), ('\u{1e944}', '\u{1e949}',
+        230), ('\u{1e94a}', '\u{1e94a}', 7)
+    ];
+
+    #[inline]
+    pub fn combining_class(c: char) -> u8 {
+        super::util::bsearch_range_value_table(c, combining_class_table).unwrap_or(0)
+    }
+
+}
+
+pub mod script {
+    pub use self::Script::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum Script {
+        Arabic,
+        Armenian,
+        Bengali,
+        Bopomofo,
+        CanadianAboriginal,
+        Cherokee,
+        Common,
+        Coptic,
+        Cyrillic,
+        Devanagari,
+        Ethiopic,
+        Georgian,
+        Greek,
+        Gujarati,
+        Gurmukhi,
+        Han,
+        Hangul,
+        Hebrew,
+        Hiragana,
+        Kannada,
+        Katakana,
+        Khmer,
+        Lao,
+        Latin,
+        Malayalam,
+        Mongolian,
+        Myanmar,
+        Nko,
+        Ogham,
+        Oriya,
+        Runic,
+        Sinhala,
+        Syriac,
+        Tamil,
+        Telugu,
+        Thaana,
+        Thai,
+        Tibetan,
+        Other,
+    }
+
+    const script_table: &[(char, char, Script)] = &[
+        ('\u{41}', '\u{aa}', Latin), ('\u{b5}', '\u{b5}', Common), ('\u{ba}', '\u{2b8}', Latin),
+        ('\u{2b9}', '\u{2d1}', Common), ('\u{2e0}', '\u{2e4}', Latin), ('\u{2ec}', '\u{36f}',
+        Common), ('\u{370}', '\u{373}', Greek), ('\u{374}', '\u{374}', Common), ('\u{376}',
+        '\u{3e1}', Greek), ('\u{3e2}', '\u{3ef}', Coptic), ('\u{3f0}', '\u{3ff}', Greek),
+        ('\u{400}', '\u{52f}', Cyrillic), ('\u{531}', '\u{588}', Armenian), ('\u{5b0}', '\u{5f2}',
+        Hebrew), ('\u{610}', '\u{63f}', Arabic), ('\u{640}', '\u{640}', Common), ('\u{641}',
+        '\u{64a}', Arabic), ('\u{64b}', '\u{655}', Common), ('\u{656}', '\u{66f}', Arabic),
+        ('\u{670}', '\u{670}', Common), ('\u{671}', '\u{6ff}', Arabic), ('\u{710}', '\u{74f}',
+        Syriac), ('\u{750}', '\u{77f}', Arabic), ('\u{780}', '\u{7b1}', Thaana), ('\u{7ca}',
+        '\u{7fa}', Nko), ('\u{860}', '\u{86a}', Syriac), ('\u{870}', '\u{8ff}', Arabic), ('\u{900}',
+        '\u{97f}', Devanagari), ('\u{980}', '\u{9fc}', Bengali), ('\u{a01}', '\u{a75}', Gurmukhi),
+        ('\u{a81}', '\u{afc}', Gujarati), ('\u{b01}', '\u{b71}', Oriya), ('\u{b82}', '\u{bd7}',
+        Tamil), ('\u{c00}', '\u{c63}', Telugu), ('\u{c80}', '\u{cf3}', Kannada), ('\u{d00}',
+        '\u{d7f}', Malayalam), ('\u{d81}', '\u{df3}', Sinhala), ('\u{e01}', '\u{e4d}', Thai),
+        ('\u{e81}', '\u{edf}', Lao), ('\u{f00}', '\u{fbc}', Tibetan), ('\u{1000}', '\u{109d}',
+        Myanmar), ('\u{10a0}', '\u{10ff}', Georgian), ('\u{1100}', '\u{11ff}', Hangul), ('\u{1200}',
+        '\u{138f}', Ethiopic), ('\u{13a0}', '\u{13fd}', Cherokee), ('\u{1401}', '\u{167f}',
+        CanadianAboriginal), ('\u{1681}', '\u{169a}', Ogham), ('\u{16a0}', '\u{16f8}', Runic),
+        ('\u{1780}', '\u{17dc}', Khmer), ('\u{1820}', '\u{18aa}', Mongolian), ('\u{18b0}',
+        '\u{18f5}', CanadianAboriginal), ('\u{1abf}', '\u{1ace}', Common), ('\u{1c80}', '\u{1c8a}',
+        Cyrillic), ('\u{1c90}', '\u{1cbf}', Georgian), ('\u{1ce9}', '\u{1cfa}', Common),
+        ('\u{1d00}', '\u{1d25}', Latin), ('\u{1d26}', '\u{1d2a}', Greek), ('\u{1d2b}', '\u{1d2b}',
+        Cyrillic), ('\u{1d2c}', '\u{1d5c}', Latin), ('\u{1d5d}', '\u{1d61}', Greek), ('\u{1d62}',
+        '\u{1d65}', Latin), ('\u{1d66}', '\u{1d6a}', Greek), ('\u{1d6b}', '\u{1d77}', Latin),
+        ('\u{1d78}', '\u{1d78}', Cyrillic), ('\u{1d79}', '\u{1dbe}', Latin), ('\u{1dbf}',
+        '\u{1dbf}', Greek), ('\u{1dd3}', '\u{1df4}', Common), ('\u{1e00}', '\u{1eff}', Latin),
+        ('\u{1f00}', '\u{1ffc}', Greek), ('\u{2071}', '\u{209c}', Latin), ('\u{2102}', '\u{2124}',
+        Common), ('\u{2126}', '\u{2126}', Greek), ('\u{2128}', '\u{2128}', Common), ('\u{212a}',
+        '\u{212b}', Latin), ('\u{212c}', '\u{2131}', Common), ('\u{2132}', '\u{2132}', Latin),
+        ('\u{2133}', '\u{2149}', Common), ('\u{214e}', '\u{2188}', Latin), ('\u{24b6}', '\u{24e9}',
+        Common), ('\u{2c60}', '\u{2c7f}', Latin), ('\u{2c80}', '\u{2cf3}', Coptic), ('\u{2d00}',
+        '\u{2d2d}', Georgian), ('\u{2d80}', '\u{2dde}', Ethiopic), ('\u{2de0}', '\u{2dff}',
+        Cyrillic), ('\u{2e2f}', '\u{2e2f}', Common), ('\u{3005}', '\u{3005}', Han), ('\u{3006}',
+        '\u{3006}', Common), ('\u{3007}', '\u{3029}', Han), ('\u{3031}', '\u{3035}', Common),
+        ('\u{3038}', '\u{303b}', Han), ('\u{303c}', '\u{303c}', Common), ('\u{3041}', '\u{309f}',
+        Hiragana), ('\u{30a1}', '\u{30fa}', Katakana), ('\u{30fc}', '\u{30fc}', Common),
+        ('\u{30fd}', '\u{30ff}', Katakana), ('\u{3105}', '\u{312f}', Bopomofo), ('\u{3131}',
+        '\u{318e}', Hangul), ('\u{31a0}', '\u{31bf}', Bopomofo), ('\u{31f0}', '\u{31ff}', Katakana),
+        ('\u{3400}', '\u{9fff}', Han), ('\u{a640}', '\u{a69f}', Cyrillic), ('\u{a717}', '\u{a71f}',
+        Common), ('\u{a722}', '\u{a787}', Latin), ('\u{a788}', '\u{a788}', Common), ('\u{a78b}',
+        '\u{a7ff}', Latin), ('\u{a8f2}', '\u{a8ff}', Devanagari), ('\u{a960}', '\u{a97c}', Hangul),
+        ('\u{a9cf}', '\u{a9cf}', Common), ('\u{a9e0}', '\u{a9fe}', Myanmar), ('\u{aa60}',
+        '\u{aa7f}', Myanmar), ('\u{ab01}', '\u{ab2e}', Ethiopic), ('\u{ab30}', '\u{ab64}', Latin),
+        ('\u{ab65}', '\u{ab65}', Greek), ('\u{ab66}', '\u{ab69}', Latin), ('\u{ab70}', '\u{abbf}',
+        Cherokee), ('\u{ac00}', '\u{d7fb}', Hangul), ('\u{f900}', '\u{fad9}', Han), ('\u{fb00}',
+        '\u{fb06}', Latin), ('\u{fb13}', '\u{fb17}', Armenian), ('\u{fb1d}', '\u{fb4f}', Hebrew),
+        ('\u{fb50}', '\u{fefc}', Arabic), ('\u{ff21}', '\u{ff5a}', Latin), ('\u{ff66}', '\u{ff6f}',
+        Katakana), ('\u{ff70}', '\u{ff70}', Common), ('\u{ff71}', '\u{ff9d}', Katakana),
+        ('\u{ff9e}', '\u{ff9f}', Common), ('\u{ffa0}', '\u{ffdc}', Hangul), ('\u{10140}',
+        '\u{10174}', Greek), ('\u{10780}', '\u{107ba}', Latin), ('\u{10ec2}', '\u{10efc}', Arabic),
+        ('\u{11ab0}', '\u{11abf}', CanadianAboriginal), ('\u{16fe3}', '\u{16ff1}', Han),
+        ('\u{1aff0}', '\u{1b000}', Katakana), ('\u{1b001}', '\u{1b11f}', Hiragana), ('\u{1b120}',
+        '\u{1b122}', Katakana), ('\u{1b132}', '\u{1b152}', Hiragana), ('\u{1b155}', '\u{1b167}',
+        Katakana), ('\u{1d400}', '\u{1d7cb}', Common), ('\u{1df00}', '\u{1df2a}', Latin),
+        ('\u{1e030}', '\u{1e08f}', Cyrillic), ('\u{1e7e0}', '\u{1e7fe}', Ethiopic), ('\u{1ee00}',
+        '\u{1eebb}', Arabic), ('\u{1f130}', '\u{1f189}', Common), ('\u{20000}', '\u{323af}', Han)
+    ];
+
+    #[inline]
+    pub fn script(c: char) -> Script {
+        super::util::bsearch_range_value_table(c, script_table).unwrap_or(Other)
+    }
+
+}
+
 pub fn is_incb_linker(c: char) -> bool {
     matches!(c, | '\u{94D}' | '\u{9CD}' | '\u{ACD}' | '\u{B4D}' | '\u{C4D}' | '\u{D4D}')
 }
@@ -1242,6 +1699,719 @@ pub mod grapheme {
         GC_Extend), ('\u{e01f0}', '\u{e0fff}', GC_Control)
     ];
 
+    #[inline]
+    pub fn grapheme_category_bmp(c: char) -> Option<GraphemeCat> {
+        let block = *grapheme_bmp_index.get(c as usize >> 5)? as usize;
+        Some(grapheme_bmp_blocks[block << 5 | (c as usize & 0x1f)])
+    }
+
+    const grapheme_bmp_index: &[u8] = &[
+        0, 1, 1, 2, 3, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 5, 5, 5, 6, 1, 1, 1,
+        1, 1, 1, 1, 1, 7, 1, 1, 1, 1, 1, 1, 1, 8, 9, 10, 1, 11, 1, 12, 13, 1, 1, 14, 15, 16, 17, 18,
+        1, 1, 19, 1, 20, 21, 22, 23, 1, 24, 1, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37,
+        38, 39, 40, 41, 42, 31, 43, 44, 45, 46, 47, 48, 1, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58,
+        59, 52, 53, 1, 60, 61, 1, 62, 63, 1, 1, 64, 65, 1, 66, 67, 1, 68, 69, 70, 71, 1, 1, 72, 73,
+        74, 75, 1, 1, 1, 76, 76, 76, 77, 77, 78, 79, 79, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 80, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 81, 82, 83,
+        83, 1, 84, 85, 1, 86, 1, 1, 1, 87, 88, 1, 1, 1, 89, 1, 1, 1, 1, 1, 1, 90, 1, 91, 92, 1, 17,
+        93, 1, 94, 95, 96, 97, 98, 99, 1, 100, 1, 101, 1, 1, 1, 1, 102, 103, 1, 1, 1, 1, 1, 1, 5, 5,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 104, 105, 106, 107, 1, 1, 17, 108, 1, 109,
+        1, 1, 110, 111, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 112, 113, 1, 1, 113, 1, 114, 115, 1, 1, 1, 1,
+        1, 1, 116, 1, 1, 1, 1, 1, 1, 117, 118, 119, 120, 121, 121, 121, 122, 121, 121, 121, 123,
+        124, 125, 126, 127, 128, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 129, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 130, 1, 131, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 132, 1, 1, 1, 133, 1, 1, 1, 5,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 134, 1, 1, 135, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 136, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 137, 138, 1, 1, 139, 1, 1, 1, 1, 1, 1, 1, 1, 140, 141, 1, 1, 142, 143, 144,
+        145, 1, 146, 147, 148, 149, 150, 151, 152, 1, 153, 154, 155, 1, 156, 157, 158, 1, 1, 1, 1,
+        1, 1, 1, 159, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160,
+        161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164,
+        165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161,
+        162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165,
+        166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162,
+        163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166,
+        160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163,
+        164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160,
+        161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164,
+        165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161,
+        162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165,
+        166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162,
+        163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166,
+        160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163,
+        164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160,
+        161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164,
+        165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161,
+        162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165,
+        166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 166, 160, 161, 162,
+        163, 164, 165, 166, 160, 161, 162, 163, 164, 165, 167, 168, 169, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 170, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 6, 6, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 138,
+        1, 1, 171
+    ];
+
+    const grapheme_bmp_blocks: &[GraphemeCat] = &[
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_LF, GC_Control, GC_Control, GC_CR, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any,
+        GC_Control, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Any,
+        GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Prepend, GC_Prepend, GC_Prepend, GC_Prepend, GC_Prepend, GC_Prepend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Control, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Prepend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Prepend, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Prepend,
+        GC_Prepend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Prepend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Extend, GC_SpacingMark,
+        GC_Extend, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any,
+        GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_Any,
+        GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_Any,
+        GC_Any, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_SpacingMark,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any,
+        GC_Extend, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_SpacingMark, GC_Any,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_InCB_Consonant, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any,
+        GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_InCB_Consonant, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Any,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Extend,
+        GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_Extend, GC_SpacingMark,
+        GC_SpacingMark, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant, GC_InCB_Consonant,
+        GC_InCB_Consonant, GC_InCB_Consonant, GC_Extend, GC_Extend, GC_Any, GC_Extend,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Any, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Prepend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Any, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_SpacingMark,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Any, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any,
+        GC_Extend, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any,
+        GC_Any, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L,
+        GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L,
+        GC_L, GC_L, GC_L, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V,
+        GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V,
+        GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Control, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_SpacingMark, GC_Extend, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Extend, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_Extend, GC_SpacingMark, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any,
+        GC_Any, GC_SpacingMark, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Control, GC_Extend, GC_ZWJ, GC_Control, GC_Control, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Extended_Pictographic,
+        GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any,
+        GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Extended_Pictographic,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic,
+        GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extended_Pictographic, GC_Any, GC_Extended_Pictographic, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any,
+        GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark,
+        GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L,
+        GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L,
+        GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_L, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend,
+        GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend, GC_Extend,
+        GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark,
+        GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_SpacingMark, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend, GC_Any, GC_Extend, GC_Extend, GC_Extend,
+        GC_Any, GC_Any, GC_Extend, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Extend,
+        GC_Extend, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_SpacingMark, GC_Extend, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_SpacingMark,
+        GC_SpacingMark, GC_Extend, GC_SpacingMark, GC_SpacingMark, GC_Extend, GC_SpacingMark,
+        GC_SpacingMark, GC_Any, GC_SpacingMark, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_LV, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LV, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LV, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LV, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LV, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LV,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LV, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LV, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT,
+        GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_LVT, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V,
+        GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_V, GC_Any, GC_Any, GC_Any, GC_Any, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T, GC_T,
+        GC_T, GC_T, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Extend, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any,
+        GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Any, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control, GC_Control,
+        GC_Control, GC_Control, GC_Control, GC_Control, GC_Any, GC_Any, GC_Any, GC_Any
+    ];
+
 }
 
 pub mod word {
@@ -0,0 +1,161 @@
+// Copyright 2012-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A coarse display width for grapheme clusters, counted in terminal-style cells.
+
+use core::cmp;
+
+use crate::grapheme::Graphemes;
+use crate::tables::grapheme as gr;
+
+/// The number of display units taken by `cluster`: 0 if it only contains invisible
+/// characters, 2 if it is wide, and 1 otherwise.
+pub fn display_units(cluster: &str) -> usize {
+    let first = match cluster.chars().next() {
+        Some(first) => first,
+        None => return 0,
+    };
+    if cluster.chars().all(is_zero_width) {
+        0
+    } else if is_wide(first)
+        || cluster.contains('\u{fe0f}')
+        || gr::grapheme_category(first).2 == gr::GC_Regional_Indicator && cluster.len() > 4
+    {
+        // an emoji presentation sequence or a flag
+        2
+    } else {
+        1
+    }
+}
+
+/// External iterator for the widest runs of grapheme clusters that fit in a number of display
+/// units.
+///
+/// This struct is created by the [`grapheme_windows`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`grapheme_windows`]: trait.UnicodeSegmentationExt.html#tymethod.grapheme_windows
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct GraphemeWindows<'a> {
+    iter: Graphemes<'a>,
+    max_width: usize,
+}
+
+impl<'a> Iterator for GraphemeWindows<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.iter.as_str();
+        let first = self.iter.next()?;
+        let mut len = first.len();
+        // the first cluster is taken even if it is too wide on its own
+        let mut width = display_units(first);
+        loop {
+            let mut peek = self.iter.clone();
+            match peek.next() {
+                Some(next) if width + display_units(next) <= self.max_width => {
+                    width += display_units(next);
+                    len += next.len();
+                    self.iter = peek;
+                }
+                _ => break,
+            }
+        }
+        Some(&rest[..len])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (cmp::min(lower, 1), upper)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GraphemeWindows<'a> {}
+
+/// The number of display units `s` takes up. See [`UnicodeSegmentationExt::display_unit_count`].
+///
+/// [`UnicodeSegmentationExt::display_unit_count`]: crate::UnicodeSegmentationExt::display_unit_count
+#[inline]
+pub fn display_unit_count(s: &str) -> usize {
+    crate::grapheme::new_graphemes(s, true)
+        .map(display_units)
+        .sum()
+}
+
+/// Returns an iterator over runs of grapheme clusters of `s` at most `max_width` display units
+/// wide. See [`UnicodeSegmentationExt::grapheme_windows`].
+///
+/// [`UnicodeSegmentationExt::grapheme_windows`]: crate::UnicodeSegmentationExt::grapheme_windows
+#[inline]
+pub fn new_grapheme_windows(s: &str, max_width: usize, is_extended: bool) -> GraphemeWindows<'_> {
+    GraphemeWindows {
+        iter: crate::grapheme::new_graphemes(s, is_extended),
+        max_width,
+    }
+}
+
+// Controls, format characters and combining marks, but not whitespace such as tabs.
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        gr::grapheme_category(c).2,
+        gr::GC_Control | gr::GC_Extend | gr::GC_ZWJ
+    ) && !c.is_whitespace()
+}
+
+fn is_wide(c: char) -> bool {
+    c >= '\u{1100}' && crate::tables::east_asian_width::Wide(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{display_units, is_wide};
+
+    #[test]
+    fn test_is_wide() {
+        assert!(!is_wide('a'));
+        assert!(!is_wide('\u{e9}'));
+        assert!(is_wide('\u{1100}'));
+        assert!(is_wide('\u{3000}'));
+        assert!(is_wide('\u{4e00}'));
+        assert!(is_wide('\u{ff21}'));
+        assert!(!is_wide('\u{ff61}'));
+        assert!(is_wide('\u{1f600}'));
+        assert!(!is_wide('\u{2764}'));
+        assert!(!is_wide('\u{1f1e6}'));
+        // unassigned ideographs default to wide
+        assert!(is_wide('\u{2fffd}'));
+    }
+
+    #[test]
+    fn test_display_units() {
+        assert_eq!(display_units(""), 0);
+        assert_eq!(display_units("a"), 1);
+        assert_eq!(display_units("e\u{301}"), 1);
+        assert_eq!(display_units("\u{301}"), 0);
+        assert_eq!(display_units("\u{200b}"), 0);
+        assert_eq!(display_units("\u{200d}"), 0);
+        assert_eq!(display_units("\u{ad}"), 0);
+        assert_eq!(display_units("\r\n"), 1);
+        assert_eq!(display_units("\t"), 1);
+        assert_eq!(display_units("\u{ac00}"), 2);
+        assert_eq!(display_units("\u{1100}\u{1161}"), 2);
+        assert_eq!(display_units("\u{2764}"), 1);
+        assert_eq!(display_units("\u{2764}\u{fe0f}"), 2);
+        assert_eq!(display_units("\u{1f1e6}"), 1);
+        assert_eq!(display_units("\u{1f1fa}\u{1f1f8}"), 2);
+        assert_eq!(
+            display_units("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+            2
+        );
+    }
+}
@@ -10,6 +10,7 @@
 
 use core::cmp;
 use core::iter::Filter;
+use core::ops::{ControlFlow, Range};
 
 use crate::tables::word::WordCat;
 
@@ -43,6 +44,8 @@ impl<'a> Iterator for UnicodeWords<'a> {
         self.inner.size_hint()
     }
 }
+
+impl<'a> core::iter::FusedIterator for UnicodeWords<'a> {}
 impl<'a> DoubleEndedIterator for UnicodeWords<'a> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a str> {
@@ -82,6 +85,8 @@ impl<'a> Iterator for UnicodeWordIndices<'a> {
         self.inner.size_hint()
     }
 }
+
+impl<'a> core::iter::FusedIterator for UnicodeWordIndices<'a> {}
 impl<'a> DoubleEndedIterator for UnicodeWordIndices<'a> {
     #[inline]
     fn next_back(&mut self) -> Option<(usize, &'a str)> {
@@ -89,6 +94,215 @@ impl<'a> DoubleEndedIterator for UnicodeWordIndices<'a> {
     }
 }
 
+/// An iterator over the words of a string whose letters come from more than one script, and
+/// their byte offsets.
+///
+/// This struct is created by the [`mixed_script_words`] method on the [`UnicodeSegmentationExt`]
+/// trait. See its documentation for more.
+///
+/// [`mixed_script_words`]: trait.UnicodeSegmentationExt.html#tymethod.mixed_script_words
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Debug)]
+pub struct MixedScriptWords<'a> {
+    #[allow(clippy::type_complexity)]
+    inner: Filter<UnicodeWordIndices<'a>, fn(&(usize, &str)) -> bool>,
+}
+
+impl<'a> Iterator for MixedScriptWords<'a> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for MixedScriptWords<'a> {}
+
+impl<'a> DoubleEndedIterator for MixedScriptWords<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.inner.next_back()
+    }
+}
+
+/// The [Word_Break](http://www.unicode.org/reports/tr29/#Table_Word_Break_Property_Values)
+/// property of a character, as used by the word segmentation in this crate.
+///
+/// This is returned by [`word_break_property`].
+///
+/// [`word_break_property`]: fn.word_break_property.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordBreakProperty {
+    /// A letter, other than a Hebrew letter or Katakana, such as `a` or `ß`.
+    ALetter,
+    /// U+000D CARRIAGE RETURN.
+    CR,
+    /// U+0022 QUOTATION MARK, `"`.
+    DoubleQuote,
+    /// A combining mark or other character that extends the preceding character.
+    Extend,
+    /// A connector that joins letters and digits into words, such as `_`.
+    ExtendNumLet,
+    /// A format character, such as U+00AD SOFT HYPHEN.
+    Format,
+    /// A Hebrew letter.
+    HebrewLetter,
+    /// A Katakana character.
+    Katakana,
+    /// U+000A LINE FEED.
+    LF,
+    /// A character that joins letters but not digits, such as `:` or `·`.
+    MidLetter,
+    /// A character that joins digits but not letters, such as `,` or `;`.
+    MidNum,
+    /// A character that joins both letters and digits, such as `.` or U+2019 RIGHT SINGLE
+    /// QUOTATION MARK.
+    MidNumLet,
+    /// A line break other than CR and LF, such as U+2028 LINE SEPARATOR.
+    Newline,
+    /// A digit.
+    Numeric,
+    /// A regional indicator symbol, used in pairs for flags.
+    RegionalIndicator,
+    /// U+0027 APOSTROPHE, `'`.
+    SingleQuote,
+    /// A horizontal space, such as U+0020 SPACE.
+    WSegSpace,
+    /// U+200D ZERO WIDTH JOINER.
+    ZWJ,
+    /// Any other character.
+    Other,
+}
+
+impl From<WordCat> for WordBreakProperty {
+    fn from(cat: WordCat) -> WordBreakProperty {
+        match cat {
+            WordCat::WC_ALetter => WordBreakProperty::ALetter,
+            WordCat::WC_CR => WordBreakProperty::CR,
+            WordCat::WC_Double_Quote => WordBreakProperty::DoubleQuote,
+            WordCat::WC_Extend => WordBreakProperty::Extend,
+            WordCat::WC_ExtendNumLet => WordBreakProperty::ExtendNumLet,
+            WordCat::WC_Format => WordBreakProperty::Format,
+            WordCat::WC_Hebrew_Letter => WordBreakProperty::HebrewLetter,
+            WordCat::WC_Katakana => WordBreakProperty::Katakana,
+            WordCat::WC_LF => WordBreakProperty::LF,
+            WordCat::WC_MidLetter => WordBreakProperty::MidLetter,
+            WordCat::WC_MidNum => WordBreakProperty::MidNum,
+            WordCat::WC_MidNumLet => WordBreakProperty::MidNumLet,
+            WordCat::WC_Newline => WordBreakProperty::Newline,
+            WordCat::WC_Numeric => WordBreakProperty::Numeric,
+            WordCat::WC_Regional_Indicator => WordBreakProperty::RegionalIndicator,
+            WordCat::WC_Single_Quote => WordBreakProperty::SingleQuote,
+            WordCat::WC_WSegSpace => WordBreakProperty::WSegSpace,
+            WordCat::WC_ZWJ => WordBreakProperty::ZWJ,
+            WordCat::WC_Any => WordBreakProperty::Other,
+        }
+    }
+}
+
+/// The Word_Break property of `c`, from the same Unicode data the word iterators use.
+///
+/// ```rust
+/// use unicode_segmentation::{word_break_property, WordBreakProperty};
+///
+/// assert_eq!(word_break_property('a'), WordBreakProperty::ALetter);
+/// assert_eq!(word_break_property('7'), WordBreakProperty::Numeric);
+/// assert_eq!(word_break_property('\u{30A2}'), WordBreakProperty::Katakana);
+/// assert_eq!(word_break_property(' '), WordBreakProperty::WSegSpace);
+/// ```
+pub fn word_break_property(c: char) -> WordBreakProperty {
+    crate::tables::word::word_category(c).2.into()
+}
+
+/// The kind of a substring produced by splitting a string on
+/// [word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries), as given by the
+/// [`lex_words`] method on the [`UnicodeSegmentationExt`] trait.
+///
+/// [`lex_words`]: trait.UnicodeSegmentationExt.html#tymethod.lex_words
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordBoundKind {
+    /// A word containing a letter, such as `can't`, `10km` or an ideograph.
+    Word,
+    /// A number with no letters, such as `42` or `3.14`.
+    Number,
+    /// A run of spaces, or a line break.
+    Whitespace,
+    /// A punctuation character, with General_Category P, such as `,`, `(` or `_`.
+    Punctuation,
+    /// Any other character, such as `$`, `+` or an emoji.
+    Symbol,
+}
+
+impl WordBoundKind {
+    // The kind of the UAX#29 segment `s`. Apart from words and numbers, segments only have
+    // more than one character when joined by WB3, WB3d, WB4, WB13a, WB15 or WB16, so the first
+    // character decides.
+    fn of(s: &str) -> WordBoundKind {
+        use crate::tables::general_category::{N, P};
+        use crate::tables::util::is_alphanumeric;
+
+        let mut alphanumeric = s.chars().filter(|&c| is_alphanumeric(c)).peekable();
+        if alphanumeric.peek().is_some() {
+            if alphanumeric.all(N) {
+                WordBoundKind::Number
+            } else {
+                WordBoundKind::Word
+            }
+        } else {
+            match s.chars().next() {
+                Some(c) if c.is_whitespace() => WordBoundKind::Whitespace,
+                Some(c) if P(c) => WordBoundKind::Punctuation,
+                _ => WordBoundKind::Symbol,
+            }
+        }
+    }
+}
+
+/// External iterator for the kinds and byte ranges of the substrings of a string split on
+/// [word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries).
+///
+/// This struct is created by the [`lex_words`] method on the [`UnicodeSegmentationExt`] trait.
+/// See its documentation for more.
+///
+/// [`lex_words`]: trait.UnicodeSegmentationExt.html#tymethod.lex_words
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Debug)]
+pub struct LexWords<'a> {
+    iter: UWordBoundIndices<'a>,
+}
+
+impl<'a> Iterator for LexWords<'a> {
+    type Item = (WordBoundKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(WordBoundKind, Range<usize>)> {
+        let (start, s) = self.iter.next()?;
+        Some((WordBoundKind::of(s), start..start + s.len()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> core::iter::FusedIterator for LexWords<'a> {}
+
+impl<'a> DoubleEndedIterator for LexWords<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(WordBoundKind, Range<usize>)> {
+        let (start, s) = self.iter.next_back()?;
+        Some((WordBoundKind::of(s), start..start + s.len()))
+    }
+}
+
 /// External iterator for a string's
 /// [word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries).
 ///
@@ -102,6 +316,10 @@ pub struct UWordBounds<'a> {
     string: &'a str,
     cat: Option<WordCat>,
     catb: Option<WordCat>,
+    options: WordOptions,
+    // The address of a position known to be preceded by an even number of regional
+    // indicators, so that reverse iteration over a long run of them doesn't rescan it.
+    even_ris_before: Option<usize>,
 }
 
 /// External iterator for word boundaries and byte offsets.
@@ -122,6 +340,7 @@ impl<'a> UWordBoundIndices<'a> {
     /// View the underlying data (the part yet to be iterated) as a slice of the original string.
     ///
     /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
     /// # use unicode_segmentation::UnicodeSegmentation;
     /// let mut iter = "Hello world".split_word_bound_indices();
     /// assert_eq!(iter.as_str(), "Hello world");
@@ -129,6 +348,7 @@ impl<'a> UWordBoundIndices<'a> {
     /// assert_eq!(iter.as_str(), " world");
     /// iter.next();
     /// assert_eq!(iter.as_str(), "world");
+    /// # }
     /// ```
     pub fn as_str(&self) -> &'a str {
         self.iter.as_str()
@@ -151,6 +371,8 @@ impl<'a> Iterator for UWordBoundIndices<'a> {
     }
 }
 
+impl<'a> core::iter::FusedIterator for UWordBoundIndices<'a> {}
+
 impl<'a> DoubleEndedIterator for UWordBoundIndices<'a> {
     #[inline]
     fn next_back(&mut self) -> Option<(usize, &'a str)> {
@@ -160,6 +382,241 @@ impl<'a> DoubleEndedIterator for UWordBoundIndices<'a> {
     }
 }
 
+/// Options tailoring how strings are split on word boundaries.
+///
+/// The default options give the segmentation described in
+/// [UAX#29](http://www.unicode.org/reports/tr29/#Word_Boundaries). Options are enabled with
+/// the builder methods and passed to the [`split_word_bounds_with`] or [`unicode_words_with`]
+/// methods on the [`UnicodeSegmentationExt`] trait.
+///
+/// [`split_word_bounds_with`]: trait.UnicodeSegmentationExt.html#tymethod.split_word_bounds_with
+/// [`unicode_words_with`]: trait.UnicodeSegmentationExt.html#tymethod.unicode_words_with
+/// [`UnicodeSegmentationExt`]: trait.UnicodeSegmentationExt.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WordOptions {
+    join_zwnj: bool,
+    split_alnum_boundaries: bool,
+    attach_open_punct: bool,
+    ideograph_per_char: bool,
+    keep_number_suffix: bool,
+    distinct_whitespace: bool,
+}
+
+impl WordOptions {
+    /// Create options giving the default UAX#29 word segmentation.
+    pub const fn new() -> WordOptions {
+        WordOptions {
+            join_zwnj: false,
+            split_alnum_boundaries: false,
+            attach_open_punct: false,
+            ideograph_per_char: false,
+            keep_number_suffix: false,
+            distinct_whitespace: false,
+        }
+    }
+
+    /// Never split words at a zero width non-joiner (U+200C) or zero width joiner (U+200D)
+    /// that sits between two letters.
+    ///
+    /// Persian and other Arabic-script text uses ZWNJ inside a single word, for example before
+    /// a plural suffix. UAX#29 already ignores these characters between two `ALetter`
+    /// characters (rule WB4), so such words stay whole by default; this option extends the
+    /// same treatment to letters the default rules break between, such as kana and ideographs.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let s = "\u{3072}\u{200c}\u{3089} \u{6a9}\u{62a}\u{627}\u{628}\u{200c}\u{647}\u{627}";
+    /// let words = s.unicode_words_with(WordOptions::new().join_zwnj(true)).collect::<Vec<_>>();
+    /// assert_eq!(words, ["\u{3072}\u{200c}\u{3089}", "\u{6a9}\u{62a}\u{627}\u{628}\u{200c}\u{647}\u{627}"]);
+    /// # }
+    /// ```
+    pub fn join_zwnj(mut self, join_zwnj: bool) -> WordOptions {
+        self.join_zwnj = join_zwnj;
+        self
+    }
+
+    /// Always split words where a letter is directly followed by a digit or a digit by a
+    /// letter.
+    ///
+    /// UAX#29 keeps runs of letters and digits such as `abc123` together, which is not what
+    /// tokenizers for identifiers or units usually want. Combining marks stay with the
+    /// character they follow, and letters and digits separated by other characters are
+    /// unaffected.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let options = WordOptions::new().split_alnum_boundaries(true);
+    /// let words = "abc123def v2 3d".unicode_words_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["abc", "123", "def", "v", "2", "3", "d"]);
+    /// # }
+    /// ```
+    pub fn split_alnum_boundaries(mut self, split_alnum_boundaries: bool) -> WordOptions {
+        self.split_alnum_boundaries = split_alnum_boundaries;
+        self
+    }
+
+    /// When splitting letters from digits with
+    /// [`split_alnum_boundaries`](WordOptions::split_alnum_boundaries), keep a number together
+    /// with a short run of letters directly after it, such as an ordinal suffix or a unit.
+    ///
+    /// UAX#29 already keeps `1st` or `10km` together, so this only has an effect together with
+    /// `split_alnum_boundaries`. It is a heuristic: a run of at most three letters is taken to
+    /// be a suffix, and longer runs are still split off.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let options = WordOptions::new().split_alnum_boundaries(true);
+    /// let words = "1st 10km v2 5apples".unicode_words_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["1", "st", "10", "km", "v", "2", "5", "apples"]);
+    ///
+    /// let options = options.keep_number_suffix(true);
+    /// let words = "1st 10km v2 5apples".unicode_words_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["1st", "10km", "v", "2", "5", "apples"]);
+    /// # }
+    /// ```
+    pub fn keep_number_suffix(mut self, keep_number_suffix: bool) -> WordOptions {
+        self.keep_number_suffix = keep_number_suffix;
+        self
+    }
+
+    /// Attach a run of opening punctuation, such as `(`, `[` or `«`, to the word that
+    /// immediately follows it.
+    ///
+    /// Opening punctuation is anything in the `Ps` (open) or `Pi` (initial quote) general
+    /// categories, plus the ASCII quotation mark `"`, which is commonly used as an opening
+    /// quote. The run is only attached if it is directly followed by a segment containing a
+    /// letter or digit; closing punctuation is never attached.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentationExt, WordOptions};
+    /// let options = WordOptions::new().attach_open_punct(true);
+    /// let words = "(\"quoted\") [ x] «oui»".split_word_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["(\"quoted", "\"", ")", " ", "[", " ", "x", "]", " ", "«oui", "»"]);
+    /// # }
+    /// ```
+    pub fn attach_open_punct(mut self, attach_open_punct: bool) -> WordOptions {
+        self.attach_open_punct = attach_open_punct;
+        self
+    }
+
+    /// Make every Han character its own segment, together with any combining marks that
+    /// follow it.
+    ///
+    /// UAX#29 already breaks around ideographs such as `中`, which is how CJK word counters
+    /// usually count them, but treats the iteration marks `々` (U+3005), `〻` (U+303B) and
+    /// U+16FE3 as letters that join each other and neighbouring letters of any script. This
+    /// option breaks around those as well. Kana, Hangul and all other text are segmented as
+    /// usual.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentation, UnicodeSegmentationExt, WordOptions};
+    /// let s = "人々はcafé々で";
+    /// assert_eq!(s.unicode_words().collect::<Vec<_>>(), ["人", "々", "は", "café々", "で"]);
+    ///
+    /// let options = WordOptions::new().ideograph_per_char(true);
+    /// let words = s.unicode_words_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["人", "々", "は", "café", "々", "で"]);
+    /// # }
+    /// ```
+    pub fn ideograph_per_char(mut self, ideograph_per_char: bool) -> WordOptions {
+        self.ideograph_per_char = ideograph_per_char;
+        self
+    }
+
+    /// Split a run of horizontal whitespace wherever the whitespace character changes, so
+    /// that each run of the same character is its own segment.
+    ///
+    /// UAX#29 keeps adjacent space characters such as U+0020 and the ideographic space
+    /// U+3000 together (rule WB3d), which loses the distinction layout tools need when
+    /// aligning text. Tabs and line breaks are never joined to spaces, with or without this
+    /// option.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
+    /// # use unicode_segmentation::{UnicodeSegmentation, UnicodeSegmentationExt, WordOptions};
+    /// let s = "a \t  \u{3000}\u{3000} b";
+    /// assert_eq!(
+    ///     s.split_word_bounds().collect::<Vec<_>>(),
+    ///     ["a", " ", "\t", "  \u{3000}\u{3000} ", "b"]
+    /// );
+    ///
+    /// let options = WordOptions::new().distinct_whitespace(true);
+    /// let words = s.split_word_bounds_with(options).collect::<Vec<_>>();
+    /// assert_eq!(words, ["a", " ", "\t", "  ", "\u{3000}\u{3000}", " ", "b"]);
+    /// # }
+    /// ```
+    pub fn distinct_whitespace(mut self, distinct_whitespace: bool) -> WordOptions {
+        self.distinct_whitespace = distinct_whitespace;
+        self
+    }
+
+    #[inline]
+    fn is_default(&self) -> bool {
+        *self == WordOptions::new()
+    }
+
+    // The offset at which the segment `s` should be split, if any. `last` selects the last
+    // rather than the first split point.
+    fn split(&self, s: &str, last: bool) -> Option<usize> {
+        use crate::tables::derived_property::Alphabetic;
+        use crate::tables::general_category::N;
+        use crate::tables::word as wd;
+
+        if self.distinct_whitespace {
+            if let Some(split) = whitespace_split(s, last) {
+                return Some(split);
+            }
+        }
+        if !self.split_alnum_boundaries {
+            return None;
+        }
+        let mut split = None;
+        let mut prev_is_digit = None;
+        for (i, c) in s.char_indices() {
+            let is_digit = match wd::word_category(c).2 {
+                wd::WC_Extend | wd::WC_Format | wd::WC_ZWJ => continue,
+                _ if N(c) => Some(true),
+                _ if Alphabetic(c) => Some(false),
+                _ => None,
+            };
+            if let (Some(prev), Some(cur)) = (prev_is_digit, is_digit) {
+                if prev != cur && !(prev && self.keep_number_suffix && is_suffix(&s[i..])) {
+                    split = Some(i);
+                    if !last {
+                        break;
+                    }
+                }
+            }
+            prev_is_digit = is_digit;
+        }
+        split
+    }
+
+    // Whether the adjacent segments `prev` and `next` produced by the UAX#29 rules should be
+    // emitted as a single segment.
+    fn joins(&self, prev: &str, next: &str) -> bool {
+        use crate::tables::derived_property::Alphabetic;
+
+        if self.join_zwnj {
+            let mut prev_chars = prev.chars().rev();
+            if let (Some('\u{200c}' | '\u{200d}'), Some(first)) =
+                (prev_chars.next(), next.chars().next())
+            {
+                let before = prev_chars.find(|&c| c != '\u{200c}' && c != '\u{200d}');
+                if matches!(before, Some(c) if Alphabetic(c)) && Alphabetic(first) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
 // state machine for word boundary rules
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum UWordBoundsState {
@@ -194,6 +651,99 @@ enum RegionalState {
     Unknown,
 }
 
+// The offset at which the UAX#29 segment `s` changes from one whitespace character to
+// another, if it is a run of whitespace joined by rule WB3d. `last` selects the last rather
+// than the first such offset.
+fn whitespace_split(s: &str, last: bool) -> Option<usize> {
+    use crate::tables::word as wd;
+
+    let mut chars = s.char_indices();
+    let (_, mut prev) = chars.next()?;
+    if wd::word_category(prev).2 != wd::WC_WSegSpace {
+        return None;
+    }
+    let mut split = None;
+    for (i, c) in chars {
+        if wd::word_category(c).2 != wd::WC_WSegSpace {
+            break;
+        }
+        if c != prev {
+            split = Some(i);
+            if !last {
+                break;
+            }
+        }
+        prev = c;
+    }
+    split
+}
+
+// Whether the letters at the start of `s`, which follow a digit, are few enough to be taken
+// as a suffix.
+fn is_suffix(s: &str) -> bool {
+    use crate::tables::derived_property::Alphabetic;
+    use crate::tables::general_category::N;
+    use crate::tables::word as wd;
+
+    let mut letters = 0;
+    for c in s.chars() {
+        match wd::word_category(c).2 {
+            wd::WC_Extend | wd::WC_Format | wd::WC_ZWJ => continue,
+            _ if Alphabetic(c) && !N(c) => letters += 1,
+            _ => break,
+        }
+        if letters > 3 {
+            return false;
+        }
+    }
+    true
+}
+
+// Whether the UAX#29 segment `s` is a single opening punctuation character, possibly followed
+// by characters ignored under rule WB4. Some of those, like U+093F DEVANAGARI VOWEL SIGN I, are
+// alphabetic, and a segment containing one is taken as a word instead, in both directions.
+fn is_open_punct(s: &str) -> bool {
+    use crate::tables::general_category::{Pi, Ps};
+    use crate::tables::word as wd;
+
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c == '"' || Ps(c) || Pi(c))
+        && chars.all(|c| {
+            matches!(
+                wd::word_category(c).2,
+                wd::WC_Extend | wd::WC_Format | wd::WC_ZWJ
+            )
+        })
+        && !has_alphanumeric(&s)
+}
+
+// If `bytes` starts with a run of ASCII spaces followed by an ASCII letter or digit, or with
+// a run of ASCII letters and digits followed by a space, returns the length of that run. It is
+// a whole segment: nothing but another space joins either side of an ASCII space (WB3d), and
+// letters and digits always join each other (WB5, WB8-WB10).
+#[inline]
+fn ascii_space_run(bytes: &[u8]) -> Option<usize> {
+    let first = *bytes.first()?;
+    if first == b' ' {
+        let len = bytes.iter().take_while(|&&b| b == b' ').count();
+        match bytes.get(len) {
+            Some(b) if b.is_ascii_alphanumeric() => Some(len),
+            _ => None,
+        }
+    } else if first.is_ascii_alphanumeric() {
+        let len = bytes
+            .iter()
+            .take_while(|b| b.is_ascii_alphanumeric())
+            .count();
+        match bytes.get(len) {
+            Some(b' ') => Some(len),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
 fn is_emoji(ch: char) -> bool {
     use crate::tables::emoji;
     emoji::emoji_category(ch).2 == emoji::EmojiCat::EC_Extended_Pictographic
@@ -210,6 +760,125 @@ impl<'a> Iterator for UWordBounds<'a> {
 
     #[inline]
     fn next(&mut self) -> Option<&'a str> {
+        if self.options.is_default() {
+            self.next_segment()
+        } else {
+            self.next_tailored()
+        }
+    }
+
+    #[inline]
+    fn count(mut self) -> usize {
+        // Decide on the options once rather than for every segment
+        let mut count = 0;
+        if self.options.is_default() {
+            while self.next_segment().is_some() {
+                count += 1;
+            }
+        } else {
+            while self.next_tailored().is_some() {
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+impl<'a> core::iter::FusedIterator for UWordBounds<'a> {}
+
+impl<'a> DoubleEndedIterator for UWordBounds<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.options.is_default() {
+            self.next_back_segment()
+        } else {
+            self.next_back_tailored()
+        }
+    }
+}
+
+impl<'a> UWordBounds<'a> {
+    // Apply `WordOptions` by joining adjacent UAX#29 segments, then splitting the result.
+    // Joins only ever depend on the pair of segments next to each other, or on a whole run of
+    // opening punctuation and the segment after it, so both directions agree on the result.
+    // Splits restart segmentation at the split point, which is never next to a character the
+    // UAX#29 rules look across.
+    fn next_tailored(&mut self) -> Option<&'a str> {
+        let rest = self.string;
+        let mut last = self.next_segment()?;
+        let mut len = last.len();
+        if self.options.attach_open_punct && is_open_punct(last) {
+            let mut peek = self.clone();
+            let mut run = len;
+            loop {
+                match peek.next_segment() {
+                    Some(next) if is_open_punct(next) => run += next.len(),
+                    Some(next) if has_alphanumeric(&next) => {
+                        len = run + next.len();
+                        last = next;
+                        *self = peek;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        loop {
+            let mut peek = self.clone();
+            match peek.next_segment() {
+                Some(next) if self.options.joins(last, next) => {
+                    len += next.len();
+                    last = next;
+                    *self = peek;
+                }
+                _ => break,
+            }
+        }
+        if let Some(split) = self.options.split(&rest[..len], false) {
+            self.string = &rest[split..];
+            self.cat = None;
+            len = split;
+        }
+        Some(&rest[..len])
+    }
+
+    fn next_back_tailored(&mut self) -> Option<&'a str> {
+        let rest = self.string;
+        let mut first = self.next_back_segment()?;
+        let mut start = rest.len() - first.len();
+        loop {
+            let mut peek = self.clone();
+            match peek.next_back_segment() {
+                Some(prev) if self.options.joins(prev, first) => {
+                    start -= prev.len();
+                    first = prev;
+                    *self = peek;
+                }
+                _ => break,
+            }
+        }
+        if self.options.attach_open_punct && has_alphanumeric(&first) {
+            loop {
+                let mut peek = self.clone();
+                match peek.next_back_segment() {
+                    Some(prev) if is_open_punct(prev) => {
+                        start -= prev.len();
+                        *self = peek;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        if let Some(split) = self.options.split(&rest[start..], true) {
+            start += split;
+            self.string = &rest[..start];
+            self.catb = None;
+        }
+        Some(&rest[start..])
+    }
+
+    #[inline]
+    fn next_segment(&mut self) -> Option<&'a str> {
         use self::FormatExtendType::*;
         use self::UWordBoundsState::*;
         use crate::tables::word as wd;
@@ -217,6 +886,14 @@ impl<'a> Iterator for UWordBounds<'a> {
             return None;
         }
 
+        if self.cat.is_none() {
+            if let Some(len) = ascii_space_run(self.string.as_bytes()) {
+                let retstr = &self.string[..len];
+                self.string = &self.string[len..];
+                return Some(retstr);
+            }
+        }
+
         let mut take_curr = true;
         let mut take_cat = true;
         let mut idx = 0;
@@ -234,7 +911,7 @@ impl<'a> Iterator for UWordBounds<'a> {
             let prev_zwj = cat == wd::WC_ZWJ;
             // if there's a category cached, grab it
             cat = match self.cat {
-                None => wd::word_category(ch).2,
+                None => self.word_category(ch),
                 _ => self.cat.take().unwrap(),
             };
             take_cat = true;
@@ -270,6 +947,10 @@ impl<'a> Iterator for UWordBounds<'a> {
             // When prev_zwj is true, for the purposes of WB3c, we are in the Zwj state,
             // however we are in the previous state for the purposes of all other rules.
             if prev_zwj && is_emoji(ch) {
+                if let FormatExtend(RequireLetter | RequireHLetter | RequireNumeric) = state {
+                    // the ZWJ follows a MidLetter or MidNum that we have to rewind to
+                    break;
+                }
                 state = Emoji;
                 continue;
             }
@@ -434,11 +1115,9 @@ impl<'a> Iterator for UWordBounds<'a> {
         self.string = &self.string[idx..];
         Some(retstr)
     }
-}
 
-impl<'a> DoubleEndedIterator for UWordBounds<'a> {
     #[inline]
-    fn next_back(&mut self) -> Option<&'a str> {
+    fn next_back_segment(&mut self) -> Option<&'a str> {
         use self::FormatExtendType::*;
         use self::UWordBoundsState::*;
         use crate::tables::word as wd;
@@ -457,6 +1136,7 @@ impl<'a> DoubleEndedIterator for UWordBounds<'a> {
         let mut cat = wd::WC_Any;
 
         let mut skipped_format_extend = false;
+        let mut regional = false;
 
         for (curr, ch) in self.string.char_indices().rev() {
             previdx = idx;
@@ -464,7 +1144,7 @@ impl<'a> DoubleEndedIterator for UWordBounds<'a> {
 
             // if there's a category cached, grab it
             cat = match self.catb {
-                None => wd::word_category(ch).2,
+                None => self.word_category(ch),
                 _ => self.catb.take().unwrap(),
             };
             take_cat = true;
@@ -597,7 +1277,20 @@ impl<'a> DoubleEndedIterator for UWordBounds<'a> {
                 Regional(mut regional_state) => match cat {
                     // rule WB13c
                     wd::WC_Regional_Indicator => {
-                        if regional_state == RegionalState::Unknown {
+                        regional = true;
+                        let end = self.string.as_ptr() as usize + self.string.len();
+                        if regional_state == RegionalState::Unknown
+                            && self.even_ris_before == Some(end)
+                            && self.string[previdx..].chars().skip(1).all(|c| {
+                                matches!(
+                                    wd::word_category(c).2,
+                                    wd::WC_Extend | wd::WC_Format | wd::WC_ZWJ
+                                )
+                            })
+                        {
+                            // the regional indicator at `previdx` is the last one before `end`
+                            regional_state = RegionalState::Half;
+                        } else if regional_state == RegionalState::Unknown {
                             let count = self.string[..previdx]
                                 .chars()
                                 .rev()
@@ -670,6 +1363,11 @@ impl<'a> DoubleEndedIterator for UWordBounds<'a> {
             }
         };
 
+        if regional {
+            // an even number of regional indicators precede a pair or an unpaired one
+            self.even_ris_before = Some(self.string.as_ptr() as usize + idx);
+        }
+
         let retstr = &self.string[idx..];
         self.string = &self.string[..idx];
         Some(retstr)
@@ -681,6 +1379,7 @@ impl<'a> UWordBounds<'a> {
     /// View the underlying data (the part yet to be iterated) as a slice of the original string.
     ///
     /// ```rust
+    /// # #[cfg(feature = "str-impl")] {
     /// # use unicode_segmentation::UnicodeSegmentation;
     /// let mut iter = "Hello world".split_word_bounds();
     /// assert_eq!(iter.as_str(), "Hello world");
@@ -688,18 +1387,31 @@ impl<'a> UWordBounds<'a> {
     /// assert_eq!(iter.as_str(), " world");
     /// iter.next();
     /// assert_eq!(iter.as_str(), "world");
+    /// # }
     /// ```
     pub fn as_str(&self) -> &'a str {
         self.string
     }
 
+    // The word break category of `c`, after applying `WordOptions::ideograph_per_char`.
     #[inline]
-    fn get_next_cat(&self, idx: usize) -> Option<WordCat> {
+    fn word_category(&self, c: char) -> WordCat {
+        use crate::tables::script::{script, Han};
         use crate::tables::word as wd;
+        let cat = wd::word_category(c).2;
+        if self.options.ideograph_per_char && cat == wd::WC_ALetter && script(c) == Han {
+            wd::WC_Any
+        } else {
+            cat
+        }
+    }
+
+    #[inline]
+    fn get_next_cat(&self, idx: usize) -> Option<WordCat> {
         let nidx = idx + self.string[idx..].chars().next().unwrap().len_utf8();
         if nidx < self.string.len() {
             let nch = self.string[nidx..].chars().next().unwrap();
-            Some(wd::word_category(nch).2)
+            Some(self.word_category(nch))
         } else {
             None
         }
@@ -707,25 +1419,260 @@ impl<'a> UWordBounds<'a> {
 
     #[inline]
     fn get_prev_cat(&self, idx: usize) -> Option<WordCat> {
-        use crate::tables::word as wd;
         if idx > 0 {
             let nch = self.string[..idx].chars().next_back().unwrap();
-            Some(wd::word_category(nch).2)
+            Some(self.word_category(nch))
         } else {
             None
         }
     }
 }
 
+/// External iterator for the
+/// [word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries) of bytes that are
+/// mostly UTF-8.
+///
+/// This struct is created by the [`word_bounds_bytes`] function. See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct WordBoundsBytes<'a> {
+    rest: &'a [u8],
+    words: UWordBounds<'a>,
+    invalid: usize,
+    window: usize,
+}
+
+impl<'a> Iterator for WordBoundsBytes<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        loop {
+            if let Some(word) = self.words.next_segment() {
+                return Some(word.as_bytes());
+            }
+            if self.invalid > 0 {
+                let (byte, rest) = self.rest.split_at(1);
+                self.rest = rest;
+                self.invalid -= 1;
+                return Some(byte);
+            }
+            if self.rest.is_empty() {
+                return None;
+            }
+            self.refill();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, words) = self.words.size_hint();
+        let len = self.rest.len() + words.unwrap_or(0);
+        (cmp::min(len, 1), Some(len))
+    }
+}
+
+impl<'a> core::iter::FusedIterator for WordBoundsBytes<'a> {}
+
+impl<'a> WordBoundsBytes<'a> {
+    // Validate the next window of `rest`, and queue up the words and invalid bytes at its start
+    // whose boundaries can't be affected by what comes after the window.
+    fn refill(&mut self) {
+        let mut window = self.window;
+        loop {
+            let end = cmp::min(window, self.rest.len());
+            let (valid, invalid) = match core::str::from_utf8(&self.rest[..end]) {
+                Ok(s) => (s, None),
+                Err(e) => {
+                    // the prefix was just validated, so this can't fail
+                    let valid = core::str::from_utf8(&self.rest[..e.valid_up_to()]).unwrap();
+                    let invalid = match e.error_len() {
+                        Some(len) => Some(len),
+                        None if end == self.rest.len() => Some(end - e.valid_up_to()),
+                        None => None,
+                    };
+                    (valid, invalid)
+                }
+            };
+            let complete = if invalid.is_some() || end == self.rest.len() {
+                valid.len()
+            } else {
+                safe_word_cut(valid)
+            };
+            if complete > 0 || invalid.is_some() {
+                self.words = new_word_bounds(&valid[..complete]);
+                self.invalid = if complete == valid.len() {
+                    invalid.unwrap_or(0)
+                } else {
+                    0
+                };
+                self.rest = &self.rest[complete..];
+                return;
+            }
+            window *= 2;
+        }
+    }
+}
+
+// The last offset in `s` at which there's a word boundary whatever text follows `s`, or 0.
+//
+// There is always a boundary after a line feed (WB3a), and before a space unless it follows
+// a carriage return or another space (WB3, WB3d).
+fn safe_word_cut(s: &str) -> usize {
+    use crate::tables::word as wd;
+
+    let bytes = s.as_bytes();
+    let line_start = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    for (i, _) in s[line_start..].match_indices(' ').rev() {
+        let i = line_start + i;
+        match s[..i].chars().next_back() {
+            Some(c) if wd::word_category(c).2 != wd::WC_WSegSpace && c != '\r' => return i,
+            _ => {}
+        }
+    }
+    line_start
+}
+
+/// Whether `s`, a substring produced by splitting on word boundaries, is word-like: whether
+/// the [`unicode_words`] method on the [`UnicodeSegmentation`] trait would return it.
+///
+/// A segment is word-like if it contains any characters with the
+/// [Alphabetic](http://unicode.org/reports/tr44/#Alphabetic) property, or with
+/// [General_Category=Number](http://unicode.org/reports/tr44/#General_Category_Values). So
+/// whitespace, punctuation, symbols, emoji and flags are not.
+///
+/// ```rust
+/// # use unicode_segmentation::is_word_segment;
+/// assert!(is_word_segment("hello"));
+/// assert!(is_word_segment("can't"));
+/// assert!(!is_word_segment(" "));
+/// assert!(!is_word_segment(","));
+/// assert!(!is_word_segment("\n"));
+/// ```
+///
+/// [`unicode_words`]: trait.UnicodeSegmentation.html#tymethod.unicode_words
+/// [`UnicodeSegmentation`]: trait.UnicodeSegmentation.html
+#[inline]
+pub fn is_word_segment(s: &str) -> bool {
+    has_alphanumeric(&s)
+}
+
+/// Returns the byte offset of the first
+/// [UAX#29 word boundary](http://www.unicode.org/reports/tr29/#Word_Boundaries) in `s` that is
+/// strictly greater than `from`, or `None` if `from` is at or past the end of `s`.
+///
+/// `from` doesn't need to be a word boundary or even a `char` boundary. Only the text from
+/// the nearest line break or space before `from` up to the boundary found is scanned, since
+/// the rules never look back across those.
+///
+/// ```rust
+/// # use unicode_segmentation::next_word_boundary_in;
+/// let s = "The quick (\"brown\") fox";
+/// assert_eq!(next_word_boundary_in(s, 0), Some(3));
+/// assert_eq!(next_word_boundary_in(s, 3), Some(4));
+/// assert_eq!(next_word_boundary_in(s, 5), Some(9));
+/// assert_eq!(next_word_boundary_in(s, 20), Some(23));
+/// assert_eq!(next_word_boundary_in(s, 23), None);
+/// ```
+pub fn next_word_boundary_in(s: &str, from: usize) -> Option<usize> {
+    if from >= s.len() {
+        return None;
+    }
+    let mut cut = from;
+    while !s.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    let mut offset = safe_word_cut(&s[..cut]);
+    for word in new_word_bounds(&s[offset..]) {
+        offset += word.len();
+        if offset > from {
+            return Some(offset);
+        }
+    }
+    unreachable!("the end of the string is always a boundary")
+}
+
+/// Returns an iterator over the byte slices of `bytes` separated on
+/// [UAX#29 word boundaries](http://www.unicode.org/reports/tr29/#Word_Boundaries), without
+/// first validating that all of `bytes` is UTF-8.
+///
+/// Runs of valid UTF-8 are split exactly as `split_word_bounds` would split them on their own,
+/// and every byte that isn't part of valid UTF-8 is returned as a segment by itself. The
+/// input is validated a few kilobytes at a time as iteration proceeds, so this is suitable
+/// for scanning large files.
+///
+/// The concatenation of the slices returned by this function is just the original bytes.
+///
+/// ```rust
+/// # use unicode_segmentation::word_bounds_bytes;
+/// let bytes = b"can't stop\xff\xfenow";
+/// let words = word_bounds_bytes(bytes).collect::<Vec<&[u8]>>();
+/// let b: &[&[u8]] = &[b"can't", b" ", b"stop", b"\xff", b"\xfe", b"now"];
+///
+/// assert_eq!(&words[..], b);
+/// ```
+pub fn word_bounds_bytes(bytes: &[u8]) -> WordBoundsBytes<'_> {
+    new_word_bounds_bytes(bytes, 4096)
+}
+
+#[inline]
+fn new_word_bounds_bytes(bytes: &[u8], window: usize) -> WordBoundsBytes<'_> {
+    WordBoundsBytes {
+        rest: bytes,
+        words: new_word_bounds(""),
+        invalid: 0,
+        window,
+    }
+}
+
+/// Returns an iterator over the substrings of `s` separated on word boundaries. See
+/// [`UnicodeSegmentation::split_word_bounds`].
+///
+/// [`UnicodeSegmentation::split_word_bounds`]: crate::UnicodeSegmentation::split_word_bounds
 #[inline]
 pub fn new_word_bounds(s: &str) -> UWordBounds<'_> {
+    new_word_bounds_with(s, WordOptions::new())
+}
+
+/// Returns an iterator over the substrings of `s` separated on word boundaries, as tailored by
+/// `options`. See [`UnicodeSegmentationExt::split_word_bounds_with`].
+///
+/// [`UnicodeSegmentationExt::split_word_bounds_with`]: crate::UnicodeSegmentationExt::split_word_bounds_with
+#[inline]
+pub fn new_word_bounds_with(s: &str, options: WordOptions) -> UWordBounds<'_> {
     UWordBounds {
         string: s,
         cat: None,
         catb: None,
+        options,
+        even_ris_before: None,
+    }
+}
+
+/// Calls `f` with each word boundary of `s`, until it returns `ControlFlow::Break`. See
+/// [`UnicodeSegmentationExt::for_each_word_boundary`].
+///
+/// [`UnicodeSegmentationExt::for_each_word_boundary`]: crate::UnicodeSegmentationExt::for_each_word_boundary
+#[inline]
+pub fn for_each_word_boundary(
+    s: &str,
+    mut f: impl FnMut(usize) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let mut bounds = new_word_bounds(s);
+    if let Some(first) = bounds.next_segment() {
+        f(0)?;
+        let mut offset = first.len();
+        f(offset)?;
+        while let Some(segment) = bounds.next_segment() {
+            offset += segment.len();
+            f(offset)?;
+        }
     }
+    ControlFlow::Continue(())
 }
 
+/// Returns an iterator over the substrings of `s` separated on word boundaries, and their byte
+/// offsets. See [`UnicodeSegmentation::split_word_bound_indices`].
+///
+/// [`UnicodeSegmentation::split_word_bound_indices`]: crate::UnicodeSegmentation::split_word_bound_indices
 #[inline]
 pub fn new_word_bound_indices(s: &str) -> UWordBoundIndices<'_> {
     UWordBoundIndices {
@@ -734,6 +1681,17 @@ pub fn new_word_bound_indices(s: &str) -> UWordBoundIndices<'_> {
     }
 }
 
+/// Returns an iterator over the words of `s` and the kind of each segment between them. See
+/// [`UnicodeSegmentationExt::lex_words`].
+///
+/// [`UnicodeSegmentationExt::lex_words`]: crate::UnicodeSegmentationExt::lex_words
+#[inline]
+pub fn new_lex_words(s: &str) -> LexWords<'_> {
+    LexWords {
+        iter: new_word_bound_indices(s),
+    }
+}
+
 #[inline]
 fn has_alphanumeric(s: &&str) -> bool {
     use crate::tables::util::is_alphanumeric;
@@ -741,23 +1699,60 @@ fn has_alphanumeric(s: &&str) -> bool {
     s.chars().any(is_alphanumeric)
 }
 
+/// Returns an iterator over the words of `s`. See [`UnicodeSegmentation::unicode_words`].
+///
+/// [`UnicodeSegmentation::unicode_words`]: crate::UnicodeSegmentation::unicode_words
 #[inline]
 pub fn new_unicode_words(s: &str) -> UnicodeWords<'_> {
-    use super::UnicodeSegmentation;
+    UnicodeWords {
+        inner: new_word_bounds(s).filter(has_alphanumeric),
+    }
+}
 
+/// Returns an iterator over the words of `s`, as tailored by `options`. See
+/// [`UnicodeSegmentationExt::unicode_words_with`].
+///
+/// [`UnicodeSegmentationExt::unicode_words_with`]: crate::UnicodeSegmentationExt::unicode_words_with
+#[inline]
+pub fn new_unicode_words_with(s: &str, options: WordOptions) -> UnicodeWords<'_> {
     UnicodeWords {
-        inner: s.split_word_bounds().filter(has_alphanumeric),
+        inner: new_word_bounds_with(s, options).filter(has_alphanumeric),
     }
 }
 
+/// Returns an iterator over the words of `s`, split further where the script changes. See
+/// [`UnicodeSegmentationExt::mixed_script_words`].
+///
+/// [`UnicodeSegmentationExt::mixed_script_words`]: crate::UnicodeSegmentationExt::mixed_script_words
 #[inline]
-pub fn new_unicode_word_indices(s: &str) -> UnicodeWordIndices<'_> {
-    use super::UnicodeSegmentation;
+pub fn new_mixed_script_words(s: &str) -> MixedScriptWords<'_> {
+    fn is_mixed(&(_, w): &(usize, &str)) -> bool {
+        crate::script::is_mixed_script(w)
+    }
+    let is_mixed: fn(&(usize, &str)) -> bool = is_mixed; // coerce to fn pointer
 
+    MixedScriptWords {
+        inner: new_unicode_word_indices(s).filter(is_mixed),
+    }
+}
+
+/// The byte offset of the first word of `s`, if there is one. See
+/// [`UnicodeSegmentationExt::first_word_offset`].
+///
+/// [`UnicodeSegmentationExt::first_word_offset`]: crate::UnicodeSegmentationExt::first_word_offset
+#[inline]
+pub fn first_word_offset(s: &str) -> Option<usize> {
+    new_unicode_word_indices(s).next().map(|(i, _)| i)
+}
+
+/// Returns an iterator over the words of `s` and their byte offsets. See
+/// [`UnicodeSegmentation::unicode_word_indices`].
+///
+/// [`UnicodeSegmentation::unicode_word_indices`]: crate::UnicodeSegmentation::unicode_word_indices
+#[inline]
+pub fn new_unicode_word_indices(s: &str) -> UnicodeWordIndices<'_> {
     UnicodeWordIndices {
-        inner: s
-            .split_word_bound_indices()
-            .filter(|(_, c)| has_alphanumeric(c)),
+        inner: new_word_bound_indices(s).filter(|(_, c)| has_alphanumeric(c)),
     }
 }
 
@@ -776,4 +1771,249 @@ mod tests {
         let (_, _, cat) = wd::word_category('\u{6dd}');
         assert_eq!(cat, wd::WC_Numeric);
     }
+
+    #[test]
+    fn test_join_zwnj() {
+        use super::{new_word_bounds, new_word_bounds_with, WordOptions};
+
+        let join = WordOptions::new().join_zwnj(true);
+        let cases: &[(&str, &[&str], &[&str])] = &[
+            // Persian words are already kept whole by WB4
+            (
+                "\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}",
+                &["\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}"],
+                &["\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}"],
+            ),
+            (
+                "\u{3072}\u{200c}\u{3089}",
+                &["\u{3072}\u{200c}", "\u{3089}"],
+                &["\u{3072}\u{200c}\u{3089}"],
+            ),
+            (
+                "\u{6f22}\u{200d}\u{5b57}\u{200c}\u{200c}a",
+                &["\u{6f22}\u{200d}", "\u{5b57}\u{200c}\u{200c}", "a"],
+                &["\u{6f22}\u{200d}\u{5b57}\u{200c}\u{200c}a"],
+            ),
+            // joiners next to non-letters still break
+            (
+                "1\u{200c}\u{3089} \u{200c}\u{3089}",
+                &["1\u{200c}", "\u{3089}", " \u{200c}", "\u{3089}"],
+                &["1\u{200c}", "\u{3089}", " \u{200c}", "\u{3089}"],
+            ),
+        ];
+
+        for &(s, default, joined) in cases {
+            assert!(new_word_bounds(s).eq(default.iter().copied()));
+            assert!(new_word_bounds_with(s, join).eq(joined.iter().copied()));
+            assert!(new_word_bounds_with(s, join)
+                .rev()
+                .eq(joined.iter().rev().copied()));
+        }
+    }
+
+    #[test]
+    fn test_word_bounds_bytes() {
+        use super::{new_word_bounds, new_word_bounds_bytes};
+
+        let strs = [
+            "The quick (\"brown\")  fox can't jump 32.3 feet, right?\r\nNo.",
+            "a:b  c\u{301} d\u{3000} e\u{200d}\u{1f468} \u{1f1e6}\u{1f1e8}\u{1f1e6}\n\n 1,2",
+            "\u{5d0}\"\u{5d1} \r \u{2060} x",
+        ];
+        for s in strs {
+            for window in 1..12 {
+                let bytes = new_word_bounds_bytes(s.as_bytes(), window);
+                assert!(bytes.eq(new_word_bounds(s).map(str::as_bytes)));
+            }
+        }
+
+        let bytes = b"ab\xffc d\xe2\x82 e\xf0\x9f\x98";
+        let expected: [&[u8]; 11] = [
+            b"ab",
+            b"\xff",
+            b"c",
+            b" ",
+            b"d",
+            b"\xe2",
+            b"\x82",
+            b" ",
+            b"e",
+            b"\xf0\x9f\x98"[..1].as_ref(),
+            b"\x9f",
+        ];
+        for window in 1..12 {
+            let words = new_word_bounds_bytes(bytes, window);
+            assert!(words.clone().take(11).eq(expected.iter().copied()));
+            assert!(words.skip(11).eq([b"\x98".as_ref()]));
+        }
+    }
+
+    #[test]
+    fn test_split_alnum_boundaries() {
+        use super::{new_word_bounds, new_word_bounds_with, WordOptions};
+
+        let split = WordOptions::new().split_alnum_boundaries(true);
+        let cases: &[(&str, &[&str])] = &[
+            ("abc123def", &["abc", "123", "def"]),
+            ("v2", &["v", "2"]),
+            ("3d", &["3", "d"]),
+            ("1st 42nd", &["1", "st", " ", "42", "nd"]),
+            ("a\u{301}1\u{301}b", &["a\u{301}", "1\u{301}", "b"]),
+            ("x1.5y", &["x", "1.5", "y"]),
+            ("a1b:c", &["a", "1", "b:c"]),
+            ("\u{661}\u{627}", &["\u{661}", "\u{627}"]),
+            // only directly adjacent letters and digits are split
+            ("a_1 abc", &["a_1", " ", "abc"]),
+            ("12:30", &["12", ":", "30"]),
+        ];
+
+        for &(s, expected) in cases {
+            assert!(new_word_bounds_with(s, split).eq(expected.iter().copied()));
+            assert!(new_word_bounds_with(s, split)
+                .rev()
+                .eq(expected.iter().rev().copied()));
+        }
+
+        assert!(new_word_bounds("abc123def").eq(["abc123def"]));
+        let both = split.join_zwnj(true);
+        assert!(new_word_bounds_with("\u{3072}\u{200c}\u{3089}2", both)
+            .eq(["\u{3072}\u{200c}\u{3089}", "2"]));
+    }
+
+    #[test]
+    fn test_keep_number_suffix() {
+        use super::{new_word_bounds_with, WordOptions};
+
+        let keep = WordOptions::new()
+            .split_alnum_boundaries(true)
+            .keep_number_suffix(true);
+        let cases: &[(&str, &[&str])] = &[
+            ("1st", &["1st"]),
+            ("42nd", &["42nd"]),
+            ("10km", &["10km"]),
+            ("3 apples", &["3", " ", "apples"]),
+            ("5apples", &["5", "apples"]),
+            ("v2", &["v", "2"]),
+            ("km2", &["km", "2"]),
+            ("1.5GHz", &["1.5GHz"]),
+            ("3e\u{301}me", &["3e\u{301}me"]),
+            ("12cd3ef", &["12cd", "3ef"]),
+            ("ab12cd3efgh", &["ab", "12cd", "3", "efgh"]),
+        ];
+
+        for &(s, expected) in cases {
+            assert!(new_word_bounds_with(s, keep).eq(expected.iter().copied()));
+            assert!(new_word_bounds_with(s, keep)
+                .rev()
+                .eq(expected.iter().rev().copied()));
+        }
+
+        // without split_alnum_boundaries the UAX#29 rules already keep suffixes
+        let keep_only = WordOptions::new().keep_number_suffix(true);
+        assert!(new_word_bounds_with("5apples", keep_only).eq(["5apples"]));
+    }
+
+    #[test]
+    fn test_distinct_whitespace() {
+        use super::{new_word_bounds, new_word_bounds_with, WordOptions};
+
+        let distinct = WordOptions::new().distinct_whitespace(true);
+        let cases: &[(&str, &[&str])] = &[
+            (" \t ", &[" ", "\t", " "]),
+            ("   ", &["   "]),
+            (" \u{3000}", &[" ", "\u{3000}"]),
+            (
+                "\u{2002}\u{2002}  \u{2003}",
+                &["\u{2002}\u{2002}", "  ", "\u{2003}"],
+            ),
+            ("  \u{301}\u{3000}", &["  \u{301}", "\u{3000}"]),
+            ("a \u{a0}b", &["a", " ", "\u{a0}", "b"]),
+            (" \r\n ", &[" ", "\r\n", " "]),
+        ];
+
+        for &(s, expected) in cases {
+            assert!(new_word_bounds_with(s, distinct).eq(expected.iter().copied()));
+            assert!(new_word_bounds_with(s, distinct)
+                .rev()
+                .eq(expected.iter().rev().copied()));
+        }
+
+        // runs of mixed spaces are only split with the option set
+        assert!(new_word_bounds(" \u{3000} ").eq([" \u{3000} "]));
+    }
+
+    #[test]
+    fn test_ideograph_per_char() {
+        use super::{new_unicode_words_with, new_word_bounds, new_word_bounds_with, WordOptions};
+
+        let per_char = WordOptions::new().ideograph_per_char(true);
+        let cases: &[(&str, &[&str])] = &[
+            ("\u{4eba}\u{3005}", &["\u{4eba}", "\u{3005}"]),
+            ("\u{3005}\u{3005}", &["\u{3005}", "\u{3005}"]),
+            ("a\u{3005}\u{303b}b", &["a", "\u{3005}", "\u{303b}", "b"]),
+            ("\u{3005}\u{301}\u{3005}", &["\u{3005}\u{301}", "\u{3005}"]),
+            ("\u{3005}:b", &["\u{3005}", ":", "b"]),
+            ("x\u{16fe3}\u{16fe3}", &["x", "\u{16fe3}", "\u{16fe3}"]),
+            // other text is unaffected
+            (
+                "can't \u{30ab}\u{30bf}\u{30ab}\u{30ca}",
+                &["can't", " ", "\u{30ab}\u{30bf}\u{30ab}\u{30ca}"],
+            ),
+            ("\u{d55c}\u{ad6d}\u{c5b4}", &["\u{d55c}\u{ad6d}\u{c5b4}"]),
+        ];
+
+        for &(s, expected) in cases {
+            assert!(new_word_bounds_with(s, per_char).eq(expected.iter().copied()));
+            assert!(new_word_bounds_with(s, per_char)
+                .rev()
+                .eq(expected.iter().rev().copied()));
+        }
+
+        assert!(new_word_bounds("a\u{3005}\u{303b}b").eq(["a\u{3005}\u{303b}b"]));
+
+        // counting words in mixed Han and Latin text
+        let s =
+            "\u{6211}\u{4eec}\u{5e38}\u{5e38}\u{8bf4} hello world\u{3002}\u{65e5}\u{3005}\u{3005}";
+        assert_eq!(new_unicode_words_with(s, WordOptions::new()).count(), 9);
+        assert_eq!(new_unicode_words_with(s, per_char).count(), 10);
+    }
+
+    #[test]
+    fn test_attach_open_punct() {
+        use super::{new_word_bounds_with, WordOptions};
+
+        let attach = WordOptions::new().attach_open_punct(true);
+        let cases: &[(&str, &[&str])] = &[
+            ("(word)", &["(word", ")"]),
+            ("[(\u{201c}nested", &["[(\u{201c}nested"]),
+            ("\"hi\" \"there\"", &["\"hi", "\"", " ", "\"there", "\""]),
+            (
+                "\u{ab}\u{a0}oui \u{bb}",
+                &["\u{ab}", "\u{a0}", "oui", " ", "\u{bb}"],
+            ),
+            ("(\u{301}x", &["(\u{301}x"]),
+            // an alphabetic mark after the punctuation makes a word
+            ("((\u{93f}", &["((\u{93f}"]),
+            ("\u{201c}(\u{93f}\r\n", &["\u{201c}(\u{93f}", "\r\n"]),
+            ("((  a", &["(", "(", "  ", "a"]),
+            ("(42) ({!", &["(42", ")", " ", "(", "{", "!"]),
+            // closing punctuation is untouched
+            ("x) ]y", &["x", ")", " ", "]", "y"]),
+        ];
+
+        for &(s, expected) in cases {
+            assert!(new_word_bounds_with(s, attach).eq(expected.iter().copied()));
+            assert!(new_word_bounds_with(s, attach)
+                .rev()
+                .eq(expected.iter().rev().copied()));
+        }
+
+        let all = attach.join_zwnj(true).split_alnum_boundaries(true);
+        let expected = ["(\u{3072}\u{200c}\u{3089}", "2", " ", "(ab", "12"];
+        let s = "(\u{3072}\u{200c}\u{3089}2 (ab12";
+        assert!(new_word_bounds_with(s, all).eq(expected.iter().copied()));
+        assert!(new_word_bounds_with(s, all)
+            .rev()
+            .eq(expected.iter().rev().copied()));
+    }
 }
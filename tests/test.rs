@@ -9,7 +9,7 @@
 // except according to those terms.
 
 use quickcheck::quickcheck;
-use unicode_segmentation::UnicodeSegmentation;
+use unicode_segmentation::{UnicodeSegmentation, UnicodeSegmentationExt};
 
 #[rustfmt::skip]
 mod testdata;
@@ -112,7 +112,6 @@ fn test_words() {
     use crate::testdata::TEST_WORD;
 
     // Unicode's official tests don't really test longer chains of flag emoji
-    // TODO This could be improved with more tests like flag emoji with interspersed Extend chars and ZWJ
     const EXTRA_TESTS: &[(&str, &[&str])] = &[
         (
             "🇦🇫🇦🇽🇦🇱🇩🇿🇦🇸🇦🇩🇦🇴",
@@ -131,6 +130,49 @@ fn test_words() {
         // perhaps wrong, spaces should not be included?
         ("hello world", &["hello", " ", "world"]),
         ("🇨🇦🇨🇭🇿🇲🇿 hi", &["🇨🇦", "🇨🇭", "🇿🇲", "🇿", " ", "hi"]),
+        // Extend, Format and ZWJ characters between regional indicators are ignored (WB4), so
+        // they neither break a flag nor change which indicators pair up (WB15, WB16)
+        (
+            "\u{1f1e6}\u{301}\u{1f1eb}\u{1f1e6}\u{200d}\u{1f1fd}\u{1f1e6}",
+            &[
+                "\u{1f1e6}\u{301}\u{1f1eb}",
+                "\u{1f1e6}\u{200d}\u{1f1fd}",
+                "\u{1f1e6}",
+            ],
+        ),
+        (
+            "\u{1f1e6}\u{1f1eb}\u{301}\u{ad}\u{1f1e6}\u{1f1fd}\u{200d}",
+            &[
+                "\u{1f1e6}\u{1f1eb}\u{301}\u{ad}",
+                "\u{1f1e6}\u{1f1fd}\u{200d}",
+            ],
+        ),
+        (
+            "\u{1f1e6}\u{301}\u{301}\u{1f1eb}\u{1f1e6}\u{301}",
+            &["\u{1f1e6}\u{301}\u{301}\u{1f1eb}", "\u{1f1e6}\u{301}"],
+        ),
+        // any other character restarts the pairing
+        (
+            "\u{1f1e6}\u{301}a\u{1f1eb}\u{1f1e6}\u{1f1fd}",
+            &["\u{1f1e6}\u{301}", "a", "\u{1f1eb}\u{1f1e6}", "\u{1f1fd}"],
+        ),
+        (
+            "\u{1f1e6}\u{1f1eb}\u{1f1e6} \u{1f1fd}\u{1f1e6}\u{1f1eb}",
+            &[
+                "\u{1f1e6}\u{1f1eb}",
+                "\u{1f1e6}",
+                " ",
+                "\u{1f1fd}\u{1f1e6}",
+                "\u{1f1eb}",
+            ],
+        ),
+        (
+            "\u{1f1e6}\u{1f468}\u{1f1eb}\u{1f1e6}",
+            &["\u{1f1e6}", "\u{1f468}", "\u{1f1eb}\u{1f1e6}"],
+        ),
+        // WB3c after a MidLetter or MidNum that doesn't continue the word
+        ("A:\u{200d}\u{a9}", &["A", ":\u{200d}\u{a9}"]),
+        ("1.\u{200d}\u{1f468}a", &["1", ".\u{200d}\u{1f468}", "a"]),
     ];
     for &(s, w) in TEST_WORD.iter().chain(EXTRA_TESTS.iter()) {
         macro_rules! assert_ {
@@ -186,6 +228,32 @@ fn test_words() {
     }
 }
 
+#[test]
+fn test_words_ascii_space_fast_path() {
+    use crate::testdata::TEST_WORD;
+
+    // consecutive spaces stay together (WB3d)
+    let w = "hello  world 42".split_word_bounds().collect::<Vec<_>>();
+    assert_eq!(w, ["hello", "  ", "world", " ", "42"]);
+
+    // Forward iteration takes the shortcut for runs of ASCII spaces, letters and digits, while
+    // reverse iteration always goes through the full rules, so the two must agree.
+    for &(s, _) in TEST_WORD.iter() {
+        let cases = [
+            s.to_string(),
+            format!("ab {}", s),
+            format!("{}  9z", s),
+            format!("a1 {} b", s),
+        ];
+        for s in cases.iter() {
+            let forward = s.split_word_bounds().collect::<Vec<_>>();
+            let mut reverse = s.split_word_bounds().rev().collect::<Vec<_>>();
+            reverse.reverse();
+            assert_eq!(forward, reverse, "{:?}", s);
+        }
+    }
+}
+
 #[test]
 fn test_sentences() {
     use crate::testdata::TEST_SENTENCE;
@@ -209,39 +277,2497 @@ fn test_sentences() {
             w.iter().cloned(),
             "Forward sentence boundaries"
         );
+
+        // generate offsets from sentence string lengths
+        let indices = w.iter().scan(0, |t, w| {
+            let i = *t;
+            *t += w.len();
+            Some((i, *w))
+        });
+
+        // test reverse iterator
+        assert_!(
+            s.split_sentence_bounds().rev(),
+            w.iter().rev().cloned(),
+            "Reverse sentence boundaries"
+        );
+
+        // test forward indices iterator
+        assert_!(
+            s.split_sentence_bound_indices(),
+            indices.clone(),
+            "Forward sentence indices"
+        );
+
+        // test backward indices iterator
+        assert_!(
+            s.split_sentence_bound_indices().rev(),
+            indices.collect::<Vec<_>>().into_iter().rev(),
+            "Reverse sentence indices"
+        );
+
+        assert_!(
+            s.unicode_sentences().rev(),
+            s.unicode_sentences().collect::<Vec<_>>().into_iter().rev(),
+            "Reverse unicode sentences"
+        );
     }
 }
 
-quickcheck! {
-    fn quickcheck_forward_reverse_graphemes_extended(s: String) -> bool {
-        let a = s.graphemes(true).collect::<Vec<_>>();
-        let mut b = s.graphemes(true).rev().collect::<Vec<_>>();
-        b.reverse();
-        a == b
+#[test]
+fn test_unicode_sentences_skip_whitespace() {
+    let s = "Hi.\n\n\u{2029} \nBye. \r\n";
+    assert_eq!(
+        s.split_sentence_bounds().collect::<Vec<_>>(),
+        &["Hi.\n", "\n", "\u{2029}", " \n", "Bye. \r\n"]
+    );
+    assert_eq!(
+        s.unicode_sentences().collect::<Vec<_>>(),
+        &["Hi.\n", "Bye. \r\n"]
+    );
+    assert_eq!(" \n\u{2029}\t".unicode_sentences().next(), None);
+}
+
+#[test]
+fn test_sentences_size_hint_empty() {
+    assert_eq!("".split_sentence_bounds().size_hint(), (0, Some(0)));
+    assert_eq!("".split_sentence_bound_indices().size_hint(), (0, Some(0)));
+    assert_eq!("".unicode_sentences().size_hint(), (0, Some(0)));
+    let (lower, upper) = "Hi. Bye.".split_sentence_bounds().size_hint();
+    assert!(lower <= 2 && upper >= Some(2));
+}
+
+#[test]
+fn test_sentence_break_property() {
+    use unicode_segmentation::{sentence_break_property, SentenceBreakProperty as SB};
+
+    let cases = [
+        ('.', SB::ATerm),
+        ('!', SB::STerm),
+        ('\u{3002}', SB::STerm),
+        (',', SB::SContinue),
+        ('"', SB::Close),
+        (')', SB::Close),
+        (' ', SB::Sp),
+        ('\r', SB::CR),
+        ('\n', SB::LF),
+        ('\u{2029}', SB::Sep),
+        ('A', SB::Upper),
+        ('a', SB::Lower),
+        ('7', SB::Numeric),
+        ('\u{4E2D}', SB::OLetter),
+        ('\u{301}', SB::Extend),
+        ('\u{AD}', SB::Format),
+        ('$', SB::Other),
+    ];
+    for &(c, prop) in cases.iter() {
+        assert_eq!(sentence_break_property(c), prop, "{:?}", c);
     }
+}
 
-    fn quickcheck_forward_reverse_graphemes_legacy(s: String) -> bool {
-        let a = s.graphemes(false).collect::<Vec<_>>();
-        let mut b = s.graphemes(false).rev().collect::<Vec<_>>();
-        b.reverse();
-        a == b
+#[test]
+fn test_sentence_cursor() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::{SentenceCursor, SentenceIncomplete};
+
+    // Split `s` into chunks of about `size` bytes, on codepoint boundaries.
+    fn chunks(s: &str, size: usize) -> Vec<(usize, &str)> {
+        let mut chunks = vec![];
+        let mut start = 0;
+        while start < s.len() {
+            let mut end = (start + size).min(s.len());
+            while !s.is_char_boundary(end) {
+                end += 1;
+            }
+            chunks.push((start, &s[start..end]));
+            start = end;
+        }
+        chunks
     }
 
-    fn quickcheck_join_graphemes(s: String) -> bool {
-        let a = s.graphemes(true).collect::<String>();
-        let b = s.graphemes(false).collect::<String>();
-        a == s && b == s
+    // Walk through `s` fed in chunks in either direction, answering requests for other
+    // chunks as they come.
+    fn streamed(s: &str, size: usize, forward: bool) -> Vec<usize> {
+        let chunks = chunks(s, size);
+        let (mut cursor, mut idx) = if forward {
+            (SentenceCursor::new(0, s.len()), 0)
+        } else {
+            (SentenceCursor::new(s.len(), s.len()), chunks.len() - 1)
+        };
+        let mut boundaries = vec![];
+        loop {
+            let (start, chunk) = chunks[idx];
+            let result = if forward {
+                cursor.next_boundary(chunk, start)
+            } else {
+                cursor.prev_boundary(chunk, start)
+            };
+            match result {
+                Ok(Some(b)) => boundaries.push(b),
+                Ok(None) => break,
+                Err(SentenceIncomplete::NextChunk) => idx += 1,
+                Err(SentenceIncomplete::PrevChunk) => idx -= 1,
+                Err(SentenceIncomplete::PreContext(n)) => {
+                    let (start, chunk) = chunks
+                        .iter()
+                        .copied()
+                        .find(|&(start, chunk)| start + chunk.len() == n)
+                        .unwrap();
+                    cursor.provide_context(chunk, start);
+                }
+                Err(e) => panic!("unexpected {:?} for {:?}", e, s),
+            }
+        }
+        boundaries
     }
 
-    fn quickcheck_forward_reverse_words(s: String) -> bool {
-        let a = s.split_word_bounds().collect::<Vec<_>>();
-        let mut b = s.split_word_bounds().rev().collect::<Vec<_>>();
-        b.reverse();
-        a == b
+    fn check(s: &str, sizes: &[usize]) {
+        let mut expected: Vec<usize> = s
+            .split_sentence_bound_indices()
+            .map(|(i, sentence)| i + sentence.len())
+            .collect();
+        for &size in sizes {
+            assert_eq!(streamed(s, size, true), expected, "{:?} in {}", s, size);
+        }
+        expected.pop();
+        expected.insert(0, 0);
+        expected.reverse();
+        for &size in sizes {
+            assert_eq!(streamed(s, size, false), expected, "{:?} in {}", s, size);
+        }
     }
 
-    fn quickcheck_join_words(s: String) -> bool {
-        let a = s.split_word_bounds().collect::<String>();
-        a == s
+    let extra = [
+        // SB8 looks ahead across several chunks
+        "etc.   (     )     but",
+        "etc.   (     )     But",
+        "etc. )\u{301}  5  \u{2060}",
+        // paragraph separators after Extend
+        "Hi.\r\n\u{301}\u{301}A\u{2029}\u{301}b",
+    ];
+    let strings = TEST_SENTENCE
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(extra.iter().copied())
+        .filter(|s| !s.is_empty());
+    for s in strings {
+        check(s, &[1, 2, 3, 7]);
+    }
+
+    // a long text in 64 KiB windows
+    let text = TEST_SENTENCE
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(extra.iter().copied())
+        .collect::<String>();
+    check(&text.repeat(256 * 1024 / text.len() + 1), &[64 * 1024]);
+}
+
+#[test]
+fn test_lines_with_terminators() {
+    let tests: &[(&str, &[(&str, &str)])] = &[
+        ("", &[]),
+        ("one", &[("one", "")]),
+        ("one\n", &[("one", "\n")]),
+        ("\n\n", &[("", "\n"), ("", "\n")]),
+        (
+            "a\r\nb\n\rc",
+            &[("a", "\r\n"), ("b", "\n"), ("", "\r"), ("c", "")],
+        ),
+        (
+            "x\u{85}y\u{2028}z\u{2029}",
+            &[("x", "\u{85}"), ("y", "\u{2028}"), ("z", "\u{2029}")],
+        ),
+        // other vertical whitespace doesn't end a line
+        ("a\u{b}b\u{c}c\r", &[("a\u{b}b\u{c}c", "\r")]),
+    ];
+    for &(s, expected) in tests {
+        let lines = s.lines_with_terminators().collect::<Vec<_>>();
+        assert_eq!(lines, expected);
+    }
+
+    for &(s, _) in crate::testdata::TEST_SENTENCE.iter() {
+        let mut joined = String::new();
+        for (line, terminator) in s.lines_with_terminators() {
+            assert!(!line.contains(&['\n', '\r', '\u{85}', '\u{2028}', '\u{2029}'][..]));
+            joined.push_str(line);
+            joined.push_str(terminator);
+        }
+        assert_eq!(joined, s);
+    }
+}
+
+#[test]
+fn test_sentence_starts() {
+    let s = "«Bonjour», dit-il! (maybe not!) 42 is the answer? \u{a0} ¿qué?\n\n  «»... x";
+    let starts = s.sentence_starts().collect::<Vec<_>>();
+    let words = starts
+        .iter()
+        .map(|&i| s[i..].split(|c: char| !c.is_alphabetic()).next().unwrap())
+        .collect::<Vec<_>>();
+    // the second line break is a sentence of its own
+    assert_eq!(words, ["Bonjour", "maybe", "is", "qué", "", "x"]);
+
+    assert_eq!("".sentence_starts().next(), None);
+    // sentences starting with a number, or without letters
+    assert!("3 apples were left. 12. Ok."
+        .sentence_starts()
+        .eq(vec![2, 20, 24]));
+    assert!("... ?! 12.".sentence_starts().eq(vec![0, 7]));
+    assert!("\u{5d0}. \u{4e00}".sentence_starts().eq(vec![0, 4]));
+}
+
+#[test]
+fn test_trimmed_sentences() {
+    use crate::testdata::TEST_SENTENCE;
+
+    for &(s, w) in TEST_SENTENCE.iter() {
+        let mut start = 0;
+        let mut expected = vec![];
+        for sentence in w {
+            let trimmed = sentence.trim_end();
+            if !trimmed.is_empty() {
+                expected.push((start, start + trimmed.len(), trimmed));
+            }
+            start += sentence.len();
+        }
+        let found = s.trimmed_sentences().collect::<Vec<_>>();
+        assert_eq!(found, expected);
+        for (start, end, trimmed) in found {
+            assert_eq!(&s[start..end], trimmed);
+        }
+    }
+
+    let s = "  \n\u{2029}Hi.\u{a0}\r\n \u{2029}";
+    assert!(s.trimmed_sentences().eq(vec![(6, 9, "Hi.")]));
+    assert_eq!("   ".trimmed_sentences().next(), None);
+}
+
+#[test]
+fn test_sentences_close_sp_runs() {
+    let cases: &[(&str, &[&str])] = &[
+        // SB8: ATerm Close* Sp* followed by lowercase does not break
+        ("etc.))  the end.", &["etc.))  the end."]),
+        ("etc.)))\"\"   \t the end.", &["etc.)))\"\"   \t the end."]),
+        ("etc.))  \"the end.", &["etc.))  \"the end."]),
+        ("etc.)) 12 the end.", &["etc.)) 12 the end."]),
+        (
+            "etc.)\u{301})\u{AD}  \u{301} the end.",
+            &["etc.)\u{301})\u{AD}  \u{301} the end."],
+        ),
+        // ... but does break before an uppercase letter or after an STerm
+        ("etc.))  The end.", &["etc.))  ", "The end."]),
+        ("Stop!))  the end.", &["Stop!))  ", "the end."]),
+        // a Close after Sp* is not part of the terminator (SB11)
+        ("etc.))  )) The end.", &["etc.))  ", ")) The end."]),
+        ("etc.))  )) the end.", &["etc.))  )) the end."]),
+        ("etc.)) \r\nthe end.", &["etc.)) \r\n", "the end."]),
+    ];
+    for &(s, expected) in cases {
+        let bounds = s.split_sentence_bounds().collect::<Vec<_>>();
+        assert_eq!(bounds, expected, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_enumerate_sentences() {
+    use crate::testdata::TEST_SENTENCE;
+
+    assert_eq!("".enumerate_sentences().next(), None);
+    for &(s, _) in TEST_SENTENCE.iter() {
+        let enumerated = s.enumerate_sentences().collect::<Vec<_>>();
+        let expected = s
+            .split_sentence_bound_indices()
+            .enumerate()
+            .map(|(n, (i, sentence))| (n, i, sentence))
+            .collect::<Vec<_>>();
+        assert_eq!(enumerated, expected);
+    }
+}
+
+#[test]
+fn test_sentences_with_direction() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::Direction::*;
+
+    let s = "\u{5d4}\u{5d5}\u{5d0} \u{5d0}\u{5de}\u{5e8}: \"Hi.\" \
+             She said \u{5e9}\u{5dc}\u{5d5}\u{5dd} back. \
+             \u{645}\u{631}\u{62d}\u{628}\u{627}! 123. \u{201c}\u{5db}\u{5df}\u{201d}.";
+    let expected = [
+        Rtl, // the Hebrew before the quotation decides
+        Ltr, // Hebrew inside an English sentence
+        Rtl, Neutral, Rtl, // opening punctuation is not strong
+    ];
+    let directions = s
+        .sentences_with_direction()
+        .map(|(_, d)| d)
+        .collect::<Vec<_>>();
+    assert_eq!(directions, expected);
+
+    // the sentences are those of split_sentence_bounds
+    for &(s, _) in TEST_SENTENCE.iter() {
+        assert!(s
+            .sentences_with_direction()
+            .map(|(s, _)| s)
+            .eq(s.split_sentence_bounds()));
+    }
+}
+
+#[test]
+fn test_words_with_sentence_start() {
+    let cases: &[(&str, &[(&str, bool)])] = &[
+        ("", &[]),
+        ("... !?", &[]),
+        (
+            "Hello there. How are you?",
+            &[
+                ("Hello", true),
+                ("there", false),
+                ("How", true),
+                ("are", false),
+                ("you", false),
+            ],
+        ),
+        // quotes around a sentence don't hide its first word
+        (
+            "He left. \"Why?\" she asked.",
+            &[
+                ("He", true),
+                ("left", false),
+                ("Why", true),
+                ("she", true),
+                ("asked", false),
+            ],
+        ),
+        // an abbreviation followed by lowercase doesn't end the sentence (SB8) ...
+        (
+            "See e.g. the docs. Done",
+            &[
+                ("See", true),
+                ("e.g", false),
+                ("the", false),
+                ("docs", false),
+                ("Done", true),
+            ],
+        ),
+        // ... but followed by an uppercase word it does
+        (
+            "Ask Mr. Smith.",
+            &[("Ask", true), ("Mr", false), ("Smith", true)],
+        ),
+        // a sentence without words contributes nothing
+        ("One. !!! Two", &[("One", true), ("Two", true)]),
+    ];
+    for &(s, expected) in cases {
+        let words = s.words_with_sentence_start().collect::<Vec<_>>();
+        assert_eq!(words, expected, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_sentences_quote_aware() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::SentenceOptions;
+
+    let options = SentenceOptions::new().quote_aware(true);
+    const TESTS: &[(&str, &[&str])] = &[
+        (
+            "\"Really?\" she asked. It was.",
+            &["\"Really?\" she asked. ", "It was."],
+        ),
+        (
+            "'Really?' she asked. It was.",
+            &["'Really?' she asked. ", "It was."],
+        ),
+        (
+            "\u{201c}Stop!\u{201d} he cried. \u{2018}Why?\u{2019} she asked.",
+            &[
+                "\u{201c}Stop!\u{201d} he cried. ",
+                "\u{2018}Why?\u{2019} she asked.",
+            ],
+        ),
+        (
+            "\u{ab}Vraiment?\u{bb} dit-elle. Oui.",
+            &["\u{ab}Vraiment?\u{bb} dit-elle. ", "Oui."],
+        ),
+        // apostrophes don't open quotations
+        ("Don't go! It's late.", &["Don't go! ", "It's late."]),
+        ("The dogs' bowls? Empty.", &["The dogs' bowls? ", "Empty."]),
+        // full stops inside quotations still end sentences
+        ("\"Fine.\" She left.", &["\"Fine.\" ", "She left."]),
+        // quotations end with the paragraph
+        (
+            "\"Unclosed?\nNext? Yes.",
+            &["\"Unclosed?\n", "Next? ", "Yes."],
+        ),
+        ("\"Last?\"", &["\"Last?\""]),
+    ];
+
+    for &(s, expected) in TESTS {
+        let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(sentences, expected);
+        assert_eq!(sentences.concat(), s);
+        let mut reversed = s
+            .split_sentence_bounds_with(options)
+            .rev()
+            .collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    // text without quotation marks is unaffected
+    for &(s, expected) in TEST_SENTENCE.iter() {
+        if s.contains(|c| {
+            "\"'\u{2018}\u{2019}\u{201c}\u{201d}\u{201e}\u{ab}\u{bb}\u{2039}\u{203a}".contains(c)
+        }) {
+            continue;
+        }
+        assert!(s
+            .split_sentence_bounds_with(options)
+            .eq(expected.iter().cloned()));
+    }
+}
+
+#[test]
+fn test_sentences_split_on_semicolon() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::SentenceOptions;
+
+    let options = SentenceOptions::new().split_on_semicolon(true);
+    const TESTS: &[(&str, &[&str])] = &[
+        (
+            "First; second;third.  Fourth;",
+            &["First; ", "second;", "third.  ", "Fourth;"],
+        ),
+        ("a;\nb", &["a;\n", "b"]),
+        (";;", &[";", ";"]),
+        (
+            "\u{6211}\u{6765}\u{ff1b}\u{6211}\u{8d70}",
+            &["\u{6211}\u{6765}\u{ff1b}", "\u{6211}\u{8d70}"],
+        ),
+    ];
+
+    for &(s, expected) in TESTS {
+        let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(sentences, expected);
+        let mut reversed = s
+            .split_sentence_bounds_with(options)
+            .rev()
+            .collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+        let split = s
+            .split_sentence_bounds_with(options)
+            .collect::<Vec<_>>()
+            .concat();
+        assert_eq!(split, s);
+    }
+
+    // clauses are only split with the option set
+    let s = "I came; I saw; I conquered.";
+    assert_eq!(s.split_sentence_bounds().collect::<Vec<_>>(), [s]);
+    assert_eq!(s.unicode_sentences_with(SentenceOptions::new()).count(), 1);
+    assert_eq!(s.unicode_sentences_with(options).count(), 3);
+
+    // joins from `quote_aware` happen before clauses are split
+    let both = options.quote_aware(true);
+    let s = "\"Why?\" she asked; nobody knew. Done.";
+    assert_eq!(
+        s.split_sentence_bounds_with(both).collect::<Vec<_>>(),
+        ["\"Why?\" she asked; ", "nobody knew. ", "Done."]
+    );
+
+    // text without semicolons is unaffected
+    for &(s, expected) in TEST_SENTENCE.iter() {
+        if !s.contains(&[';', '\u{ff1b}'][..]) {
+            assert!(s
+                .split_sentence_bounds_with(options)
+                .eq(expected.iter().cloned()));
+        }
+    }
+}
+
+#[test]
+fn test_sentences_with_from_both_ends() {
+    use unicode_segmentation::SentenceOptions;
+
+    let options = SentenceOptions::new()
+        .quote_aware(true)
+        .split_on_semicolon(true);
+    let s = "One; two. \"Three?\" she said; four.\nFive. Six; seven.\n\nEight; nine. Ten.";
+    let expected = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+    assert_eq!(expected.len(), 11);
+
+    // take `front` sentences from the front, then alternate ends, starting with the back
+    for front in 0..=expected.len() {
+        let mut iter = s.split_sentence_bounds_with(options);
+        let mut head = iter.by_ref().take(front).collect::<Vec<_>>();
+        let mut tail = vec![];
+        while let Some(sentence) = iter.next_back() {
+            tail.push(sentence);
+            match iter.next() {
+                Some(sentence) => head.push(sentence),
+                None => break,
+            }
+        }
+        tail.reverse();
+        head.extend(tail);
+        assert_eq!(head, expected, "front = {}", front);
+    }
+}
+
+#[test]
+fn test_sentences_property_override() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::{SentenceBreakProperty, SentenceOptions};
+
+    let options = SentenceOptions::new().property_override(|c| match c {
+        ';' => Some(SentenceBreakProperty::STerm),
+        '#' => Some(SentenceBreakProperty::Sep),
+        '\n' => Some(SentenceBreakProperty::Other),
+        '\u{3002}' => Some(SentenceBreakProperty::Other),
+        _ => None,
+    });
+    const TESTS: &[(&str, &[&str])] = &[
+        ("a; b;c. D", &["a; ", "b;", "c. ", "D"]),
+        ("Wait\u{3002} What?", &["Wait\u{3002} What?"]),
+        // paragraph separators can't be added or removed
+        ("a#b\nc", &["a#b\n", "c"]),
+        ("(x;) y", &["(x;) ", "y"]),
+    ];
+
+    for &(s, expected) in TESTS {
+        let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(sentences, expected);
+        let mut reversed = s
+            .split_sentence_bounds_with(options)
+            .rev()
+            .collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    // without the override, `\u{3002}` ends a sentence and `;` doesn't
+    assert_eq!("Wait\u{3002} What?".split_sentence_bounds().count(), 2);
+    assert_eq!("a; b;c. D".split_sentence_bounds().count(), 2);
+
+    // an override that changes nothing gives the UAX#29 segmentation
+    let options = SentenceOptions::new().property_override(|_| None);
+    for &(s, expected) in TEST_SENTENCE.iter() {
+        assert!(s
+            .split_sentence_bounds_with(options)
+            .eq(expected.iter().cloned()));
+        assert!(s
+            .split_sentence_bounds_with(options)
+            .rev()
+            .eq(expected.iter().rev().cloned()));
+    }
+}
+
+#[test]
+fn test_sentences_skip_plain_ascii() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::SentenceOptions;
+
+    // Runs of ASCII without terminators are skipped over in bulk, unless there is a property
+    // override, so an override that changes nothing checks them character by character.
+    let options = SentenceOptions::new().property_override(|_| None);
+    let long = "word ".repeat(100);
+    let extra = [
+        format!("{}. {}", long, long),
+        format!("(e.g. {}) \u{3002}{}?\r\n{}", long, long, long),
+        format!("U.S.A {} etc.) the {} \u{5b57} {}", long, long, long),
+        format!("a{}\u{2029}\u{301}{}.\u{301}{}!", long, long, long),
+        "He said \"x.\" (and) \"y\". Then   ".to_string(),
+    ];
+    let strings = TEST_SENTENCE
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(extra.iter().map(|s| s.as_str()));
+    for s in strings {
+        let checked = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(
+            s.split_sentence_bounds().collect::<Vec<_>>(),
+            checked,
+            "{:?}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_sentences_require_space_after_terminator() {
+    use crate::testdata::TEST_SENTENCE;
+    use unicode_segmentation::SentenceOptions;
+
+    let options = SentenceOptions::new().require_space_after_terminator(true);
+    const TESTS: &[(&str, &[&str])] = &[
+        ("Visit example.com today.", &["Visit example.com today."]),
+        ("Pi is 3.14 or so.", &["Pi is 3.14 or so."]),
+        ("Done. Next", &["Done. ", "Next"]),
+        ("Why?!Because. Last", &["Why?!Because. ", "Last"]),
+        (
+            "Done.(Next) \u{4e2d}.\u{6587}",
+            &["Done.(Next) \u{4e2d}.\u{6587}"],
+        ),
+        ("Done.\nNext", &["Done.\n", "Next"]),
+        ("Done.) \u{301}Next", &["Done.) \u{301}", "Next"]),
+        ("Done.", &["Done."]),
+    ];
+
+    for &(s, expected) in TESTS {
+        let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(sentences, expected, "{:?}", s);
+        let mut reversed = s
+            .split_sentence_bounds_with(options)
+            .rev()
+            .collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    // the default rules break where there is no space
+    assert_eq!(
+        "Why?!Because.".split_sentence_bounds().collect::<Vec<_>>(),
+        ["Why?!", "Because."]
+    );
+    assert_eq!(
+        "Why?!Because."
+            .unicode_sentences_with(options)
+            .collect::<Vec<_>>(),
+        ["Why?!Because."]
+    );
+
+    // every break but the last is now followed by whitespace, and no text is lost
+    for &(s, _) in TEST_SENTENCE.iter() {
+        let sentences = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        assert_eq!(sentences.concat(), s);
+        for sentence in &sentences[..sentences.len().saturating_sub(1)] {
+            let last = sentence.chars().rev().find(|&c| {
+                !matches!(
+                    c,
+                    '\u{300}'..='\u{36f}' | '\u{ad}' | '\u{200d}' | '\u{2060}'
+                )
+            });
+            assert!(last.is_some_and(char::is_whitespace), "{:?}", s);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_graphemes_owned() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::new_owned_graphemes;
+
+    for &(s, g) in TEST_SAME.iter() {
+        assert!(new_owned_graphemes(s, true).eq(g.iter().cloned()));
+        assert!(new_owned_graphemes(s, true)
+            .rev()
+            .eq(g.iter().rev().cloned()));
+    }
+    for &(s, gt, gf) in TEST_DIFF.iter() {
+        assert!(new_owned_graphemes(s, true).eq(gt.iter().cloned()));
+        assert!(new_owned_graphemes(s, false).eq(gf.iter().cloned()));
+    }
+
+    // clusters longer than the inline capacity move to the heap
+    let s =
+        "e\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}\u{308}\u{309}\u{30a}\u{30b}\u{30c}x";
+    let owned = new_owned_graphemes(s, true).collect::<Vec<_>>();
+    assert_eq!(owned.len(), 2);
+    assert!(!owned[0].is_inline());
+    assert!(owned[1].is_inline());
+    assert_eq!(String::from(owned[0].clone()) + &owned[1], s);
+}
+
+#[test]
+fn test_grapheme_mode() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::{GraphemeCursor, GraphemeMode};
+
+    assert_eq!(GraphemeMode::default(), GraphemeMode::Extended);
+    for &is_extended in &[true, false] {
+        let mode = GraphemeMode::from(is_extended);
+        assert_eq!(mode.is_extended(), is_extended);
+        assert_eq!(bool::from(mode), is_extended);
+    }
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        let boundaries = |mode: GraphemeMode| {
+            let mut cursor = GraphemeCursor::with_mode(0, s.len(), mode);
+            std::iter::from_fn(|| cursor.next_boundary(s, 0).unwrap()).collect::<Vec<_>>()
+        };
+        let (extended, legacy) = (
+            boundaries(GraphemeMode::Extended),
+            boundaries(GraphemeMode::Legacy),
+        );
+        for &(mode, bounds) in &[
+            (GraphemeMode::Extended, &extended),
+            (GraphemeMode::Legacy, &legacy),
+        ] {
+            assert!(s.graphemes_with(mode).eq(s.graphemes(mode.is_extended())));
+            let ends = s
+                .grapheme_indices(mode.is_extended())
+                .map(|(i, g)| i + g.len());
+            assert!(ends.eq(bounds.iter().cloned()));
+        }
+        // legacy clusters only leave out rules that join, so they never span an extended
+        // boundary
+        assert!(extended.iter().all(|b| legacy.contains(b)), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_graphemes_typed() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    for &(s, g) in TEST_SAME.iter() {
+        assert!(s.graphemes_typed(true).eq(g.iter().cloned()));
+        assert!(s.graphemes_typed(true).rev().eq(g.iter().rev().cloned()));
+        assert!(s
+            .grapheme_indices_typed(true)
+            .map(|(i, g)| (i, g.as_str()))
+            .eq(s.grapheme_indices(true)));
+    }
+    for &(s, _, gl) in TEST_DIFF.iter() {
+        assert!(s.graphemes_typed(false).eq(gl.iter().cloned()));
+        assert!(s
+            .grapheme_indices_typed(false)
+            .rev()
+            .map(|(i, g)| (i, g.as_str()))
+            .eq(s.grapheme_indices(false).rev()));
     }
+
+    let g = "e\u{301}x".graphemes_typed(true).next().unwrap();
+    assert_eq!(g, "e\u{301}");
+    assert_eq!(g.len_bytes(), 3);
+    assert_eq!(g.chars().collect::<String>(), "e\u{301}");
+    assert_eq!(g.to_string(), "e\u{301}");
+    assert!(g.starts_with('e'));
+}
+
+#[test]
+fn test_graphemes_cow() {
+    use crate::testdata::TEST_SAME;
+    use std::borrow::Cow;
+    use unicode_segmentation::graphemes_cow;
+
+    for &(s, g) in TEST_SAME.iter() {
+        for text in [Cow::Borrowed(s), Cow::Owned(s.to_string())].iter() {
+            let mut iter = graphemes_cow(text.clone(), true);
+            for &cluster in g.iter() {
+                assert_eq!(iter.next_cluster(), Some(cluster));
+            }
+            assert_eq!(iter.next_cluster(), None);
+            assert_eq!(iter.next_cluster(), None);
+            assert_eq!(iter.as_str(), "");
+            assert_eq!(iter.into_inner(), *text);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_collect_into() {
+    use crate::testdata::{TEST_SAME, TEST_SENTENCE, TEST_WORD};
+    use unicode_segmentation::{
+        collect_graphemes_into, collect_sentence_bounds_into, collect_word_bounds_into,
+    };
+
+    let mut out = vec!["stale"; 64];
+    for &(s, g) in TEST_SAME.iter() {
+        collect_graphemes_into(s, &mut out, true);
+        assert_eq!(out, g);
+        collect_graphemes_into(s, &mut out, false);
+        assert_eq!(out, g);
+    }
+    for &(s, w) in TEST_WORD.iter() {
+        collect_word_bounds_into(s, &mut out);
+        assert_eq!(out, w);
+    }
+    for &(s, b) in TEST_SENTENCE.iter() {
+        collect_sentence_bounds_into(s, &mut out);
+        assert_eq!(out, b);
+    }
+
+    // the buffer is reused rather than reallocated
+    let capacity = out.capacity();
+    collect_graphemes_into("", &mut out, true);
+    assert!(out.is_empty());
+    assert_eq!(out.capacity(), capacity);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_graphemes_canonical_order() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use std::borrow::Cow;
+    use unicode_segmentation::new_canonical_graphemes;
+
+    // reordering never moves a boundary, and only clusters that change are copied
+    for &(s, g) in TEST_SAME.iter() {
+        for (c, &expected) in new_canonical_graphemes(s, true).zip(g) {
+            assert_eq!(c.len(), expected.len());
+            assert_eq!(matches!(c, Cow::Borrowed(_)), c == expected);
+        }
+        assert_eq!(new_canonical_graphemes(s, true).count(), g.len());
+    }
+    for &(s, _, gf) in TEST_DIFF.iter() {
+        for (c, &expected) in new_canonical_graphemes(s, false).zip(gf) {
+            assert_eq!(c.len(), expected.len());
+            assert_eq!(matches!(c, Cow::Borrowed(_)), c == expected);
+        }
+        assert_eq!(new_canonical_graphemes(s, false).count(), gf.len());
+    }
+
+    let s = "o\u{307}\u{323}\u{31b}x\u{302}\u{328}\u{300}";
+    let gr = new_canonical_graphemes(s, true).collect::<Vec<_>>();
+    assert_eq!(gr, ["o\u{31b}\u{323}\u{307}", "x\u{328}\u{302}\u{300}"]);
+    assert!(new_canonical_graphemes(s, true)
+        .rev()
+        .eq(gr.into_iter().rev()));
+}
+
+#[test]
+fn test_insertion_splits_cluster() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::insertion_splits_cluster;
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s))
+        .collect::<Vec<_>>();
+    let inserts = [
+        "",
+        "a",
+        "\u{301}",
+        "\u{200d}",
+        "\u{1f1e6}",
+        "\u{1f466}",
+        "\r",
+        "\n",
+        "\u{600}",
+    ];
+    for s in &strings {
+        for &is_extended in &[true, false] {
+            let boundaries = s
+                .grapheme_indices(is_extended)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            for offset in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+                for insert in &inserts {
+                    let t = [&s[..offset], insert, &s[offset..]].concat();
+                    let t_boundaries = t
+                        .grapheme_indices(is_extended)
+                        .map(|(i, _)| i)
+                        .chain(Some(t.len()))
+                        .collect::<Vec<_>>();
+                    let is_boundary = |i| i == 0 || i == t.len() || t_boundaries.contains(&i);
+                    let expected =
+                        !(offset == 0 || offset == s.len() || boundaries.contains(&offset))
+                            || !is_boundary(offset)
+                            || !is_boundary(offset + insert.len());
+                    assert_eq!(
+                        insertion_splits_cluster(s, offset, insert, is_extended),
+                        expected,
+                        "{:?} into {:?} at {}",
+                        insert,
+                        s,
+                        offset
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_would_join_across() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::would_join_across;
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let boundaries = s
+                .grapheme_indices(is_extended)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            for (i, _) in s.char_indices().skip(1) {
+                assert_eq!(
+                    would_join_across(&s[..i], &s[i..], is_extended),
+                    !boundaries.contains(&i),
+                    "{:?} at {}",
+                    s,
+                    i
+                );
+            }
+        }
+    }
+    assert!(!would_join_across("", "\u{301}", true));
+    assert!(!would_join_across("a", "", true));
+}
+
+#[test]
+fn test_trailing_incomplete_cluster_len() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::trailing_incomplete_cluster_len;
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let clusters = s.graphemes(is_extended).collect::<Vec<_>>();
+            for (i, _) in s.char_indices().skip(1) {
+                // whatever follows, the clusters before the held back bytes are final
+                let chunk = &s[..i];
+                let held_back = trailing_incomplete_cluster_len(chunk, is_extended);
+                let stable = &chunk[..chunk.len() - held_back];
+                let stable_clusters = stable.graphemes(is_extended).collect::<Vec<_>>();
+                assert_eq!(
+                    stable_clusters,
+                    clusters[..stable_clusters.len()],
+                    "{:?}",
+                    chunk
+                );
+                if held_back == 0 {
+                    assert_eq!(clusters[..stable_clusters.len()].concat(), chunk);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_len_at() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let mut expected = vec![None; s.len() + 1];
+            for (i, g) in s.grapheme_indices(is_extended) {
+                expected[i] = Some(g.len());
+            }
+            for (offset, &len) in expected.iter().enumerate() {
+                assert_eq!(
+                    s.grapheme_len_at(offset, is_extended),
+                    len,
+                    "{} in {:?}",
+                    offset,
+                    s
+                );
+            }
+            assert_eq!(s.grapheme_len_at(s.len() + 1, is_extended), None);
+        }
+    }
+}
+
+#[test]
+fn test_snap_to_grapheme_boundary() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    // "e\u{301}" spans bytes 0..3, "\u{1F600}" 3..7 and "\r\n" 7..9
+    let s = "e\u{301}\u{1F600}\r\n";
+    let expected = [0, 0, 3, 3, 3, 3, 7, 7, 7, 9, 9];
+    for (offset, &snapped) in expected.iter().enumerate() {
+        assert_eq!(
+            s.snap_to_grapheme_boundary(offset, true),
+            snapped,
+            "{}",
+            offset
+        );
+    }
+    assert_eq!("".snap_to_grapheme_boundary(0, true), 0);
+    assert_eq!("".snap_to_grapheme_boundary(3, true), 0);
+
+    // compare with the nearest of the expected boundaries, for every offset
+    fn check(s: &str, clusters: &[&str], is_extended: bool) {
+        let boundaries = core::iter::once(0)
+            .chain(clusters.iter().scan(0, |end, c| {
+                *end += c.len();
+                Some(*end)
+            }))
+            .collect::<Vec<_>>();
+        for offset in 0..=s.len() + 1 {
+            let nearest = boundaries
+                .iter()
+                .copied()
+                .min_by_key(|&b| (b.abs_diff(offset), b))
+                .unwrap();
+            assert_eq!(
+                s.snap_to_grapheme_boundary(offset, is_extended),
+                nearest,
+                "{:?} {}",
+                s,
+                offset
+            );
+        }
+    }
+    for &(s, g) in TEST_SAME {
+        check(s, g, true);
+        check(s, g, false);
+    }
+    for &(s, gt, gf) in TEST_DIFF {
+        check(s, gt, true);
+        check(s, gf, false);
+    }
+}
+
+#[test]
+fn test_in_emoji_sequence() {
+    let cases: &[(&str, bool)] = &[
+        ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", true),
+        ("\u{1F44D}\u{1F3FD}", true),
+        ("\u{2764}\u{FE0F}", true),
+        ("\u{A9}\u{FE0F}", true),
+        ("\u{1F1EB}\u{1F1F7}", true),
+        ("1\u{FE0F}\u{20E3}", true),
+        ("#\u{20E3}", true),
+        (
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+            true,
+        ),
+        ("\u{1F600}", false),
+        ("\u{1F1EB}", false),
+        ("e\u{301}", false),
+        ("1\u{FE0F}", false),
+        ("\r\n", false),
+    ];
+    for &(cluster, expected) in cases {
+        // surrounded by other clusters, every offset inside the cluster gives the same answer
+        let s = format!("a{}b", cluster);
+        assert!(!s.in_emoji_sequence(0), "{:?}", cluster);
+        for offset in 1..=cluster.len() {
+            assert_eq!(
+                s.in_emoji_sequence(offset),
+                expected,
+                "{:?} {}",
+                cluster,
+                offset
+            );
+        }
+        assert!(!s.in_emoji_sequence(s.len() - 1), "{:?}", cluster);
+        assert!(!s.in_emoji_sequence(s.len()), "{:?}", cluster);
+    }
+    assert!(!"".in_emoji_sequence(0));
+}
+
+#[test]
+fn test_last_grapheme_boundary() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let cases: &[(&str, usize)] = &[
+        ("", 0),
+        ("a", 0),
+        ("ab", 1),
+        ("a\r\n", 1),
+        ("e\u{301}\u{301}", 0),
+        // regional indicators pair up from the start of the run
+        ("\u{1F1EB}\u{1F1F7}", 0),
+        ("\u{1F1EB}\u{1F1F7}\u{1F1E9}", 8),
+        ("\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}", 8),
+        ("a\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}\u{1F1EF}", 17),
+        ("a\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}\u{301}", 9),
+        ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 0),
+    ];
+    for &(s, expected) in cases {
+        assert_eq!(s.last_grapheme_boundary(true), expected, "{:?}", s);
+    }
+
+    for &(s, g) in TEST_SAME {
+        let expected = s.len() - g.last().map_or(0, |g| g.len());
+        assert_eq!(s.last_grapheme_boundary(true), expected, "{:?}", s);
+        assert_eq!(s.last_grapheme_boundary(false), expected, "{:?}", s);
+    }
+    for &(s, gt, gf) in TEST_DIFF {
+        let expected = s.len() - gt.last().map_or(0, |g| g.len());
+        assert_eq!(s.last_grapheme_boundary(true), expected, "{:?}", s);
+        let expected = s.len() - gf.last().map_or(0, |g| g.len());
+        assert_eq!(s.last_grapheme_boundary(false), expected, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_is_single_grapheme() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    for &(s, g) in TEST_SAME.iter() {
+        assert_eq!(s.is_single_grapheme(true), g.len() == 1, "{:?}", s);
+        for &cluster in g {
+            assert!(cluster.is_single_grapheme(true));
+        }
+    }
+    for &(s, gt, gf) in TEST_DIFF.iter() {
+        assert_eq!(s.is_single_grapheme(true), gt.len() == 1, "{:?}", s);
+        assert_eq!(s.is_single_grapheme(false), gf.len() == 1, "{:?}", s);
+    }
+    assert!(!"".is_single_grapheme(false));
+}
+
+#[test]
+fn test_display_unit_count() {
+    let tests: &[(&str, usize)] = &[
+        ("", 0),
+        ("Hello, world!", 13),
+        // combining marks and invisible format characters
+        ("a\u{310}e\u{301}o\u{308}\u{332}", 3),
+        ("\u{feff}a\u{200b}b\u{2060}", 2),
+        // CJK and fullwidth forms
+        ("\u{65e5}\u{672c}\u{8a9e}", 6),
+        ("\u{d55c}\u{1100}\u{1161}\u{11a8}", 4),
+        ("\u{ff21}\u{ff62}\u{ff71}", 4),
+        // emoji, emoji sequences and flags
+        ("\u{1f600}\u{1f44d}\u{1f3fd}", 4),
+        ("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}", 2),
+        ("\u{263a} \u{263a}\u{fe0f}", 4),
+        ("\u{1f1ef}\u{1f1f5}\u{1f1e6}", 3),
+        ("a\tb\r\n", 4),
+    ];
+    for &(s, count) in tests {
+        assert_eq!(s.display_unit_count(), count, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_grapheme_chunks() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let clusters = s.graphemes(is_extended).collect::<Vec<_>>();
+            for size in 1..=clusters.len() + 1 {
+                let chunks = s.grapheme_chunks(size, is_extended).collect::<Vec<_>>();
+                let expected = clusters
+                    .chunks(size)
+                    .map(|c| c.concat())
+                    .collect::<Vec<_>>();
+                assert_eq!(chunks, expected, "{:?} in chunks of {}", s, size);
+                assert_eq!(chunks.concat(), s);
+            }
+        }
+    }
+    assert_eq!("".grapheme_chunks(3, true).next(), None);
+}
+
+#[test]
+fn test_grapheme_windows() {
+    let tests: &[(&str, usize, &[&str])] = &[
+        ("", 4, &[]),
+        ("Hello, world!", 5, &["Hello", ", wor", "ld!"]),
+        ("Hello", 100, &["Hello"]),
+        // wide clusters never straddle a window
+        ("a\u{65e5}\u{672c}b", 2, &["a", "\u{65e5}", "\u{672c}", "b"]),
+        ("a\u{65e5}\u{672c}b", 3, &["a\u{65e5}", "\u{672c}b"]),
+        // clusters wider than the window are yielded alone
+        ("\u{1f1ef}\u{1f1f5}x", 1, &["\u{1f1ef}\u{1f1f5}", "x"]),
+        ("ab", 0, &["a", "b"]),
+        // invisible clusters fill up the current window
+        ("ab\u{200b}\u{200b}c", 2, &["ab\u{200b}\u{200b}", "c"]),
+        (
+            "a\u{301}b\u{301}c\u{301}",
+            2,
+            &["a\u{301}b\u{301}", "c\u{301}"],
+        ),
+    ];
+    for &(s, max_width, expected) in tests {
+        let windows = s.grapheme_windows(max_width, true).collect::<Vec<_>>();
+        assert_eq!(windows, expected, "{:?} in {}", s, max_width);
+    }
+
+    // legacy clusters split a spacing mark from its base
+    let s = "\u{915}\u{93f}";
+    assert_eq!(s.grapheme_windows(1, true).collect::<Vec<_>>(), [s]);
+    assert_eq!(
+        s.grapheme_windows(1, false).collect::<Vec<_>>(),
+        ["\u{915}", "\u{93f}"]
+    );
+}
+
+#[test]
+fn test_grapheme_count_to() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let cases = TEST_SAME
+        .iter()
+        .map(|&(s, g)| (s, g, true))
+        .chain(TEST_DIFF.iter().map(|&(s, _, g)| (s, g, false)));
+    for (s, g, is_extended) in cases {
+        let mut end = 0;
+        for (i, cluster) in g.iter().enumerate() {
+            for offset in end..end + cluster.len() {
+                assert_eq!(s.grapheme_count_to(offset, is_extended), i);
+            }
+            end += cluster.len();
+        }
+        assert_eq!(s.grapheme_count_to(s.len(), is_extended), g.len());
+    }
+}
+
+#[test]
+fn test_grapheme_resume_token_bytes() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::GraphemeResumeToken;
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            for offset in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+                let token = s.grapheme_state_at(offset, is_extended);
+                let bytes = token.to_bytes();
+                let decoded = GraphemeResumeToken::from_bytes(bytes).unwrap();
+                assert_eq!(decoded.offset(), token.offset());
+                assert_eq!(decoded.is_extended(), is_extended);
+                assert_eq!(decoded.to_bytes(), bytes);
+                assert!(s.resume_graphemes(decoded).eq(s.resume_graphemes(token)));
+            }
+        }
+    }
+
+    let mut bytes = "abc".grapheme_state_at(1, true).to_bytes();
+    bytes[16] = 2;
+    assert!(GraphemeResumeToken::from_bytes(bytes).is_none());
+    // an offset past the end
+    bytes[..8].copy_from_slice(&4u64.to_le_bytes());
+    bytes[16] = 1;
+    assert!(GraphemeResumeToken::from_bytes(bytes).is_none());
+}
+
+#[test]
+fn test_grapheme_count_bytes() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::grapheme_count_bytes;
+
+    for &(s, g) in TEST_SAME.iter() {
+        assert_eq!(grapheme_count_bytes(s.as_bytes(), true), g.len());
+        assert_eq!(grapheme_count_bytes(s.as_bytes(), false), g.len());
+    }
+    for &(s, gt, gf) in TEST_DIFF.iter() {
+        assert_eq!(grapheme_count_bytes(s.as_bytes(), true), gt.len());
+        assert_eq!(grapheme_count_bytes(s.as_bytes(), false), gf.len());
+    }
+
+    // every invalid byte is a cluster of its own, and clusters don't join across them
+    assert_eq!(grapheme_count_bytes(b"", true), 0);
+    assert_eq!(grapheme_count_bytes(b"\xff", true), 1);
+    assert_eq!(grapheme_count_bytes(b"e\xff\xcc\x81", true), 3);
+    assert_eq!(grapheme_count_bytes(b"\r\x80\n", true), 3);
+    // a truncated sequence at the end
+    assert_eq!(grapheme_count_bytes(b"a\xf0\x9f\x87", true), 4);
+    // ..and in the middle, where the byte that cut it short is valid
+    assert_eq!(grapheme_count_bytes(b"a\xf0\x9fb", true), 4);
+    // overlong encodings, surrogates and values past U+10FFFF
+    assert_eq!(grapheme_count_bytes(b"\xc0\x80", true), 2);
+    assert_eq!(grapheme_count_bytes(b"\xe0\x80\x80", true), 3);
+    assert_eq!(grapheme_count_bytes(b"\xed\xa0\x80", true), 3);
+    assert_eq!(grapheme_count_bytes(b"\xf4\x90\x80\x80", true), 4);
+    // flags and emoji sequences on either side of an invalid byte
+    let flags = "\u{1f1f7}\u{1f1fa}\u{1f1f8}";
+    assert_eq!(grapheme_count_bytes(flags.as_bytes(), true), 2);
+    let s = [flags.as_bytes(), b"\xff", flags.as_bytes()].concat();
+    assert_eq!(grapheme_count_bytes(&s, true), 5);
+    let s = [
+        "\u{1f468}\u{200d}".as_bytes(),
+        b"\xff",
+        "\u{1f469}".as_bytes(),
+    ]
+    .concat();
+    assert_eq!(grapheme_count_bytes(&s, true), 3);
+}
+
+#[test]
+fn test_find_safe_grapheme_boundary() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::find_safe_grapheme_boundary;
+
+    let flags = "\u{1f1e6}\u{1f1eb}".repeat(40);
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}";
+    let extra = [
+        format!("ab{}c", flags),
+        format!("a{}\u{1f1e6}b", flags),
+        format!("x {}{}\u{1f44d}\u{1f3fc}y", family, family),
+        format!("e{}f", "\u{301}".repeat(100)),
+        format!(
+            "\u{915}\u{94d}\u{915}{}\u{915}\u{94d}\u{200d}\u{937} a",
+            flags
+        ),
+    ];
+    for s in TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s))
+        .chain(extra.iter().map(|s| &s[..]))
+    {
+        let boundaries = |is_extended| {
+            let mut b = s
+                .grapheme_indices(is_extended)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            b.push(s.len());
+            b
+        };
+        let (extended, legacy) = (boundaries(true), boundaries(false));
+        for approx in 0..s.len() + 2 {
+            let offset = find_safe_grapheme_boundary(s, approx);
+            assert!(offset <= approx, "{} for {} in {:?}", offset, approx, s);
+            assert!(
+                extended.contains(&offset) && legacy.contains(&offset),
+                "{} for {} in {:?}",
+                offset,
+                approx,
+                s
+            );
+            if offset > 0 && approx < s.len() {
+                let mut floor = approx;
+                while !s.is_char_boundary(floor) {
+                    floor -= 1;
+                }
+                assert!(s[offset..floor].chars().count() <= 64);
+            }
+        }
+    }
+
+    // the flags and the emoji sequences are never split, so everything is pushed back to
+    // the character before them
+    let s = &extra[0];
+    assert_eq!(find_safe_grapheme_boundary(s, 10), 2);
+    assert_eq!(find_safe_grapheme_boundary(s, s.len() - 1), s.len() - 1);
+    let s = &extra[2];
+    assert_eq!(find_safe_grapheme_boundary(s, 5), 2);
+    assert_eq!(
+        find_safe_grapheme_boundary(s, 2 + family.len() + 3),
+        2 + family.len()
+    );
+    // too far back to find where the indicators start
+    assert_eq!(find_safe_grapheme_boundary(&extra[1], 300), 0);
+}
+
+#[test]
+fn test_recent_emoji() {
+    // The tables are generated from Unicode 16.0; these pin the behavior for emoji and emoji
+    // sequences added since 14.0, each of which is a single cluster and a single word segment.
+    let emoji = [
+        // 14.0: melting face, hamsa, handshake with skin tones
+        "\u{1fae0}",
+        "\u{1faac}",
+        "\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3ff}",
+        // 15.0: shaking face, pink heart, moose, jellyfish
+        "\u{1fae8}",
+        "\u{1fa77}",
+        "\u{1face}",
+        "\u{1fabc}",
+        // 15.1: phoenix, lime, head shaking horizontally, person running facing right,
+        // broken chain, family: adult, adult, child
+        "\u{1f426}\u{200d}\u{1f525}",
+        "\u{1f34b}\u{200d}\u{1f7e9}",
+        "\u{1f642}\u{200d}\u{2194}\u{fe0f}",
+        "\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}",
+        "\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}",
+        "\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}",
+        // 16.0: face with bags under eyes, fingerprint
+        "\u{1fae9}",
+        "\u{1fac6}",
+    ];
+    for &e in &emoji {
+        for &is_extended in &[true, false] {
+            assert_eq!(e.graphemes(is_extended).collect::<Vec<_>>(), [e]);
+        }
+        assert_eq!(e.split_word_bounds().collect::<Vec<_>>(), [e]);
+
+        let s = format!("a{}{}b {}", e, e, e);
+        assert_eq!(
+            s.graphemes(true).collect::<Vec<_>>(),
+            ["a", e, e, "b", " ", e],
+            "{:?}",
+            s
+        );
+        assert_eq!(
+            s.split_word_bounds().collect::<Vec<_>>(),
+            ["a", e, e, "b", " ", e],
+            "{:?}",
+            s
+        );
+        assert!(s
+            .split_word_bounds()
+            .rev()
+            .eq(["a", e, e, "b", " ", e].iter().rev().cloned()));
+    }
+}
+
+#[test]
+fn test_word_break_property() {
+    use unicode_segmentation::{word_break_property, WordBreakProperty as WB};
+
+    let cases = [
+        ('a', WB::ALetter),
+        ('\u{5D0}', WB::HebrewLetter),
+        ('\u{30A2}', WB::Katakana),
+        ('7', WB::Numeric),
+        ('_', WB::ExtendNumLet),
+        ('.', WB::MidNumLet),
+        (':', WB::MidLetter),
+        (',', WB::MidNum),
+        ('\'', WB::SingleQuote),
+        ('"', WB::DoubleQuote),
+        (' ', WB::WSegSpace),
+        ('\r', WB::CR),
+        ('\n', WB::LF),
+        ('\u{2028}', WB::Newline),
+        ('\u{301}', WB::Extend),
+        ('\u{AD}', WB::Format),
+        ('\u{200D}', WB::ZWJ),
+        ('\u{1F1E8}', WB::RegionalIndicator),
+        ('$', WB::Other),
+        ('\u{4E2D}', WB::Other),
+    ];
+    for &(c, prop) in cases.iter() {
+        assert_eq!(word_break_property(c), prop, "{:?}", c);
+    }
+}
+
+#[test]
+fn test_is_word_segment() {
+    use crate::testdata::TEST_WORD;
+    use unicode_segmentation::is_word_segment;
+
+    assert!(is_word_segment("hello"));
+    assert!(is_word_segment("42"));
+    assert!(is_word_segment("\u{4E2D}"));
+    assert!(!is_word_segment(" "));
+    assert!(!is_word_segment(","));
+    assert!(!is_word_segment("\n"));
+    assert!(!is_word_segment("\u{1F1E8}\u{1F1E6}"));
+    assert!(!is_word_segment(""));
+
+    // agrees with `unicode_words`
+    for &(s, _) in TEST_WORD.iter() {
+        assert!(s
+            .split_word_bounds()
+            .filter(|w| is_word_segment(w))
+            .eq(s.unicode_words()));
+    }
+}
+
+#[test]
+fn test_next_word_boundary_in() {
+    use crate::testdata::TEST_WORD;
+    use unicode_segmentation::next_word_boundary_in;
+
+    let extra: &[&str] = &[
+        "a b  c\r\n \u{301}d\u{200d} e\n\n\u{1f1e6}\u{1f1e8}\u{1f1e6} x:y 3.4",
+        "\u{5d0}\"\u{5d1} \u{3000} \u{2060}z",
+    ];
+    for s in TEST_WORD
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(extra.iter().cloned())
+    {
+        let boundaries = s
+            .split_word_bound_indices()
+            .map(|(i, w)| i + w.len())
+            .collect::<Vec<_>>();
+        for from in 0..s.len() {
+            let expected = boundaries.iter().cloned().find(|&b| b > from);
+            assert_eq!(
+                next_word_boundary_in(s, from),
+                expected,
+                "{} in {:?}",
+                from,
+                s
+            );
+        }
+        assert_eq!(next_word_boundary_in(s, s.len()), None);
+    }
+}
+
+#[test]
+fn test_first_word_offset() {
+    let cases: &[(&str, Option<usize>)] = &[
+        ("", None),
+        ("word", Some(0)),
+        ("   word", Some(3)),
+        ("\t\r\n\u{3000}word", Some(6)),
+        ("...--!? word", Some(8)),
+        ("\u{1F600} word", Some(5)),
+        ("\u{1F468}\u{200D}\u{1F469} 42", Some(12)),
+        ("\u{1F1E6}\u{1F1E8}", None),
+        ("  , ; ", None),
+    ];
+    for &(s, expected) in cases {
+        assert_eq!(s.first_word_offset(), expected, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_lex_words() {
+    use crate::testdata::TEST_WORD;
+    use unicode_segmentation::WordBoundKind::{self, *};
+
+    let cases: &[(&str, &[(WordBoundKind, &str)])] = &[
+        ("", &[]),
+        (
+            "Mr. O'Neil paid $1,000.50!",
+            &[
+                (Word, "Mr"),
+                (Punctuation, "."),
+                (Whitespace, " "),
+                (Word, "O'Neil"),
+                (Whitespace, " "),
+                (Word, "paid"),
+                (Whitespace, " "),
+                (Symbol, "$"),
+                (Number, "1,000.50"),
+                (Punctuation, "!"),
+            ],
+        ),
+        (
+            "a__b  \r\n\u{3000}\u{1F44D}\u{1F3FD}",
+            &[
+                (Word, "a__b"),
+                (Whitespace, "  "),
+                (Whitespace, "\r\n"),
+                (Whitespace, "\u{3000}"),
+                (Symbol, "\u{1F44D}\u{1F3FD}"),
+            ],
+        ),
+        (
+            "10km \u{4e2d}\u{0661}\u{0662} \u{ab}\u{2014}\u{20ac}\u{1F1EB}\u{1F1F7}",
+            &[
+                (Word, "10km"),
+                (Whitespace, " "),
+                (Word, "\u{4e2d}"),
+                (Number, "\u{0661}\u{0662}"),
+                (Whitespace, " "),
+                (Punctuation, "\u{ab}"),
+                (Punctuation, "\u{2014}"),
+                (Symbol, "\u{20ac}"),
+                (Symbol, "\u{1F1EB}\u{1F1F7}"),
+            ],
+        ),
+        ("__ ", &[(Punctuation, "__"), (Whitespace, " ")]),
+        // punctuation added in Unicode 15.0 and 16.0
+        (
+            "\u{11f43}\u{1b4e}",
+            &[(Punctuation, "\u{11f43}"), (Punctuation, "\u{1b4e}")],
+        ),
+    ];
+    for &(s, expected) in cases {
+        let tokens = s
+            .lex_words()
+            .map(|(kind, range)| (kind, &s[range]))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected, "{:?}", s);
+    }
+
+    // the ranges are those of the word bounds, from either end
+    for &(s, _) in TEST_WORD.iter() {
+        let ranges = s.lex_words().map(|(_, range)| range).collect::<Vec<_>>();
+        let bounds = s
+            .split_word_bound_indices()
+            .map(|(i, w)| i..i + w.len())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, bounds);
+        let mut rev = s.lex_words().rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, s.lex_words().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_graphemes_coalesce() {
+    use crate::testdata::TEST_SAME;
+
+    let s = "ab  c\u{301}\u{200b}\u{2060}d\u{200b}";
+    let both_lower =
+        |a: &str, b: &str| a.chars().all(char::is_lowercase) && b.chars().all(char::is_lowercase);
+    let gr = s.graphemes_coalesce(true, both_lower).collect::<Vec<_>>();
+    assert_eq!(
+        gr,
+        ["ab", " ", " ", "c\u{301}", "\u{200b}", "\u{2060}", "d", "\u{200b}"]
+    );
+    // attach default ignorables to the cluster before them
+    let ignorable = |_: &str, b: &str| b == "\u{200b}" || b == "\u{2060}";
+    let gr = s.graphemes_coalesce(true, ignorable).collect::<Vec<_>>();
+    assert_eq!(
+        gr,
+        ["a", "b", " ", " ", "c\u{301}\u{200b}\u{2060}", "d\u{200b}"]
+    );
+    assert!(s
+        .graphemes_coalesce(true, ignorable)
+        .rev()
+        .eq(gr.iter().rev().cloned()));
+
+    // merging everything gives the whole string, and nothing gives the clusters
+    for &(s, g) in TEST_SAME.iter() {
+        let all = s.graphemes_coalesce(true, |_, _| true).collect::<Vec<_>>();
+        assert_eq!(all, [s]);
+        assert!(s
+            .graphemes_coalesce(true, |_, _| false)
+            .eq(g.iter().cloned()));
+        assert!(s
+            .graphemes_coalesce(true, |_, _| false)
+            .rev()
+            .eq(g.iter().rev().cloned()));
+    }
+}
+
+#[test]
+fn test_long_regional_indicator_runs() {
+    use unicode_segmentation::GraphemeCursor;
+
+    // Long runs must be paired the same way from either end, without rescanning the run for
+    // every pair, which would make reverse iteration quadratic in its length.
+    for &n in &[20_000, 20_001] {
+        let s = format!("a{}\u{301}b", "\u{1F1E6}".repeat(n));
+
+        let graphemes = s.graphemes(true).collect::<Vec<_>>();
+        assert_eq!(graphemes.len(), 2 + n.div_ceil(2));
+        assert!(s.graphemes(true).rev().eq(graphemes.iter().rev().cloned()));
+        let mut cursor = GraphemeCursor::new(s.len(), s.len(), true);
+        let mut count = 0;
+        while cursor.prev_boundary(&s, 0).unwrap().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, graphemes.len());
+
+        let words = s.split_word_bounds().collect::<Vec<_>>();
+        assert_eq!(words.len(), 2 + n.div_ceil(2));
+        assert!(s.split_word_bounds().rev().eq(words.iter().rev().cloned()));
+        assert_eq!(
+            words[words.len() - 2].len(),
+            if n % 2 == 0 { 10 } else { 6 }
+        );
+    }
+}
+
+#[test]
+fn test_char_segments() {
+    use crate::testdata::{TEST_SAME, TEST_WORD};
+
+    assert_eq!("".char_segments().next(), None);
+    for &(s, _) in TEST_SAME.iter().chain(TEST_WORD.iter()) {
+        let segments = s.char_segments().collect::<Vec<_>>();
+        assert_eq!(segments.concat(), s);
+        assert!(segments.iter().map(|c| c.chars().count()).all(|n| n == 1));
+        assert!(s.char_segments().rev().eq(segments.iter().rev().cloned()));
+
+        let (lower, upper) = s.char_segments().size_hint();
+        assert!(lower <= segments.len() && upper >= Some(segments.len()));
+    }
+
+    let mut iter = "a\u{1F600}b\u{e9}".char_segments();
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next_back(), Some("\u{e9}"));
+    assert_eq!(iter.next_back(), Some("b"));
+    assert_eq!(iter.next(), Some("\u{1F600}"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_graphemes_single_cluster() {
+    let singles = &[
+        "a",
+        "e\u{301}",
+        "\u{1F44D}\u{1F3FD}",
+        "\u{1F1EF}\u{1F1F5}",
+        "1\u{FE0F}\u{20E3}",
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    ];
+    for s in singles {
+        let mut iter = s.graphemes(true);
+        assert_eq!(iter.size_hint(), (1, Some(1)), "{:?}", s);
+        assert_eq!(iter.next(), Some(*s));
+        assert_eq!(iter.as_str(), "");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = s.graphemes(true);
+        assert_eq!(iter.next_back(), Some(*s));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.offset(), 0);
+    }
+
+    // close to a single cluster, but not quite
+    let cases: &[(&str, &[&str])] = &[
+        ("\r\u{301}", &["\r", "\u{301}"]),
+        (
+            "\u{1F1EF}\u{1F1F5}\u{1F1FA}",
+            &["\u{1F1EF}\u{1F1F5}", "\u{1F1FA}"],
+        ),
+        ("a\u{200D}\u{1F467}", &["a\u{200D}", "\u{1F467}"]),
+        (
+            "\u{1F600}\u{FE0F}\u{200D}\u{FE0F}\u{200D}\u{1F600}",
+            &["\u{1F600}\u{FE0F}\u{200D}\u{FE0F}\u{200D}", "\u{1F600}"],
+        ),
+        ("\u{915}\u{903}", &["\u{915}\u{903}"]),
+    ];
+    for &(s, expected) in cases {
+        let graphemes = s.graphemes(true).collect::<Vec<_>>();
+        assert_eq!(graphemes, expected, "{:?}", s);
+    }
+    assert_eq!(
+        "\u{915}\u{903}".graphemes(false).collect::<Vec<_>>(),
+        &["\u{915}", "\u{903}"]
+    );
+}
+
+#[test]
+fn test_graphemes_with_first_char() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let expected = s
+                .graphemes(is_extended)
+                .map(|g| (g, g.chars().next().unwrap()))
+                .collect::<Vec<_>>();
+            let firsts = s.graphemes_with_first_char(is_extended).collect::<Vec<_>>();
+            assert_eq!(firsts, expected);
+            let mut firsts_rev = s
+                .graphemes_with_first_char(is_extended)
+                .rev()
+                .collect::<Vec<_>>();
+            firsts_rev.reverse();
+            assert_eq!(firsts_rev, expected);
+        }
+    }
+}
+
+#[test]
+fn test_graphemes_with_hash() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use std::collections::HashMap;
+
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s));
+    let mut seen = HashMap::new();
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let hashes = s.graphemes_with_hash(is_extended).collect::<Vec<_>>();
+            assert!(hashes.iter().map(|&(g, _)| g).eq(s.graphemes(is_extended)));
+            assert!(hashes
+                .iter()
+                .rev()
+                .copied()
+                .eq(s.graphemes_with_hash(is_extended).rev()));
+            // the clusters of the test data are all told apart by their hashes
+            for &(cluster, hash) in &hashes {
+                assert_eq!(*seen.entry(hash).or_insert(cluster), cluster);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_ranges() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    let cases = TEST_SAME
+        .iter()
+        .map(|&(s, _)| (s, true))
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| (s, true)))
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| (s, false)));
+    for (s, is_extended) in cases {
+        let expected = s
+            .grapheme_indices(is_extended)
+            .map(|(i, g)| (i..i + g.len(), g))
+            .collect::<Vec<_>>();
+        let found = s.grapheme_ranges(is_extended).collect::<Vec<_>>();
+        assert_eq!(found, expected, "{:?}", s);
+        let mut rev = s.grapheme_ranges(is_extended).rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, expected, "{:?}", s);
+        for (range, g) in found {
+            assert_eq!(&s[range], g);
+        }
+    }
+}
+
+#[test]
+fn test_graphemes_mut() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::new_graphemes_mut;
+
+    let cases = TEST_SAME
+        .iter()
+        .map(|&(s, _)| (s, true))
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| (s, true)))
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| (s, false)));
+    for (s, is_extended) in cases {
+        let mut owned = s.to_owned();
+        let expected = s.graphemes(is_extended).collect::<Vec<_>>();
+        let found = new_graphemes_mut(&mut owned, is_extended).collect::<Vec<_>>();
+        assert_eq!(found, expected, "{:?}", s);
+        let mut rev = new_graphemes_mut(&mut owned, is_extended)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, expected, "{:?}", s);
+    }
+
+    // clusters from both ends can be held and changed at once
+    let mut s = String::from("ab\u{301}cd");
+    let mut iter = new_graphemes_mut(&mut s, true);
+    let first = iter.next().unwrap();
+    let last = iter.next_back().unwrap();
+    let middle = iter.collect::<Vec<_>>();
+    first.make_ascii_uppercase();
+    last.make_ascii_uppercase();
+    for cluster in middle {
+        cluster.make_ascii_uppercase();
+    }
+    assert_eq!(s, "AB\u{301}CD");
+    assert_eq!(new_graphemes_mut(&mut String::new(), true).next(), None);
+}
+
+#[test]
+fn test_grapheme_indices_offset() {
+    use crate::testdata::TEST_SAME;
+
+    for &(s, _) in TEST_SAME.iter() {
+        let mut iter = s.grapheme_indices(true);
+        loop {
+            let offset = iter.offset();
+            assert_eq!(&s[offset..], iter.as_str());
+            match iter.next() {
+                Some((i, _)) => assert_eq!(i, offset, "{:?}", s),
+                None => break,
+            }
+        }
+    }
+
+    let mut iter = "ab".grapheme_indices(true);
+    iter.next_back();
+    assert_eq!(iter.offset(), 0);
+    iter.next();
+    assert_eq!(iter.offset(), 1);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.offset(), 1);
+}
+
+#[test]
+fn test_grapheme_boundary_indices() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    assert_eq!("".grapheme_boundary_indices(true).collect::<Vec<_>>(), [0]);
+
+    for &(s, _) in TEST_SAME {
+        let expected = s
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(Some(s.len()))
+            .collect::<Vec<_>>();
+        let found = s.grapheme_boundary_indices(true).collect::<Vec<_>>();
+        assert_eq!(found, expected, "{:?}", s);
+    }
+
+    for &(s, _, _) in TEST_DIFF {
+        let expected = s
+            .grapheme_indices(false)
+            .map(|(i, _)| i)
+            .chain(Some(s.len()))
+            .collect::<Vec<_>>();
+        let found = s.grapheme_boundary_indices(false).collect::<Vec<_>>();
+        assert_eq!(found, expected, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_grapheme_mode_diff() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+
+    for &(s, _) in TEST_SAME {
+        assert_eq!(s.grapheme_mode_diff().next(), None, "{:?}", s);
+    }
+
+    for &(s, gt, gf) in TEST_DIFF {
+        let offsets = |g: &[&str]| {
+            g.iter()
+                .scan(0, |offset, c| {
+                    *offset += c.len();
+                    Some(*offset)
+                })
+                .collect::<Vec<_>>()
+        };
+        let extended = offsets(gt);
+        let legacy = offsets(gf);
+        let mut expected = extended
+            .iter()
+            .filter(|b| !legacy.contains(b))
+            .chain(legacy.iter().filter(|b| !extended.contains(b)))
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(
+            s.grapheme_mode_diff().collect::<Vec<_>>(),
+            expected,
+            "{:?}",
+            s
+        );
+        assert!(!expected.is_empty());
+    }
+}
+
+#[test]
+fn test_grapheme_boundaries_in() {
+    use crate::testdata::TEST_SAME;
+
+    let extra: &[&str] = &["\u{1f1e6}\u{1f1e8}\u{1f1e6}\u{1f1e9}\u{1f1e6}\u{1f1ea}\u{1f1e6}"];
+    for s in TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(extra.iter().cloned())
+    {
+        let all = s
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(Some(s.len()))
+            .collect::<Vec<_>>();
+        let offsets = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(Some(s.len()))
+            .collect::<Vec<_>>();
+        for &start in &offsets {
+            for &end in offsets.iter().filter(|&&end| end >= start) {
+                let inside = all
+                    .iter()
+                    .cloned()
+                    .filter(|&b| start < b && b < end)
+                    .collect::<Vec<_>>();
+                let found = s
+                    .grapheme_boundaries_in(start..end, true)
+                    .collect::<Vec<_>>();
+                assert_eq!(found, inside, "{:?} in {:?}", start..end, s);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_count() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME, TEST_WORD};
+    use unicode_segmentation::WordOptions;
+
+    for &(s, g) in TEST_SAME.iter() {
+        assert_eq!(s.graphemes(true).count(), g.len(), "graphemes of {:?}", s);
+        assert_eq!(s.graphemes(false).count(), g.len(), "graphemes of {:?}", s);
+    }
+    for &(s, gt, gf) in TEST_DIFF.iter() {
+        assert_eq!(s.graphemes(true).count(), gt.len(), "graphemes of {:?}", s);
+        assert_eq!(s.graphemes(false).count(), gf.len(), "graphemes of {:?}", s);
+    }
+    for &(s, w) in TEST_WORD.iter() {
+        assert_eq!(s.split_word_bounds().count(), w.len(), "words of {:?}", s);
+        let options = WordOptions::new().split_alnum_boundaries(true);
+        assert_eq!(
+            s.split_word_bounds_with(options).count(),
+            s.split_word_bounds_with(options).fold(0, |n, _| n + 1),
+            "words of {:?}",
+            s
+        );
+    }
+
+    // only what is left is counted
+    let mut graphemes = "a\u{301}bcd".graphemes(true);
+    graphemes.next();
+    graphemes.next_back();
+    assert_eq!(graphemes.count(), 2);
+    let mut words = "one two three".split_word_bounds();
+    words.next();
+    words.next_back();
+    assert_eq!(words.count(), 3);
+    assert_eq!("".graphemes(true).count(), 0);
+    assert_eq!("".split_word_bounds().count(), 0);
+}
+
+#[test]
+fn test_iterators_fused() {
+    use std::iter::FusedIterator;
+    use unicode_segmentation::{graphemes_over, word_bounds_bytes, SentenceOptions, WordOptions};
+    use unicode_segmentation::{new_canonical_graphemes, new_graphemes_mut, new_owned_graphemes};
+
+    fn exhaust<I: FusedIterator>(mut iter: I) {
+        while iter.next().is_some() {}
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+    }
+
+    for &s in &[
+        "",
+        "a",
+        "Hello, world! How are you?\r\n\u{1f1fa}\u{1f1f8} a\u{301}.",
+    ] {
+        exhaust(s.graphemes(true));
+        exhaust(s.grapheme_indices(false));
+        exhaust(s.grapheme_ranges(true));
+        exhaust(s.graphemes_with_first_char(true));
+        exhaust(new_graphemes_mut(&mut s.to_owned(), true));
+        exhaust(s.graphemes_with_hash(true));
+        exhaust(s.graphemes_coalesce(true, |a, b| a == b));
+        exhaust(new_owned_graphemes(s, true));
+        exhaust(new_canonical_graphemes(s, true));
+        exhaust(s.graphemes_with_mark_counts(true));
+        exhaust(s.char_segments());
+        exhaust(s.grapheme_windows(3, true));
+        exhaust(s.grapheme_chunks(2, true));
+        exhaust(s.grapheme_boundary_indices(true));
+        exhaust(s.grapheme_mode_diff());
+        exhaust(s.grapheme_boundaries_in(0..s.len(), true));
+        exhaust(graphemes_over(s, s.len(), true));
+        exhaust(s.split_word_bounds());
+        exhaust(s.split_word_bounds_with(WordOptions::new().split_alnum_boundaries(true)));
+        exhaust(s.split_word_bound_indices());
+        exhaust(s.unicode_words());
+        exhaust(s.unicode_word_indices());
+        exhaust(s.lex_words());
+        exhaust(s.mixed_script_words());
+        exhaust(word_bounds_bytes(s.as_bytes()));
+        exhaust(s.split_sentence_bounds());
+        exhaust(s.split_sentence_bounds_with(SentenceOptions::new().split_on_semicolon(true)));
+        exhaust(s.split_sentence_bound_indices());
+        exhaust(s.unicode_sentences());
+        exhaust(s.trimmed_sentences());
+        exhaust(s.sentence_starts());
+        exhaust(s.enumerate_sentences());
+        exhaust(s.words_with_sentence_start());
+        exhaust(s.sentences_with_direction());
+        exhaust(s.lines_with_terminators());
+    }
+}
+
+#[test]
+fn test_for_each_boundary() {
+    use crate::testdata::{TEST_SAME, TEST_SENTENCE, TEST_WORD};
+    use std::ops::ControlFlow;
+
+    fn offsets<'a>(segments: impl Iterator<Item = &'a str>) -> Vec<usize> {
+        let mut offsets = vec![];
+        let mut end = 0;
+        for segment in segments {
+            offsets.push(end);
+            end += segment.len();
+        }
+        if end > 0 {
+            offsets.push(end);
+        }
+        offsets
+    }
+
+    fn visit(
+        for_each: impl FnOnce(&mut dyn FnMut(usize) -> ControlFlow<()>) -> ControlFlow<()>,
+    ) -> Vec<usize> {
+        let mut seen = vec![];
+        let flow = for_each(&mut |offset| {
+            seen.push(offset);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(flow, ControlFlow::Continue(()));
+        seen
+    }
+
+    for &(s, g) in TEST_SAME.iter() {
+        let seen = visit(|f| s.for_each_grapheme_boundary(true, f));
+        assert_eq!(seen, offsets(g.iter().cloned()), "graphemes of {:?}", s);
+    }
+    for &(s, w) in TEST_WORD.iter() {
+        let seen = visit(|f| s.for_each_word_boundary(f));
+        assert_eq!(seen, offsets(w.iter().cloned()), "words of {:?}", s);
+    }
+    for &(s, w) in TEST_SENTENCE.iter() {
+        let seen = visit(|f| s.for_each_sentence_boundary(f));
+        assert_eq!(seen, offsets(w.iter().cloned()), "sentences of {:?}", s);
+    }
+
+    assert!(visit(|f| "".for_each_grapheme_boundary(true, f)).is_empty());
+    assert!(visit(|f| "".for_each_word_boundary(f)).is_empty());
+    assert!(visit(|f| "".for_each_sentence_boundary(f)).is_empty());
+
+    // stopping early
+    let mut calls = 0;
+    let flow = "one two three".for_each_word_boundary(|offset| {
+        calls += 1;
+        if offset == 4 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(flow, ControlFlow::Break(()));
+    assert_eq!(calls, 3);
+}
+
+// Characters from the grapheme, word and sentence break categories that take part in the
+// more involved rules, so that random strings of them exercise those rules far more often
+// than arbitrary strings do.
+const SPECIAL_CHARS: &[char] = &[
+    '\r',
+    '\n',
+    '\u{1}',
+    '\u{85}',
+    '\u{2029}',
+    ' ',
+    '\t',
+    '\u{3000}',
+    '\u{301}',
+    '\u{200c}',
+    '\u{200d}',
+    '\u{ad}',
+    '\u{93e}',
+    '\u{600}',
+    '\u{1100}',
+    '\u{1161}',
+    '\u{11a8}',
+    '\u{ac00}',
+    '\u{ac01}',
+    '\u{1f1e6}',
+    '\u{1f1e8}',
+    '\u{1f468}',
+    '\u{1f3fb}',
+    '\u{915}',
+    '\u{94d}',
+    '\u{e0067}',
+    'a',
+    'A',
+    '\u{5d0}',
+    '1',
+    '\'',
+    '"',
+    '.',
+    '!',
+    '?',
+    ',',
+    ':',
+    ';',
+    '_',
+    '(',
+    ')',
+    '\u{30a2}',
+    '\u{4e2d}',
+    '\u{3005}',
+    '\u{3002}',
+];
+
+fn special_string(picks: &[u8]) -> String {
+    picks
+        .iter()
+        .map(|&i| SPECIAL_CHARS[i as usize % SPECIAL_CHARS.len()])
+        .collect()
+}
+
+// Whether the non-empty segments yielded with their offsets follow each other and cover `s`.
+fn tiles<'a>(s: &str, segments: impl Iterator<Item = (usize, &'a str)>) -> bool {
+    let mut offset = 0;
+    for (i, segment) in segments {
+        if i != offset || segment.is_empty() || s[i..].get(..segment.len()) != Some(segment) {
+            return false;
+        }
+        offset += segment.len();
+    }
+    offset == s.len()
+}
+
+quickcheck! {
+    fn quickcheck_forward_reverse_graphemes_extended(s: String) -> bool {
+        let a = s.graphemes(true).collect::<Vec<_>>();
+        let mut b = s.graphemes(true).rev().collect::<Vec<_>>();
+        b.reverse();
+        a == b
+    }
+
+    fn quickcheck_forward_reverse_graphemes_legacy(s: String) -> bool {
+        let a = s.graphemes(false).collect::<Vec<_>>();
+        let mut b = s.graphemes(false).rev().collect::<Vec<_>>();
+        b.reverse();
+        a == b
+    }
+
+    fn quickcheck_join_graphemes(s: String) -> bool {
+        let a = s.graphemes(true).collect::<String>();
+        let b = s.graphemes(false).collect::<String>();
+        a == s && b == s
+    }
+
+    fn quickcheck_forward_reverse_words(s: String) -> bool {
+        let a = s.split_word_bounds().collect::<Vec<_>>();
+        let mut b = s.split_word_bounds().rev().collect::<Vec<_>>();
+        b.reverse();
+        a == b
+    }
+
+    fn quickcheck_join_words(s: String) -> bool {
+        let a = s.split_word_bounds().collect::<String>();
+        a == s
+    }
+
+    fn quickcheck_join_sentences(s: String) -> bool {
+        let a = s.split_sentence_bounds().collect::<String>();
+        a == s
+    }
+
+    fn quickcheck_join_segments_of_special_chars(picks: Vec<u8>) -> bool {
+        let s = special_string(&picks);
+        [true, false].iter().all(|&is_extended| {
+            s.graphemes(is_extended).collect::<String>() == s
+                && tiles(&s, s.grapheme_indices(is_extended))
+        }) && s.split_word_bounds().collect::<String>() == s
+            && tiles(&s, s.split_word_bound_indices())
+            && s.split_sentence_bounds().collect::<String>() == s
+            && tiles(&s, s.split_sentence_bound_indices())
+    }
+
+    fn quickcheck_forward_reverse_words_with(
+        s: String,
+        join_zwnj: bool,
+        split_alnum: bool,
+        attach_open: bool,
+        ideograph: bool,
+        keep_suffix: bool,
+        distinct_ws: bool
+    ) -> bool {
+        let options = unicode_segmentation::WordOptions::new()
+            .join_zwnj(join_zwnj)
+            .split_alnum_boundaries(split_alnum)
+            .attach_open_punct(attach_open)
+            .ideograph_per_char(ideograph)
+            .keep_number_suffix(keep_suffix)
+            .distinct_whitespace(distinct_ws);
+        let a = s.split_word_bounds_with(options).collect::<Vec<_>>();
+        let mut b = s.split_word_bounds_with(options).rev().collect::<Vec<_>>();
+        b.reverse();
+        a == b && a.concat() == s
+    }
+
+    fn quickcheck_sentences_skip_plain_ascii(picks: Vec<u8>) -> bool {
+        let options = unicode_segmentation::SentenceOptions::new().property_override(|_| None);
+        let s = special_string(&picks);
+        s.split_sentence_bounds().eq(s.split_sentence_bounds_with(options))
+    }
+
+    fn quickcheck_grapheme_count_bytes(bytes: Vec<u8>, is_extended: bool) -> bool {
+        use unicode_segmentation::grapheme_count_bytes;
+
+        // valid runs counted as strings, and every other byte by itself
+        let mut expected = 0;
+        let mut rest = &bytes[..];
+        while let Err(e) = std::str::from_utf8(rest) {
+            let valid = std::str::from_utf8(&rest[..e.valid_up_to()]).unwrap();
+            let invalid_len = e.error_len().unwrap_or(rest.len() - valid.len());
+            expected += valid.graphemes(is_extended).count() + invalid_len;
+            rest = &rest[valid.len() + invalid_len..];
+        }
+        expected += std::str::from_utf8(rest).unwrap().graphemes(is_extended).count();
+        grapheme_count_bytes(&bytes, is_extended) == expected
+    }
+
+    fn quickcheck_forward_reverse_sentences_with(
+        s: String,
+        quote_aware: bool,
+        split_on_semicolon: bool,
+        require_space: bool
+    ) -> bool {
+        use unicode_segmentation::SentenceOptions;
+
+        let options = SentenceOptions::new()
+            .quote_aware(quote_aware)
+            .split_on_semicolon(split_on_semicolon)
+            .require_space_after_terminator(require_space);
+        let a = s.split_sentence_bounds_with(options).collect::<Vec<_>>();
+        let mut b = s.split_sentence_bounds_with(options).rev().collect::<Vec<_>>();
+        b.reverse();
+        a == b
+    }
+}
+
+#[test]
+fn test_reverse_streaming() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+
+    // Walk backwards through `s` fed one codepoint per chunk, answering
+    // every pre-context request with the single codepoint before it.
+    fn reverse_streamed(s: &str, is_extended: bool) -> Vec<usize> {
+        let chunks: Vec<(usize, &str)> = s
+            .char_indices()
+            .map(|(i, c)| (i, &s[i..i + c.len_utf8()]))
+            .collect();
+        let mut cursor = GraphemeCursor::new(s.len(), s.len(), is_extended);
+        let mut boundaries = vec![];
+        let mut idx = chunks.len().saturating_sub(1);
+        loop {
+            let (start, chunk) = chunks[idx];
+            match cursor.prev_boundary(chunk, start) {
+                Ok(Some(b)) => boundaries.push(b),
+                Ok(None) => break,
+                Err(GraphemeIncomplete::PrevChunk) => idx -= 1,
+                Err(GraphemeIncomplete::PreContext(n)) => {
+                    let (start, chunk) = chunks
+                        .iter()
+                        .copied()
+                        .find(|&(start, chunk)| start + chunk.len() == n)
+                        .unwrap();
+                    cursor.provide_context(chunk, start);
+                }
+                Err(e) => panic!("unexpected {:?} for {:?}", e, s),
+            }
+        }
+        boundaries
+    }
+
+    let extra = [
+        // flags
+        "\u{1F1E6}\u{1F1E8}\u{1F1E6}\u{1F1E9}\u{1F1E6}",
+        "a\u{1F1E6}\u{1F1E8}\u{1F1E6}\u{1F1E9}\u{1F1E6}\u{1F1EA}",
+        // emoji ZWJ sequences
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+        "\u{1F468}\u{1F3FB}\u{301}\u{200D}\u{1F469}",
+        "a\u{200D}\u{1F469}",
+        "\u{301}\u{200D}\u{1F469}",
+    ];
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s))
+        .chain(extra.iter().copied())
+        .filter(|s| !s.is_empty());
+    for s in strings {
+        for &is_extended in &[true, false] {
+            let mut expected: Vec<usize> =
+                s.grapheme_indices(is_extended).map(|(i, _)| i).collect();
+            expected.reverse();
+            assert_eq!(reverse_streamed(s, is_extended), expected, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_grapheme_ascii_fast_path() {
+    use crate::testdata::{TEST_DIFF, TEST_SAME};
+    use unicode_segmentation::{GraphemeCursor, GraphemeOptions};
+
+    // Ask a fresh cursor about every codepoint offset. `is_boundary` has no fast path, so
+    // every decision goes through the table lookups.
+    fn slow_boundaries(s: &str, options: GraphemeOptions) -> Vec<usize> {
+        (1..=s.len())
+            .filter(|&i| s.is_char_boundary(i))
+            .filter(|&i| {
+                let mut cursor = GraphemeCursor::with_options(i, s.len(), options);
+                cursor.is_boundary(s, 0).unwrap()
+            })
+            .collect()
+    }
+
+    let extra = [
+        "fn main() {\r\n    println!(\"hello\");\n}\n",
+        "ab\u{301}c d\u{200d}\u{1f469}e\tf",
+        "#\u{fe0f}\u{20e3}*\u{fe0f}\u{20e3}0a",
+        "x\u{1F1E6}\u{1F1E8}y\u{915}\u{94d}\u{915}z",
+    ];
+    let strings = TEST_SAME
+        .iter()
+        .map(|&(s, _)| s)
+        .chain(TEST_DIFF.iter().map(|&(s, _, _)| s))
+        .chain(extra.iter().copied())
+        .filter(|s| !s.is_empty());
+    for s in strings {
+        let options = [
+            GraphemeOptions::new(),
+            GraphemeOptions::new().extended(false),
+            // printable ASCII that is no longer `Other`
+            GraphemeOptions::new().extended_pictographic(|c| c == '*' || c == 'a'),
+        ];
+        for &options in &options {
+            let expected = slow_boundaries(s, options);
+            let mut cursor = GraphemeCursor::with_options(0, s.len(), options);
+            let mut fast = vec![];
+            while let Some(b) = cursor.next_boundary(s, 0).unwrap() {
+                fast.push(b);
+            }
+            assert_eq!(fast, expected, "{:?}", s);
+            let ends: Vec<usize> = s
+                .graphemes_with(options)
+                .scan(0, |end, g| {
+                    *end += g.len();
+                    Some(*end)
+                })
+                .collect();
+            assert_eq!(ends, expected, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_implement_trait() {
+    use unicode_segmentation::{
+        GraphemeIndices, Graphemes, USentenceBoundIndices, USentenceBounds, UWordBoundIndices,
+        UWordBounds, UnicodeSentences, UnicodeWordIndices, UnicodeWords,
+    };
+
+    // other types can implement the trait by forwarding to `str`
+    struct Text(String);
+
+    impl UnicodeSegmentation for Text {
+        fn graphemes(&self, is_extended: bool) -> Graphemes<'_> {
+            self.0.graphemes(is_extended)
+        }
+        fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_> {
+            self.0.grapheme_indices(is_extended)
+        }
+        fn unicode_words(&self) -> UnicodeWords<'_> {
+            self.0.unicode_words()
+        }
+        fn unicode_word_indices(&self) -> UnicodeWordIndices<'_> {
+            self.0.unicode_word_indices()
+        }
+        fn split_word_bounds(&self) -> UWordBounds<'_> {
+            self.0.split_word_bounds()
+        }
+        fn split_word_bound_indices(&self) -> UWordBoundIndices<'_> {
+            self.0.split_word_bound_indices()
+        }
+        fn unicode_sentences(&self) -> UnicodeSentences<'_> {
+            self.0.unicode_sentences()
+        }
+        fn split_sentence_bounds(&self) -> USentenceBounds<'_> {
+            self.0.split_sentence_bounds()
+        }
+        fn split_sentence_bound_indices(&self) -> USentenceBoundIndices<'_> {
+            self.0.split_sentence_bound_indices()
+        }
+    }
+
+    let s = "Hi e\u{301}! \u{1f1f7}\u{1f1fa} a.";
+    let text = Text(s.to_owned());
+    assert!(text.graphemes(true).eq(s.graphemes(true)));
+    assert!(text.split_word_bounds().eq(s.split_word_bounds()));
+    assert!(text.unicode_sentences().eq(s.unicode_sentences()));
 }